@@ -0,0 +1,187 @@
+// The benchmarks measure the panicking methods on purpose
+#![cfg_attr(feature = "no-panic", allow(deprecated))]
+
+use std::collections::{LinkedList, VecDeque};
+
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use linked_vec::LinkedVec;
+use nonmax::NonMaxU32;
+
+const LEN: u64 = 10_000;
+
+/// Builds a list whose physical order is shuffled relative to its
+/// logical order, as it would be after a mix of pushes at both ends.
+macro_rules! mixed_list {
+    ($index:ty) => {{
+        let mut list = LinkedVec::<u64, $index>::new();
+        for i in 0..LEN {
+            if i % 2 == 0 {
+                list.push_back(i);
+            } else {
+                list.push_front(i);
+            }
+        }
+        list
+    }};
+}
+
+macro_rules! bench_index_type {
+    ($c:expr, $name:literal, $index:ty) => {{
+        let c: &mut Criterion = $c;
+
+        c.bench_function(concat!("push_back/LinkedVec<", $name, ">"), |b| {
+            b.iter(|| {
+                let mut list = LinkedVec::<u64, $index>::new();
+                for i in 0..LEN {
+                    list.push_back(black_box(i));
+                }
+                list
+            })
+        });
+        c.bench_function(concat!("push_front/LinkedVec<", $name, ">"), |b| {
+            b.iter(|| {
+                let mut list = LinkedVec::<u64, $index>::new();
+                for i in 0..LEN {
+                    list.push_front(black_box(i));
+                }
+                list
+            })
+        });
+        c.bench_function(concat!("pop_front/LinkedVec<", $name, ">"), |b| {
+            b.iter_batched(
+                || mixed_list!($index),
+                |mut list| {
+                    while let Some(x) = list.pop_front() {
+                        black_box(x);
+                    }
+                },
+                BatchSize::SmallInput,
+            )
+        });
+        c.bench_function(concat!("pop_back/LinkedVec<", $name, ">"), |b| {
+            b.iter_batched(
+                || mixed_list!($index),
+                |mut list| {
+                    while let Some(x) = list.pop_back() {
+                        black_box(x);
+                    }
+                },
+                BatchSize::SmallInput,
+            )
+        });
+
+        let list = mixed_list!($index);
+        c.bench_function(concat!("iter_logical/LinkedVec<", $name, ">"), |b| {
+            b.iter(|| list.iter().sum::<u64>())
+        });
+        c.bench_function(concat!("iter_physical/LinkedVec<", $name, ">"), |b| {
+            b.iter(|| (0..list.len()).map(|p| list.index_p(p)).sum::<u64>())
+        });
+
+        c.bench_function(concat!("append/LinkedVec<", $name, ">"), |b| {
+            b.iter_batched(
+                || (mixed_list!($index), mixed_list!($index)),
+                |(mut a, mut b)| {
+                    a.append(&mut b);
+                    a
+                },
+                BatchSize::SmallInput,
+            )
+        });
+    }};
+}
+
+fn linked_vec(c: &mut Criterion) {
+    bench_index_type!(c, "usize", usize);
+    bench_index_type!(c, "NonMaxU32", NonMaxU32);
+}
+
+fn baselines(c: &mut Criterion) {
+    c.bench_function("push_back/LinkedList", |b| {
+        b.iter(|| {
+            let mut list = LinkedList::new();
+            for i in 0..LEN {
+                list.push_back(black_box(i));
+            }
+            list
+        })
+    });
+    c.bench_function("push_back/Vec", |b| {
+        b.iter(|| {
+            let mut list = Vec::new();
+            for i in 0..LEN {
+                list.push(black_box(i));
+            }
+            list
+        })
+    });
+    c.bench_function("push_front/LinkedList", |b| {
+        b.iter(|| {
+            let mut list = LinkedList::new();
+            for i in 0..LEN {
+                list.push_front(black_box(i));
+            }
+            list
+        })
+    });
+    c.bench_function("push_front/VecDeque", |b| {
+        b.iter(|| {
+            let mut list = VecDeque::new();
+            for i in 0..LEN {
+                list.push_front(black_box(i));
+            }
+            list
+        })
+    });
+    c.bench_function("pop_front/LinkedList", |b| {
+        b.iter_batched(
+            || (0..LEN).collect::<LinkedList<_>>(),
+            |mut list| {
+                while let Some(x) = list.pop_front() {
+                    black_box(x);
+                }
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    c.bench_function("pop_front/VecDeque", |b| {
+        b.iter_batched(
+            || (0..LEN).collect::<VecDeque<_>>(),
+            |mut list| {
+                while let Some(x) = list.pop_front() {
+                    black_box(x);
+                }
+            },
+            BatchSize::SmallInput,
+        )
+    });
+
+    let list: LinkedList<_> = (0..LEN).collect();
+    c.bench_function("iter/LinkedList", |b| b.iter(|| list.iter().sum::<u64>()));
+    let list: Vec<_> = (0..LEN).collect();
+    c.bench_function("iter/Vec", |b| b.iter(|| list.iter().sum::<u64>()));
+
+    c.bench_function("append/LinkedList", |b| {
+        b.iter_batched(
+            || ((0..LEN).collect::<LinkedList<_>>(), (0..LEN).collect()),
+            |(mut a, mut b)| {
+                a.append(&mut b);
+                a
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    c.bench_function("append/Vec", |b| {
+        b.iter_batched(
+            || ((0..LEN).collect::<Vec<_>>(), (0..LEN).collect()),
+            |(mut a, mut b)| {
+                a.append(&mut b);
+                a
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+criterion_group!(benches, linked_vec, baselines);
+criterion_main!(benches);
@@ -0,0 +1,96 @@
+//! Picks the `Vec` that backs the physical array.
+//!
+//! With the `allocator-api2` feature, this is the `Vec` of that crate,
+//! which itself re-exports the standard one with `allocator-api`.
+//! Otherwise, it is a thin wrapper around the standard `Vec` that only
+//! supports the global allocator.
+
+#[cfg(feature = "allocator-api2")]
+pub use allocator_api2::{
+    alloc::{Allocator, Global},
+    vec::Vec,
+};
+
+#[cfg(not(feature = "allocator-api2"))]
+pub use fallback::{Allocator, Global, Vec};
+
+#[cfg(not(feature = "allocator-api2"))]
+mod fallback {
+    use core::ops::{Deref, DerefMut};
+
+    mod sealed {
+        pub trait Sealed {}
+    }
+
+    /// An allocator for the physical array.
+    ///
+    /// Without the `allocator-api` or `allocator-api2` feature, only
+    /// [`Global`] implements this.
+    pub trait Allocator: sealed::Sealed {}
+
+    /// The global memory allocator.
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Global;
+
+    impl sealed::Sealed for Global {}
+    impl Allocator for Global {}
+
+    /// A standard `Vec` that carries its allocator along.
+    pub struct Vec<T, A: Allocator = Global> {
+        vec: alloc::vec::Vec<T>,
+        alloc: A,
+    }
+
+    impl<T> Vec<T> {
+        pub const fn new() -> Self {
+            Self::new_in(Global)
+        }
+
+        /// # Safety
+        ///
+        /// Same as [`alloc::vec::Vec::from_raw_parts`].
+        pub unsafe fn from_raw_parts(ptr: *mut T, length: usize, capacity: usize) -> Self {
+            Self {
+                // Safety: Ensured by the caller
+                vec: unsafe { alloc::vec::Vec::from_raw_parts(ptr, length, capacity) },
+                alloc: Global,
+            }
+        }
+    }
+
+    impl<T, A: Allocator> Vec<T, A> {
+        pub const fn new_in(alloc: A) -> Self {
+            Self {
+                vec: alloc::vec::Vec::new(),
+                alloc,
+            }
+        }
+
+        pub fn allocator(&self) -> &A {
+            &self.alloc
+        }
+    }
+
+    impl<T, A: Allocator> Deref for Vec<T, A> {
+        type Target = alloc::vec::Vec<T>;
+
+        fn deref(&self) -> &Self::Target {
+            &self.vec
+        }
+    }
+
+    impl<T, A: Allocator> DerefMut for Vec<T, A> {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+            &mut self.vec
+        }
+    }
+
+    impl<T, A: Allocator> IntoIterator for Vec<T, A> {
+        type Item = T;
+        type IntoIter = alloc::vec::IntoIter<T>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.vec.into_iter()
+        }
+    }
+}
@@ -0,0 +1,290 @@
+use alloc::vec::Vec;
+
+use crate::{
+    inner_types::{StoreIndex, VecNode},
+    iterators::Iter,
+    links::LinksMut,
+};
+
+/// Identifies one of the lists in a [`LinkedArena`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ListId(usize);
+
+#[derive(Debug, Clone, Copy)]
+struct ListEnds<I> {
+    head: Option<I>,
+    tail: Option<I>,
+    len: usize,
+}
+
+/// Several linked lists sharing one physical array.
+///
+/// Every node belongs to exactly one list. Nodes can be moved from one
+/// list to another in *O*(1) time without touching the payload.
+pub struct LinkedArena<T, I: StoreIndex + Copy = usize> {
+    data: Vec<VecNode<T, I>>,
+    lists: Vec<ListEnds<I>>,
+    /// The list each node belongs to, parallel to `data`.
+    owners: Vec<I>,
+}
+
+impl<T, I: StoreIndex + Copy> LinkedArena<T, I> {
+    pub const fn new() -> Self {
+        Self {
+            data: Vec::new(),
+            lists: Vec::new(),
+            owners: Vec::new(),
+        }
+    }
+
+    /// Creates a new empty list in the arena.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `I` cannot index another list.
+    pub fn new_list(&mut self) -> ListId {
+        if self.lists.len() > I::MAX_USIZE {
+            crate::capacity_overflow()
+        }
+        self.lists.push(ListEnds {
+            head: None,
+            tail: None,
+            len: 0,
+        });
+        ListId(self.lists.len() - 1)
+    }
+
+    /// Returns the number of lists in the arena.
+    pub fn list_count(&self) -> usize {
+        self.lists.len()
+    }
+
+    /// Returns the number of elements in all lists together.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of elements in `list`.
+    ///
+    /// This operation should compute in *O*(1) time.
+    pub fn list_len(&self, list: ListId) -> usize {
+        self.lists[list.0].len
+    }
+
+    /// Returns the list that the element at physical index `index` belongs
+    /// to, or `None` if the index is out of bounds.
+    #[must_use]
+    pub fn list_of_p(&self, index: usize) -> Option<ListId> {
+        self.owners.get(index).map(|x| ListId(x.to_usize()))
+    }
+
+    /// Returns a reference to the element at `index` on the physical array,
+    /// or `None` if the index is out of bounds.
+    #[must_use]
+    pub fn get_p(&self, index: usize) -> Option<&T> {
+        self.data.get(index).map(|x| &x.payload)
+    }
+
+    /// Returns a mutable reference to the element at `index` on the physical
+    /// array, or `None` if the index is out of bounds.
+    #[must_use]
+    pub fn get_p_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.data.get_mut(index).map(|x| &mut x.payload)
+    }
+
+    /// Returns a reference to the element at `index` on the physical array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    #[must_use]
+    pub fn index_p(&self, index: usize) -> &T {
+        if index >= self.len() {
+            crate::index_out_of_bounds(index, self.len())
+        }
+        &self.data[index].payload
+    }
+
+    /// Returns a mutable reference to the element at `index` on the physical
+    /// array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    #[must_use]
+    pub fn index_p_mut(&mut self, index: usize) -> &mut T {
+        if index >= self.len() {
+            crate::index_out_of_bounds(index, self.len())
+        }
+        &mut self.data[index].payload
+    }
+
+    /// Provides a reference to the front element of `list`, or `None` if
+    /// it is empty.
+    #[must_use]
+    pub fn front(&self, list: ListId) -> Option<&T> {
+        self.lists[list.0].head.map(|x| self.index_p(x.to_usize()))
+    }
+
+    /// Provides a reference to the back element of `list`, or `None` if
+    /// it is empty.
+    #[must_use]
+    pub fn back(&self, list: ListId) -> Option<&T> {
+        self.lists[list.0].tail.map(|x| self.index_p(x.to_usize()))
+    }
+
+    /// Inserts an element first in `list` and last in the physical array.
+    ///
+    /// Returns the physical index of the new element.
+    pub fn push_front(&mut self, list: ListId, value: T) -> usize {
+        let inserted = self.push_p(list, value);
+        self.link_before(list.0, inserted, self.lists[list.0].head);
+        inserted.to_usize()
+    }
+
+    /// Inserts an element last in `list` and last in the physical array.
+    ///
+    /// Returns the physical index of the new element.
+    pub fn push_back(&mut self, list: ListId, value: T) -> usize {
+        let inserted = self.push_p(list, value);
+        self.link_after(list.0, inserted, self.lists[list.0].tail);
+        inserted.to_usize()
+    }
+
+    /// Remove and return the first element of `list`, if any.
+    pub fn pop_front(&mut self, list: ListId) -> Option<T> {
+        let head = self.lists[list.0].head?;
+        Some(self.in_swap_remove(list.0, head.to_usize()))
+    }
+
+    /// Remove and return the last element of `list`, if any.
+    pub fn pop_back(&mut self, list: ListId) -> Option<T> {
+        let tail = self.lists[list.0].tail?;
+        Some(self.in_swap_remove(list.0, tail.to_usize()))
+    }
+
+    /// Moves the element at physical index `index` from whichever list
+    /// it is in to the front of the list `to`.
+    ///
+    /// The element keeps its physical index.
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    pub fn move_to_front(&mut self, index: usize, to: ListId) {
+        let moved = self.hand_over(index, to);
+        self.link_before(to.0, moved, self.lists[to.0].head);
+    }
+
+    /// Moves the element at physical index `index` from whichever list
+    /// it is in to the back of the list `to`.
+    ///
+    /// The element keeps its physical index.
+    /// This operation should compute in *O*(1) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    pub fn move_to_back(&mut self, index: usize, to: ListId) {
+        let moved = self.hand_over(index, to);
+        self.link_after(to.0, moved, self.lists[to.0].tail);
+    }
+
+    /// Provides a forward iterator over `list`.
+    #[must_use]
+    pub fn iter(&self, list: ListId) -> Iter<'_, T, I> {
+        let ends = self.lists[list.0];
+        Iter::from_nodes_range(
+            &self.data,
+            ends.head.map_or(0, |x| x.to_usize()),
+            ends.tail.map_or(0, |x| x.to_usize()),
+            ends.len,
+        )
+    }
+
+    fn push_p(&mut self, list: ListId, value: T) -> I {
+        let start_len = self.len();
+        if start_len > I::MAX_USIZE {
+            crate::capacity_overflow()
+        }
+        self.data.push(VecNode::new(value));
+        // Safety: new_list checked that the list index is <= MAX_USIZE
+        self.owners.push(unsafe { I::from_usize_unchecked(list.0) });
+
+        // Safety: Already checked that start_len <= MAX_USIZE
+        unsafe { I::from_usize_unchecked(start_len) }
+    }
+
+    fn in_swap_remove(&mut self, list: usize, index: usize) -> T {
+        self.unlink(list, index);
+        let last = self.len() - 1;
+        let payload = self.data.swap_remove(index).payload;
+        self.owners.swap_remove(index);
+        if index != last {
+            // The node that was last now lives at index.
+            let owner = self.owners[index].to_usize();
+            self.links_mut(owner).relink_moved(index);
+        }
+        payload
+    }
+
+    /// Unlinks the node at `index` from its list and hands it over to
+    /// `to`.
+    fn hand_over(&mut self, index: usize, to: ListId) -> I {
+        let from = self.owners[index].to_usize();
+        self.unlink(from, index);
+        self.owners[index] = I::from_usize(to.0);
+        I::from_usize(index)
+    }
+
+    fn unlink(&mut self, list: usize, target: usize) {
+        self.links_mut(list).unlink(target);
+        self.lists[list].len -= 1;
+    }
+
+    fn link_before(&mut self, list: usize, inserted: I, target: Option<I>) {
+        self.links_mut(list).insert_before(inserted, target);
+        self.lists[list].len += 1;
+    }
+
+    fn link_after(&mut self, list: usize, inserted: I, target: Option<I>) {
+        self.links_mut(list).insert_after(inserted, target);
+        self.lists[list].len += 1;
+    }
+
+    fn links_mut(&mut self, list: usize) -> LinksMut<'_, T, I> {
+        let ends = &mut self.lists[list];
+        LinksMut {
+            nodes: &mut self.data,
+            head: &mut ends.head,
+            tail: &mut ends.tail,
+        }
+    }
+}
+
+impl<T, I: StoreIndex + Copy> Default for LinkedArena<T, I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: core::fmt::Debug, I: StoreIndex + Copy> core::fmt::Debug for LinkedArena<T, I> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list()
+            .entries((0..self.lists.len()).map(|i| DebugList(self.iter(ListId(i)))))
+            .finish()
+    }
+}
+
+struct DebugList<'a, T, I: StoreIndex + Copy>(Iter<'a, T, I>);
+
+impl<T: core::fmt::Debug, I: StoreIndex + Copy> core::fmt::Debug for DebugList<'_, T, I> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.0).finish()
+    }
+}
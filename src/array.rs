@@ -0,0 +1,460 @@
+use core::fmt::Debug;
+
+use crate::{
+    inner_types::{StoreIndex, VecNode},
+    iterators::Iter,
+    links::{Links, LinksMut},
+    raw::InlineVec,
+};
+
+/// A doubly linked list whose nodes are stored inline in an array of
+/// `N` slots.
+///
+/// This is the allocation-free counterpart of [`LinkedVec`](crate::LinkedVec).
+/// The first `len` slots are always initialized, and removing an element
+/// moves the physically last node into the freed slot, as in `LinkedVec`.
+/// Inserting into a full list hands the value back instead of growing.
+pub struct ArrayLinkedVec<T, const N: usize, I: StoreIndex + Copy = usize> {
+    data: InlineVec<VecNode<T, I>, N>,
+    head: Option<I>,
+    tail: Option<I>,
+}
+
+impl<T, const N: usize, I: StoreIndex + Copy> ArrayLinkedVec<T, N, I> {
+    pub const fn new() -> Self {
+        Self {
+            data: InlineVec::new(),
+            head: None,
+            tail: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of elements the list can hold.
+    ///
+    /// This is `N`, unless `I` cannot index that many slots.
+    pub fn capacity(&self) -> usize {
+        N.min(I::MAX_USIZE.saturating_add(1))
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+
+    /// Returns a reference to the element at `index` on the physical array,
+    /// or `None` if the index is out of bounds.
+    #[must_use]
+    pub fn get_p(&self, index: usize) -> Option<&T> {
+        if index >= self.len() {
+            return None;
+        }
+        Some(&self.node(index).payload)
+    }
+
+    /// Returns a mutable reference to the element at `index` on the physical
+    /// array, or `None` if the index is out of bounds.
+    #[must_use]
+    pub fn get_p_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len() {
+            return None;
+        }
+        Some(&mut self.node_mut(index).payload)
+    }
+
+    /// Returns a reference to the element at `index` on the physical array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    #[must_use]
+    pub fn index_p(&self, index: usize) -> &T {
+        if index >= self.len() {
+            crate::index_out_of_bounds(index, self.len())
+        }
+        &self.node(index).payload
+    }
+
+    /// Returns a mutable reference to the element at `index` on the physical
+    /// array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    #[must_use]
+    pub fn index_p_mut(&mut self, index: usize) -> &mut T {
+        if index >= self.len() {
+            crate::index_out_of_bounds(index, self.len())
+        }
+        &mut self.node_mut(index).payload
+    }
+
+    /// Provides a reference to the front element, or `None` if the list is
+    /// empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    #[must_use]
+    pub fn front(&self) -> Option<&T> {
+        self.head.map(|x| self.index_p(x.to_usize()))
+    }
+
+    /// Provides a mutable reference to the front element, or `None` if the list is
+    /// empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    #[must_use]
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.head.map(|x| self.index_p_mut(x.to_usize()))
+    }
+
+    /// Provides a reference to the back element, or `None` if the list is
+    /// empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    #[must_use]
+    pub fn back(&self) -> Option<&T> {
+        self.tail.map(|x| self.index_p(x.to_usize()))
+    }
+
+    /// Provides a mutable reference to the back element, or `None` if the list is
+    /// empty.
+    ///
+    /// This operation should compute in *O*(1) time.
+    #[must_use]
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.tail.map(|x| self.index_p_mut(x.to_usize()))
+    }
+
+    /// Inserts an element first in the linked list and last in the physical array.
+    ///
+    /// # Errors
+    ///
+    /// Returns the value back if the list is full.
+    pub fn push_front(&mut self, value: T) -> Result<(), T> {
+        let inserted = self.push_p(value)?;
+        self.insert_node_before(inserted, self.head);
+        Ok(())
+    }
+
+    /// Inserts an element last in the linked list and last in the physical array.
+    ///
+    /// # Errors
+    ///
+    /// Returns the value back if the list is full.
+    pub fn push_back(&mut self, value: T) -> Result<(), T> {
+        let inserted = self.push_p(value)?;
+        self.insert_node_after(inserted, self.tail);
+        Ok(())
+    }
+
+    /// Remove and return first element in the linked list, if any.
+    pub fn pop_front(&mut self) -> Option<T> {
+        let head = self.head?;
+        Some(self.in_swap_remove(head.to_usize()))
+    }
+
+    /// Remove and return last element in the linked list, if any.
+    pub fn pop_back(&mut self) -> Option<T> {
+        let tail = self.tail?;
+        Some(self.in_swap_remove(tail.to_usize()))
+    }
+
+    /// Remove and return last element in the physical array, if any.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(self.in_swap_remove(self.len() - 1))
+    }
+
+    /// Remove and return the element pointed to by the index on the physical array.
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        if index >= self.len() {
+            crate::index_out_of_bounds(index, self.len())
+        }
+        self.in_swap_remove(index)
+    }
+
+    /// Removes all elements from the list.
+    ///
+    /// The elements are dropped in logical order, front to back.
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    /// Provides a forward iterator.
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_, T, I> {
+        Iter::from_nodes(self.data.as_slice(), self.head, self.tail)
+    }
+
+    pub fn cursor_front(&self) -> ArrayCursor<'_, T, N, I> {
+        ArrayCursor {
+            index_la: 0,
+            current_pa: self.head.map(|x| x.to_usize()),
+            list: self,
+        }
+    }
+
+    pub fn cursor_back(&self) -> ArrayCursor<'_, T, N, I> {
+        ArrayCursor {
+            index_la: self.len().saturating_sub(1),
+            current_pa: self.tail.map(|x| x.to_usize()),
+            list: self,
+        }
+    }
+
+    pub fn cursor_front_mut(&mut self) -> ArrayCursorMut<'_, T, N, I> {
+        ArrayCursorMut {
+            index_la: 0,
+            current_pa: self.head.map(|x| x.to_usize()),
+            list: self,
+        }
+    }
+
+    pub fn cursor_back_mut(&mut self) -> ArrayCursorMut<'_, T, N, I> {
+        ArrayCursorMut {
+            index_la: self.len().saturating_sub(1),
+            current_pa: self.tail.map(|x| x.to_usize()),
+            list: self,
+        }
+    }
+
+    fn node(&self, index: usize) -> &VecNode<T, I> {
+        &self.data.as_slice()[index]
+    }
+
+    fn node_mut(&mut self, index: usize) -> &mut VecNode<T, I> {
+        &mut self.data.as_mut_slice()[index]
+    }
+
+    fn push_p(&mut self, value: T) -> Result<I, T> {
+        if self.is_full() {
+            return Err(value);
+        }
+        self.data
+            .push(VecNode::new(value))
+            .map_err(|node| node.payload)?;
+
+        // Safety: The list was not full, so len - 1 < capacity <= MAX_USIZE + 1
+        Ok(unsafe { I::from_usize_unchecked(self.len() - 1) })
+    }
+
+    fn in_swap_remove(&mut self, index: usize) -> T {
+        self.links_mut().unlink(index);
+        let last = self.len() - 1;
+        let payload = self.data.swap_remove(index).payload;
+        if index != last {
+            self.links_mut().relink_moved(index);
+        }
+        payload
+    }
+
+    fn insert_node_before(&mut self, inserted: I, target: Option<I>) {
+        self.links_mut().insert_before(inserted, target);
+    }
+
+    fn insert_node_after(&mut self, inserted: I, target: Option<I>) {
+        self.links_mut().insert_after(inserted, target);
+    }
+
+    fn links(&self) -> Links<'_, T, I> {
+        Links {
+            nodes: self.data.as_slice(),
+            head: self.head,
+            tail: self.tail,
+        }
+    }
+
+    fn links_mut(&mut self) -> LinksMut<'_, T, I> {
+        LinksMut {
+            nodes: self.data.as_mut_slice(),
+            head: &mut self.head,
+            tail: &mut self.tail,
+        }
+    }
+}
+
+impl<T, const N: usize, I: StoreIndex + Copy> Drop for ArrayLinkedVec<T, N, I> {
+    /// Drops the elements in logical order, front to back.
+    fn drop(&mut self) {
+        self.clear()
+    }
+}
+
+impl<T, const N: usize, I: StoreIndex + Copy> Default for ArrayLinkedVec<T, N, I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug, const N: usize, I: StoreIndex + Copy> Debug for ArrayLinkedVec<T, N, I> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T: 'a, const N: usize, I: Copy + StoreIndex> IntoIterator for &'a ArrayLinkedVec<T, N, I> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// A cursor over an [`ArrayLinkedVec`].
+///
+/// Works like [`VecCursor`](crate::iterators::VecCursor).
+#[derive(Debug)]
+pub struct ArrayCursor<'a, T: 'a, const N: usize, I: Copy + StoreIndex> {
+    index_la: usize,
+    current_pa: Option<usize>,
+    list: &'a ArrayLinkedVec<T, N, I>,
+}
+
+impl<T, const N: usize, I: Copy + StoreIndex> Clone for ArrayCursor<'_, T, N, I> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<'a, T: 'a, const N: usize, I: Copy + StoreIndex> ArrayCursor<'a, T, N, I> {
+    /// Returns the cursor position within the linked list.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    #[must_use]
+    pub fn index_l(&self) -> Option<usize> {
+        let _ = self.current_pa?;
+        Some(self.index_la)
+    }
+
+    /// Returns the cursor position within the physical array.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    #[must_use]
+    pub fn index_p(&self) -> Option<usize> {
+        self.current_pa
+    }
+
+    /// Returns a reference to the element that the cursor is currently
+    /// pointing to.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    #[must_use]
+    pub fn current(&self) -> Option<&'a T> {
+        Some(self.list.index_p(self.current_pa?))
+    }
+
+    /// Moves the cursor to the next element of the linked list.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this will move it to
+    /// the first element of the list. If it is pointing to the last
+    /// element of the list, then this will move it to the "ghost" non-element.
+    pub fn move_next(&mut self) {
+        self.list
+            .links()
+            .step_next(&mut self.index_la, &mut self.current_pa);
+    }
+
+    /// Moves the cursor to the previous element of the linked list.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this will move it to
+    /// the last element of the list. If it is pointing to the first
+    /// element of the list, then this will move it to the "ghost" non-element.
+    pub fn move_prev(&mut self) {
+        self.list
+            .links()
+            .step_prev(&mut self.index_la, &mut self.current_pa);
+    }
+
+    /// Returns a reference to the next element.
+    #[must_use]
+    pub fn peek_next(&self) -> Option<&'a T> {
+        let mut next = self.clone();
+        next.move_next();
+        next.current()
+    }
+
+    /// Returns a reference to the previous element.
+    #[must_use]
+    pub fn peek_prev(&self) -> Option<&'a T> {
+        let mut prev = self.clone();
+        prev.move_prev();
+        prev.current()
+    }
+}
+
+/// A cursor over an [`ArrayLinkedVec`] with mutable access to the
+/// elements.
+///
+/// Works like [`VecCursorMut`](crate::iterators::VecCursorMut).
+#[derive(Debug)]
+pub struct ArrayCursorMut<'a, T: 'a, const N: usize, I: Copy + StoreIndex> {
+    index_la: usize,
+    current_pa: Option<usize>,
+    list: &'a mut ArrayLinkedVec<T, N, I>,
+}
+
+impl<'a, T: 'a, const N: usize, I: Copy + StoreIndex> ArrayCursorMut<'a, T, N, I> {
+    /// Returns the cursor position within the linked list.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    #[must_use]
+    pub fn index_l(&self) -> Option<usize> {
+        let _ = self.current_pa?;
+        Some(self.index_la)
+    }
+
+    /// Returns the cursor position within the physical array.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    #[must_use]
+    pub fn index_p(&self) -> Option<usize> {
+        self.current_pa
+    }
+
+    /// Returns a reference to the element that the cursor is currently
+    /// pointing to.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    #[must_use]
+    pub fn current(&mut self) -> Option<&mut T> {
+        Some(self.list.index_p_mut(self.current_pa?))
+    }
+
+    /// Moves the cursor to the next element of the linked list.
+    pub fn move_next(&mut self) {
+        self.list
+            .links()
+            .step_next(&mut self.index_la, &mut self.current_pa);
+    }
+
+    /// Moves the cursor to the previous element of the linked list.
+    pub fn move_prev(&mut self) {
+        self.list
+            .links()
+            .step_prev(&mut self.index_la, &mut self.current_pa);
+    }
+
+    #[must_use]
+    pub fn as_cursor(&self) -> ArrayCursor<'_, T, N, I> {
+        ArrayCursor {
+            index_la: self.index_la,
+            current_pa: self.current_pa,
+            list: self.list,
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//! Graphviz dumps of the physical array.
+//!
+//! Every slot is a node labelled with its physical index and payload.
+//! `next` links are solid edges and `prev` links dashed ones, so in a
+//! healthy list every solid edge has a dashed edge going back. Links are
+//! written as they are, even when they point out of bounds.
+
+use core::fmt::{self, Debug, Write};
+
+use crate::{inner_types::StoreIndex, Allocator, LinkedVec};
+
+/// Escapes what it writes for a quoted DOT string.
+struct Escape<'a, W: Write>(&'a mut W);
+
+impl<W: Write> Write for Escape<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            match c {
+                '"' | '\\' => {
+                    self.0.write_char('\\')?;
+                    self.0.write_char(c)?;
+                }
+                '\n' => self.0.write_str("\\n")?,
+                _ => self.0.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: Debug, I: StoreIndex + Copy, A: Allocator> LinkedVec<T, I, A> {
+    /// Writes a DOT graph of the physical slots and their links to `w`.
+    ///
+    /// The slots are named `n0`, `n1` and so on by physical index, and
+    /// `head` and `tail` point to the ends. Render it with
+    /// `dot -Tsvg`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of `w`.
+    pub fn debug_dot<W: Write>(&self, w: &mut W) -> fmt::Result {
+        writeln!(w, "digraph LinkedVec {{")?;
+        writeln!(w, "    node [shape=box];")?;
+        writeln!(w, "    head [shape=plaintext];")?;
+        writeln!(w, "    tail [shape=plaintext];")?;
+        for (p, node) in self.data.iter().enumerate() {
+            write!(w, "    n{p} [label=\"{p}: ")?;
+            write!(Escape(w), "{:?}", node.payload)?;
+            writeln!(w, "\"];")?;
+        }
+        for (p, node) in self.data.iter().enumerate() {
+            if let Some(next) = node.next {
+                writeln!(w, "    n{p} -> n{};", next.to_usize())?;
+            }
+            if let Some(prev) = node.prev {
+                writeln!(w, "    n{p} -> n{} [style=dashed];", prev.to_usize())?;
+            }
+        }
+        if let Some(head) = self.head {
+            writeln!(w, "    head -> n{};", head.to_usize())?;
+        }
+        if let Some(tail) = self.tail {
+            writeln!(w, "    tail -> n{};", tail.to_usize())?;
+        }
+        writeln!(w, "}}")
+    }
+}
@@ -0,0 +1,91 @@
+use core::fmt::Debug;
+
+use crate::{inner_types::StoreIndex, iterators::VecCursorMut, Allocator, Global, LinkedVec};
+
+/// A position in a [`LinkedVec`], which may hold an element or be the end
+/// of the list.
+///
+/// Returned by [`LinkedVec::entry_l`]. An occupied entry is a cursor at
+/// the element, so it can be changed, removed, or have elements inserted
+/// next to it without walking the list again.
+#[derive(Debug)]
+pub enum Entry<'a, T, I: StoreIndex + Copy = usize, A: Allocator = Global> {
+    Occupied(VecCursorMut<'a, T, I, A>),
+    Vacant(EndInsertPoint<'a, T, I, A>),
+}
+
+/// The end of a [`LinkedVec`], where an element can be inserted.
+pub struct EndInsertPoint<'a, T, I: StoreIndex + Copy = usize, A: Allocator = Global> {
+    list: &'a mut LinkedVec<T, I, A>,
+}
+
+impl<T, I: StoreIndex + Copy, A: Allocator> LinkedVec<T, I, A> {
+    /// Gets the entry at logical `index`, which is vacant if `index` is
+    /// the length of the list.
+    ///
+    /// This operation should compute in *O*(min(index, len - index)) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn entry_l(&mut self, index: usize) -> Entry<'_, T, I, A> {
+        if index == self.len() {
+            return Entry::Vacant(EndInsertPoint { list: self });
+        }
+        match self.nth_p(index) {
+            Some(current_pa) => Entry::Occupied(VecCursorMut {
+                index_la: index,
+                current_pa: Some(current_pa),
+                list: self,
+            }),
+            None => crate::index_out_of_bounds(index, self.len()),
+        }
+    }
+}
+
+impl<'a, T, I: StoreIndex + Copy, A: Allocator> Entry<'a, T, I, A> {
+    /// Returns a mutable reference to the element of the entry, inserting
+    /// `value` at the back first if the entry is vacant.
+    pub fn or_insert(self, value: T) -> &'a mut T {
+        self.or_insert_with(|| value)
+    }
+
+    /// Returns a mutable reference to the element of the entry, inserting
+    /// the result of `f` at the back first if the entry is vacant.
+    pub fn or_insert_with<F: FnOnce() -> T>(self, f: F) -> &'a mut T {
+        let cursor = match self {
+            Self::Occupied(cursor) => cursor,
+            Self::Vacant(point) => point.insert(f()),
+        };
+        cursor.into_current().unwrap()
+    }
+
+    /// Calls `f` on the element if the entry is occupied.
+    #[must_use]
+    pub fn and_modify<F: FnOnce(&mut T)>(mut self, f: F) -> Self {
+        if let Self::Occupied(cursor) = &mut self {
+            f(cursor.current().unwrap());
+        }
+        self
+    }
+}
+
+impl<'a, T, I: StoreIndex + Copy, A: Allocator> EndInsertPoint<'a, T, I, A> {
+    /// Inserts `value` at the back of the list and returns a cursor
+    /// pointing to it.
+    pub fn insert(self, value: T) -> VecCursorMut<'a, T, I, A> {
+        self.list.push_back(value);
+        let list = self.list;
+        VecCursorMut {
+            index_la: list.len() - 1,
+            current_pa: Some(list.len() - 1),
+            list,
+        }
+    }
+}
+
+impl<T: Debug, I: StoreIndex + Copy, A: Allocator> Debug for EndInsertPoint<'_, T, I, A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("EndInsertPoint").field(&self.list).finish()
+    }
+}
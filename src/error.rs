@@ -0,0 +1,96 @@
+use core::fmt;
+
+use crate::LinkCorruption;
+
+/// The error returned by fallible operations that take ownership of
+/// data, such as pushes and insertions.
+///
+/// It carries back the data that could not be stored, along with the
+/// reason.
+pub struct TryError<D> {
+    error: Error,
+    data: D,
+}
+
+impl<D> TryError<D> {
+    pub(crate) fn new(error: Error, data: D) -> Self {
+        Self { error, data }
+    }
+
+    /// Returns why the operation failed.
+    pub fn error(&self) -> Error {
+        self.error
+    }
+
+    /// Returns the data that could not be stored.
+    pub fn into_inner(self) -> D {
+        self.data
+    }
+
+    /// Returns why the operation failed and the data that could not be
+    /// stored.
+    pub fn into_parts(self) -> (Error, D) {
+        (self.error, self.data)
+    }
+}
+
+impl<D> fmt::Debug for TryError<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryError")
+            .field("error", &self.error)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<D> fmt::Display for TryError<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl<D> core::error::Error for TryError<D> {}
+
+/// The error type for the fallible operations of this crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The list would hold more elements than its index type can
+    /// address, or more bytes than an allocation can span.
+    CapacityOverflow,
+    /// An index was past the end of the list.
+    IndexOutOfBounds { index: usize, len: usize },
+    /// The allocator reported a failure.
+    AllocError,
+    /// A snapshot does not have the size its header announces.
+    InvalidSnapshot,
+    /// The links of a list are broken.
+    LinkCorruption(LinkCorruption),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CapacityOverflow => f.write_str("capacity overflow"),
+            Self::IndexOutOfBounds { index, len } => {
+                write!(f, "index (is {index}) should be < or <= len (is {len})")
+            }
+            Self::AllocError => f.write_str("memory allocation failed"),
+            Self::InvalidSnapshot => f.write_str("invalid snapshot"),
+            Self::LinkCorruption(err) => write!(f, "link corruption: {err}"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+impl From<LinkCorruption> for Error {
+    fn from(err: LinkCorruption) -> Self {
+        Self::LinkCorruption(err)
+    }
+}
+
+impl<D> From<TryError<D>> for Error {
+    fn from(err: TryError<D>) -> Self {
+        err.error
+    }
+}
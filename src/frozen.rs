@@ -0,0 +1,93 @@
+use alloc::boxed::Box;
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use core::{fmt::Debug, ops::Deref};
+
+use crate::{inner_types::StoreIndex, Allocator, Global, LinkedVec};
+
+/// A [`LinkedVec`] that can no longer be changed.
+///
+/// It dereferences to the list, so every read API is available, but none
+/// that mutate. Since nothing can move the nodes, it is `Sync` whenever
+/// `T` is, and can be traversed by several threads at once through a
+/// shared reference or an [`Arc`].
+///
+/// Freezing records the physical index of every logical position, so
+/// [`get_l`](FrozenLinkedVec::get_l) takes constant time.
+pub struct FrozenLinkedVec<T, I: StoreIndex + Copy = usize, A: Allocator = Global> {
+    list: LinkedVec<T, I, A>,
+    order: Box<[I]>,
+}
+
+impl<T, I: StoreIndex + Copy, A: Allocator> LinkedVec<T, I, A> {
+    /// Makes the list immutable.
+    ///
+    /// This operation should compute in *O*(n) time.
+    pub fn freeze(self) -> FrozenLinkedVec<T, I, A> {
+        let order = self
+            .iter_indices()
+            .map(|(_, p, _)| I::from_usize(p))
+            .collect();
+        FrozenLinkedVec { list: self, order }
+    }
+}
+
+impl<T, I: StoreIndex + Copy, A: Allocator> FrozenLinkedVec<T, I, A> {
+    /// Returns a reference to the element at logical `index`, or `None` if
+    /// the index is out of bounds.
+    ///
+    /// This operation should compute in *O*(1) time.
+    #[must_use]
+    pub fn get_l(&self, index: usize) -> Option<&T> {
+        let index_p = self.order.get(index)?.to_usize();
+        self.list.get_p(index_p)
+    }
+
+    /// Returns the physical index of the element at logical `index`, or
+    /// `None` if the index is out of bounds.
+    #[must_use]
+    pub fn index_l_to_p(&self, index: usize) -> Option<usize> {
+        self.order.get(index).map(StoreIndex::to_usize)
+    }
+
+    /// Returns the number of heap bytes allocated for the physical array
+    /// and the recorded logical order.
+    pub fn allocated_bytes(&self) -> usize {
+        self.list.allocated_bytes() + self.order.len() * core::mem::size_of::<I>()
+    }
+
+    /// Gives the mutable list back.
+    pub fn thaw(self) -> LinkedVec<T, I, A> {
+        self.list
+    }
+
+    /// Gives the mutable list back if `this` is the only reference to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `this` unchanged if it is shared.
+    #[cfg(target_has_atomic = "ptr")]
+    pub fn try_thaw(this: Arc<Self>) -> Result<LinkedVec<T, I, A>, Arc<Self>> {
+        Arc::try_unwrap(this).map(Self::thaw)
+    }
+}
+
+impl<T, I: StoreIndex + Copy, A: Allocator> Deref for FrozenLinkedVec<T, I, A> {
+    type Target = LinkedVec<T, I, A>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+impl<T, I: StoreIndex + Copy, A: Allocator> From<LinkedVec<T, I, A>> for FrozenLinkedVec<T, I, A> {
+    fn from(list: LinkedVec<T, I, A>) -> Self {
+        list.freeze()
+    }
+}
+
+impl<T: Debug, I: StoreIndex + Copy, A: Allocator> Debug for FrozenLinkedVec<T, I, A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.list.fmt(f)
+    }
+}
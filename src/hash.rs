@@ -0,0 +1,21 @@
+//! Duplicate removal across the whole list, with a hash set of keys.
+
+use core::hash::Hash;
+
+use hashbrown::HashSet;
+
+use crate::{inner_types::StoreIndex, Allocator, LinkedVec};
+
+impl<T, I: StoreIndex + Copy, A: Allocator> LinkedVec<T, I, A> {
+    /// Removes every element whose key was already seen earlier in the
+    /// list, keeping only the first occurrence of each key.
+    ///
+    /// Unlike a consecutive dedup, duplicates are found anywhere in the
+    /// list. The elements are visited in logical order, and the survivors
+    /// keep their logical order. This takes *O*(n) expected time, and
+    /// keeps every key until it returns.
+    pub fn dedup_all_by_key<K: Hash + Eq, F: FnMut(&mut T) -> K>(&mut self, mut key: F) {
+        let mut seen = HashSet::with_capacity(self.len());
+        self.retain_with_index(|_, x| seen.insert(key(x)));
+    }
+}
@@ -0,0 +1,214 @@
+use alloc::vec::Vec;
+use core::{fmt::Debug, ops::Deref};
+
+use crate::{index_out_of_bounds, inner_types::StoreIndex, Allocator, Global, LinkedVec};
+
+/// The smallest distance between two checkpoints.
+const MIN_STRIDE: usize = 8;
+
+/// A [`LinkedVec`] that records the physical index of every `k`-th
+/// element, for sub-linear logical indexing.
+///
+/// `k` follows the square root of the length, and the records are rebuilt
+/// when the length moves too far from `k * k`. [`get_l`], [`insert_l`] and
+/// [`remove_l`] then cost *O*(√n) time. Pushing to the front and popping
+/// also costs *O*(√n), so lists that are only used at their ends are
+/// better off as a plain [`LinkedVec`].
+///
+/// It dereferences to the list for every read API.
+///
+/// [`get_l`]: IndexedLinkedVec::get_l
+/// [`insert_l`]: IndexedLinkedVec::insert_l
+/// [`remove_l`]: IndexedLinkedVec::remove_l
+pub struct IndexedLinkedVec<T, I: StoreIndex + Copy = usize, A: Allocator = Global> {
+    list: LinkedVec<T, I, A>,
+    stride: usize,
+    /// The physical index of every element whose logical index is a
+    /// multiple of `stride`.
+    checkpoints: Vec<usize>,
+}
+
+impl<T, I: StoreIndex + Copy> IndexedLinkedVec<T, I> {
+    pub fn new() -> Self {
+        Self::from(LinkedVec::new())
+    }
+}
+
+impl<T, I: StoreIndex + Copy, A: Allocator> IndexedLinkedVec<T, I, A> {
+    /// Returns a reference to the element at logical `index`, or `None` if
+    /// the index is out of bounds.
+    #[must_use]
+    pub fn get_l(&self, index: usize) -> Option<&T> {
+        self.nth_p(index).map(|p| self.list.index_p(p))
+    }
+
+    /// Returns a mutable reference to the element at logical `index`, or
+    /// `None` if the index is out of bounds.
+    #[must_use]
+    pub fn get_l_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.nth_p(index).map(|p| self.list.index_p_mut(p))
+    }
+
+    /// Inserts an element at logical `index` in the linked list and last
+    /// in the physical array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert_l(&mut self, index: usize, value: T) {
+        let len = self.list.len();
+        if index > len {
+            index_out_of_bounds(index, len)
+        }
+        let target = self.nth_p(index);
+        self.list.insert_before_p(target, value);
+
+        // Every checkpoint at or after `index` now belongs to the element
+        // before it.
+        let first = index.div_ceil(self.stride);
+        for cp in &mut self.checkpoints[first..] {
+            *cp = self.list.data[*cp].prev.unwrap().to_usize();
+        }
+        if len.is_multiple_of(self.stride) {
+            self.checkpoints.push(self.list.tail.unwrap().to_usize());
+        }
+        self.resize_stride();
+    }
+
+    /// Removes and returns the element at logical `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    pub fn remove_l(&mut self, index: usize) -> T {
+        let len = self.list.len();
+        let Some(removed) = self.nth_p(index) else {
+            index_out_of_bounds(index, len)
+        };
+
+        // Every checkpoint at or after `index` now belongs to the element
+        // after it, except at the tail.
+        let first = index.div_ceil(self.stride);
+        let mut past_tail = false;
+        for cp in &mut self.checkpoints[first..] {
+            match self.list.data[*cp].next {
+                Some(next) => *cp = next.to_usize(),
+                None => past_tail = true,
+            }
+        }
+        if past_tail {
+            self.checkpoints.pop();
+        }
+
+        let value = self.list.in_swap_remove(removed);
+        // The physically last node was moved into the freed slot
+        if let Some(cp) = self.checkpoints.iter_mut().find(|cp| **cp == len - 1) {
+            *cp = removed;
+        }
+        self.resize_stride();
+        value
+    }
+
+    /// Inserts an element first in the linked list and last in the physical array.
+    pub fn push_front(&mut self, value: T) {
+        self.insert_l(0, value)
+    }
+
+    /// Inserts an element last in the linked list and last in the physical array.
+    pub fn push_back(&mut self, value: T) {
+        self.insert_l(self.list.len(), value)
+    }
+
+    /// Remove and return first element in the linked list, if any.
+    pub fn pop_front(&mut self) -> Option<T> {
+        (!self.list.is_empty()).then(|| self.remove_l(0))
+    }
+
+    /// Remove and return last element in the linked list, if any.
+    pub fn pop_back(&mut self) -> Option<T> {
+        let len = self.list.len();
+        (len > 0).then(|| self.remove_l(len - 1))
+    }
+
+    /// Removes all elements from the list.
+    pub fn clear(&mut self) {
+        self.list.clear();
+        self.rebuild();
+    }
+
+    /// Returns the number of heap bytes allocated for the physical array
+    /// and the checkpoints.
+    pub fn allocated_bytes(&self) -> usize {
+        self.list.allocated_bytes() + self.checkpoints.capacity() * core::mem::size_of::<usize>()
+    }
+
+    /// Gives the plain list back.
+    pub fn into_inner(self) -> LinkedVec<T, I, A> {
+        self.list
+    }
+
+    /// Gets the physical index of the element at logical `index`, walking
+    /// from the closest checkpoint.
+    fn nth_p(&self, index: usize) -> Option<usize> {
+        if index >= self.list.len() {
+            return None;
+        }
+        let mut j = index / self.stride;
+        if index % self.stride > self.stride / 2 && j + 1 < self.checkpoints.len() {
+            j += 1;
+        }
+        let hint = (j * self.stride, self.checkpoints[j]);
+        self.list.nth_p_from(Some(hint), index)
+    }
+
+    /// Rebuilds the checkpoints if the stride is far from the square root
+    /// of the length.
+    fn resize_stride(&mut self) {
+        let len = self.list.len();
+        let square = self.stride * self.stride;
+        if len > 4 * square || (self.stride > MIN_STRIDE && 4 * len < square) {
+            self.rebuild();
+        }
+    }
+
+    fn rebuild(&mut self) {
+        self.stride = self.list.len().isqrt().max(MIN_STRIDE);
+        self.checkpoints.clear();
+        self.checkpoints
+            .extend(self.list.indices_l().step_by(self.stride));
+    }
+}
+
+impl<T, I: StoreIndex + Copy, A: Allocator> From<LinkedVec<T, I, A>> for IndexedLinkedVec<T, I, A> {
+    /// Records the checkpoints of `list`. This operation should compute in
+    /// *O*(n) time.
+    fn from(list: LinkedVec<T, I, A>) -> Self {
+        let mut ret = Self {
+            list,
+            stride: MIN_STRIDE,
+            checkpoints: Vec::new(),
+        };
+        ret.rebuild();
+        ret
+    }
+}
+
+impl<T, I: StoreIndex + Copy> Default for IndexedLinkedVec<T, I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, I: StoreIndex + Copy, A: Allocator> Deref for IndexedLinkedVec<T, I, A> {
+    type Target = LinkedVec<T, I, A>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+impl<T: Debug, I: StoreIndex + Copy, A: Allocator> Debug for IndexedLinkedVec<T, I, A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.list.fmt(f)
+    }
+}
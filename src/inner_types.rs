@@ -1,6 +1,5 @@
 use alloc::borrow::ToOwned;
 use core::{borrow::Borrow, fmt::Debug};
-use nonmax;
 
 macro_rules! debug_unwrap {
     ($result:expr) => {
@@ -148,15 +147,43 @@ storeindex_for_nonmax!(u64, nonmax::NonMaxU64);
 storeindex_for_nonmax!(u128, nonmax::NonMaxU128);
 storeindex_for_nonmax!(usize, nonmax::NonMaxUsize);
 
+/// A node of the physical array.
+///
+/// `next` and `prev` are the physical indices of the neighboring nodes,
+/// or `None` at the ends of the list. With the `repr-c` feature, the
+/// fields are laid out in declaration order as with `#[repr(C)]`, so
+/// a node can be described on the other side of an FFI boundary as long
+/// as `T` and `Option<I>` can be. Otherwise, the layout is unspecified.
+///
+/// Either way, a node is no larger than `T` followed by the two links,
+/// rounded up to the alignment of the node. The links are as small as
+/// `I` for the `nonmax` index types, whose `Option` uses the niche, and
+/// twice that for the primitive integers. So on common targets:
+///
+/// | `T`   | `I`                   | Node size |
+/// |-------|-----------------------|-----------|
+/// | `u16` | `nonmax::NonMaxU8`    | 4         |
+/// | `u16` | `u8`                  | 6         |
+/// | `u32` | `nonmax::NonMaxU16`   | 8         |
+/// | `u32` | `u16`                 | 12        |
+/// | `u16` | [`U24`](crate::U24)   | 8         |
+/// | `u64` | `nonmax::NonMaxU32`   | 16        |
+/// | `u64` | `u32`                 | 24        |
+/// | `u64` | `nonmax::NonMaxU64`   | 24        |
+/// | `u64` | `u64`                 | 40        |
+///
+/// [`LinkedVec::node_size`](crate::LinkedVec::node_size) gives the size
+/// at runtime.
 #[derive(Debug, Default)]
-pub(super) struct VecNode<T, I = usize> {
+#[cfg_attr(feature = "repr-c", repr(C))]
+pub struct VecNode<T, I = usize> {
     pub payload: T,
     pub next: Option<I>,
     pub prev: Option<I>,
 }
 
 impl<T, I> VecNode<T, I> {
-    pub const fn new(payload: T) -> Self {
+    pub(crate) const fn new(payload: T) -> Self {
         VecNode {
             payload,
             next: None,
@@ -181,7 +208,7 @@ impl<T: Clone, I: Clone> VecNode<T, I> {
     /// with the referrent. However, we still need something to
     /// replace the clone method for when we want to clone
     /// collectios with multiple nodes (LinkedVec).
-    pub fn not_clone(&self) -> Self {
+    pub(crate) fn not_clone(&self) -> Self {
         Self {
             payload: self.payload.clone(),
             next: self.next.clone(),
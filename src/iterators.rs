@@ -1,27 +1,184 @@
 use alloc::vec::Vec;
+use core::{
+    fmt::Debug,
+    iter::{FusedIterator, Sum},
+    marker::PhantomData,
+};
 
 pub use crate::iterators::SafeIterMut as IterMut;
 use crate::{
     inner_types::{StoreIndex, VecNode},
-    LinkedVec,
+    Allocator, Global, LinkedVec,
 };
 
+/// Moves a cursor position to the next element, or to the head from the
+/// "ghost" non-element.
+fn step_next<T, I: Copy + StoreIndex, A: Allocator>(
+    list: &LinkedVec<T, I, A>,
+    index_la: &mut usize,
+    current_pa: &mut Option<usize>,
+) {
+    list.links().step_next(index_la, current_pa);
+}
+
+/// Moves a cursor position to the previous element, or to the tail from the
+/// "ghost" non-element.
+fn step_prev<T, I: Copy + StoreIndex, A: Allocator>(
+    list: &LinkedVec<T, I, A>,
+    index_la: &mut usize,
+    current_pa: &mut Option<usize>,
+) {
+    list.links().step_prev(index_la, current_pa);
+}
+
+/// Gets the physical index of the element `n` places after the next one
+/// (or before the previous one), without crossing the "ghost" non-element.
+fn peek_nth_p<T, I: Copy + StoreIndex, A: Allocator>(
+    list: &LinkedVec<T, I, A>,
+    current_pa: Option<usize>,
+    n: usize,
+    forward: bool,
+) -> Option<usize> {
+    let step = |x: Option<I>| {
+        if forward {
+            list.get_next(x)
+        } else {
+            list.get_prev(x)
+        }
+    };
+    let mut index = step(current_pa.map(I::from_usize))?;
+    for _ in 0..n {
+        index = step(Some(index))?;
+    }
+    Some(index.to_usize())
+}
+
+/// Moves a cursor position forward while the next element satisfies
+/// `pred`, and returns how many times it moved.
+fn advance_while<T, I: Copy + StoreIndex, A: Allocator, F: FnMut(&T) -> bool>(
+    list: &LinkedVec<T, I, A>,
+    index_la: &mut usize,
+    current_pa: &mut Option<usize>,
+    mut pred: F,
+) -> usize {
+    let mut moved = 0;
+    while let Some(next) = list.get_next(current_pa.map(I::from_usize)) {
+        if !pred(&list.data[next.to_usize()].payload) {
+            break;
+        }
+        step_next(list, index_la, current_pa);
+        moved += 1;
+    }
+    moved
+}
+
+/// Moves a cursor position forward to the next element that satisfies
+/// `pred`, or to the "ghost" non-element if there is none.
+fn skip_to<T, I: Copy + StoreIndex, A: Allocator, F: FnMut(&T) -> bool>(
+    list: &LinkedVec<T, I, A>,
+    index_la: &mut usize,
+    current_pa: &mut Option<usize>,
+    mut pred: F,
+) -> bool {
+    loop {
+        step_next(list, index_la, current_pa);
+        match *current_pa {
+            Some(current) if pred(&list.data[current].payload) => return true,
+            Some(_) => {}
+            None => return false,
+        }
+    }
+}
+
+/// Movement and lookups shared by [`VecCursor`], [`VecCursorMut`] and
+/// [`NonEmptyVecCursor`], for algorithms that work with any of them.
+///
+/// The methods mirror the inherent ones of each cursor, which keep their
+/// own signatures. Every method has the semantics of the cursor it is
+/// called on, so a [`NonEmptyVecCursor`] wraps around where the others
+/// reach the "ghost" non-element.
+///
+/// The peeks only take `&self`. With the trait in scope, they take
+/// precedence over the mutable peeks of [`VecCursorMut`] in method call
+/// syntax. Those can still be called with a path, as in
+/// `VecCursorMut::peek_next(&mut cursor)`.
+pub trait CursorOps {
+    type Item;
+
+    /// Moves the cursor to the next element of the linked list.
+    fn move_next(&mut self);
+
+    /// Moves the cursor to the previous element of the linked list.
+    fn move_prev(&mut self);
+
+    /// Returns the cursor position within the linked list, or `None` at
+    /// the "ghost" non-element.
+    fn index_l(&self) -> Option<usize>;
+
+    /// Returns the cursor position within the physical array, or `None` at
+    /// the "ghost" non-element.
+    fn index_p(&self) -> Option<usize>;
+
+    /// Returns a reference to the current element, or `None` at the
+    /// "ghost" non-element.
+    fn peek_current(&self) -> Option<&Self::Item>;
+
+    /// Returns a reference to the element a call to `move_next` would
+    /// move to, or `None` if it would reach the "ghost" non-element.
+    fn peek_next(&self) -> Option<&Self::Item>;
+
+    /// Returns a reference to the element a call to `move_prev` would
+    /// move to, or `None` if it would reach the "ghost" non-element.
+    fn peek_prev(&self) -> Option<&Self::Item>;
+}
+
+/// A cursor position saved without borrowing the list.
+///
+/// Returned by [`VecCursor::save`] and [`VecCursorMut::save`]. Along with
+/// the position, it records the version of the list, so that
+/// [`LinkedVec::resume`] can tell if the list has been structurally
+/// changed since. Changing elements in place does not make a token stale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CursorToken {
+    pub(crate) index_l: Option<usize>,
+    pub(crate) index_p: Option<usize>,
+    pub(crate) version: u64,
+}
+
+impl CursorToken {
+    /// Returns the saved position within the linked list, or `None` if the
+    /// cursor was pointing to the "ghost" non-element.
+    #[must_use]
+    pub fn index_l(&self) -> Option<usize> {
+        self.index_l
+    }
+
+    /// Returns the saved position within the physical array, or `None` if
+    /// the cursor was pointing to the "ghost" non-element.
+    #[must_use]
+    pub fn index_p(&self) -> Option<usize> {
+        self.index_p
+    }
+}
+
 #[derive(Debug)]
-pub struct VecCursor<'a, T: 'a, I: Copy + StoreIndex> {
+pub struct VecCursor<'a, T: 'a, I: Copy + StoreIndex, A: Allocator = Global> {
     pub(crate) index_la: usize,
     pub(crate) current_pa: Option<usize>, // Optionally replace usize with I
-    pub(crate) list: &'a LinkedVec<T, I>,
+    pub(crate) list: &'a LinkedVec<T, I, A>,
 }
 
-impl<'a, T: 'a, I: Copy + StoreIndex> VecCursor<'a, T, I> {
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> VecCursor<'a, T, I, A> {
     /// Returns a new cursor with known index_l and index_p.
     ///
+    /// # Safety
+    ///
     /// index_l and index_p must both either be Some or None
     /// If they are Some, they must be corresponding index (index_l)
     /// and physical index (index_p) in list.
     #[must_use]
     pub unsafe fn new_with_index_unchecked(
-        list: &'a LinkedVec<T, I>,
+        list: &'a LinkedVec<T, I, A>,
         index_l: Option<usize>,
         index_p: Option<usize>,
     ) -> Self {
@@ -58,6 +215,17 @@ impl<'a, T: 'a, I: Copy + StoreIndex> VecCursor<'a, T, I> {
         self.current_pa
     }
 
+    /// Saves the position of the cursor, to resume it later with
+    /// [`LinkedVec::resume`] once the borrow has ended.
+    #[must_use]
+    pub fn save(&self) -> CursorToken {
+        CursorToken {
+            index_l: self.index_l(),
+            index_p: self.index_p(),
+            version: self.list.version,
+        }
+    }
+
     /// Returns a reference to the element that the cursor is currently
     /// pointing to.
     ///
@@ -65,13 +233,13 @@ impl<'a, T: 'a, I: Copy + StoreIndex> VecCursor<'a, T, I> {
     /// "ghost" non-element.
     #[must_use]
     pub fn current(&self) -> Option<&'a T> {
-        Some(self.list.get_p(self.current_pa?))
+        Some(self.list.index_p(self.current_pa?))
     }
 
     /// Returns a reference to the list that the cursor is pointing
     /// to.
     #[must_use]
-    pub fn get_list(&self) -> &'a LinkedVec<T, I> {
+    pub fn get_list(&self) -> &'a LinkedVec<T, I, A> {
         self.list
     }
 
@@ -81,19 +249,7 @@ impl<'a, T: 'a, I: Copy + StoreIndex> VecCursor<'a, T, I> {
     /// the first element of the list. If it is pointing to the last
     /// element of the list, then this will move it to the "ghost" non-element.
     pub fn move_next(&mut self) {
-        match self.current_pa {
-            // We had no current element; the cursor was sitting at the start position
-            // Next element should be the head of the list
-            None => {
-                self.current_pa = self.list.head.map(|x| x.to_usize());
-                self.index_la = 0;
-            }
-            // We had a previous element, so let's go to its next
-            Some(current) => {
-                self.current_pa = self.list.data[current].next.map(|x| x.to_usize());
-                self.index_la += 1;
-            }
-        }
+        step_next(self.list, &mut self.index_la, &mut self.current_pa)
     }
 
     /// Moves the cursor to the previous element of the linked list.
@@ -102,19 +258,27 @@ impl<'a, T: 'a, I: Copy + StoreIndex> VecCursor<'a, T, I> {
     /// the last element of the list. If it is pointing to the first
     /// element of the list, then this will move it to the "ghost" non-element.
     pub fn move_prev(&mut self) {
-        match self.current_pa {
-            // We had no current element; the cursor was sitting at the start position
-            // Next element should be the tail of the list
-            None => {
-                self.current_pa = self.list.tail.map(|x| x.to_usize());
-                self.index_la = self.list.len().checked_sub(1).unwrap_or(0);
-            }
-            // We had a previous element, so let's go to its prev
-            Some(current) => {
-                self.current_pa = self.list.data[current].prev.map(|x| x.to_usize());
-                self.index_la = self.index_la.checked_sub(1).unwrap_or(self.list.len());
-            }
-        }
+        step_prev(self.list, &mut self.index_la, &mut self.current_pa)
+    }
+
+    /// Moves the cursor forward while the next element satisfies `pred`,
+    /// and returns how many times it moved.
+    ///
+    /// The cursor ends on the last element of the run that satisfies
+    /// `pred`, or stays put if the next element does not. It never moves
+    /// onto the "ghost" non-element.
+    pub fn advance_while<F: FnMut(&T) -> bool>(&mut self, pred: F) -> usize {
+        advance_while(self.list, &mut self.index_la, &mut self.current_pa, pred)
+    }
+
+    /// Moves the cursor forward to the next element that satisfies `pred`,
+    /// and returns whether there was one.
+    ///
+    /// The current element is not checked, so calling this again finds
+    /// the following match. If no element after the cursor satisfies
+    /// `pred`, the cursor ends at the "ghost" non-element.
+    pub fn skip_to<F: FnMut(&T) -> bool>(&mut self, pred: F) -> bool {
+        skip_to(self.list, &mut self.index_la, &mut self.current_pa, pred)
     }
 
     /// Returns a reference to the next element.
@@ -141,6 +305,29 @@ impl<'a, T: 'a, I: Copy + StoreIndex> VecCursor<'a, T, I> {
         prev.current()
     }
 
+    /// Returns a reference to the element `n` places after the next one,
+    /// so `peek_nth(0)` is the same as [`peek_next`](Self::peek_next).
+    ///
+    /// This returns `None` if the walk reaches the "ghost" non-element.
+    /// The cursor is not moved.
+    #[must_use]
+    pub fn peek_nth(&self, n: usize) -> Option<&'a T> {
+        let index = peek_nth_p(self.list, self.current_pa, n, true)?;
+        Some(self.list.index_p(index))
+    }
+
+    /// Returns a reference to the element `n` places before the previous
+    /// one, so `peek_prev_nth(0)` is the same as
+    /// [`peek_prev`](Self::peek_prev).
+    ///
+    /// This returns `None` if the walk reaches the "ghost" non-element.
+    /// The cursor is not moved.
+    #[must_use]
+    pub fn peek_prev_nth(&self, n: usize) -> Option<&'a T> {
+        let index = peek_nth_p(self.list, self.current_pa, n, false)?;
+        Some(self.list.index_p(index))
+    }
+
     /// Equivalint to `self.list().front()`
     #[must_use]
     pub fn front(&self) -> Option<&'a T> {
@@ -160,16 +347,57 @@ impl<'a, T: 'a, I: Copy + StoreIndex> VecCursor<'a, T, I> {
     /// will not change the state of the other. If you would like
     /// to keep the state of `NonEmptyVecCursor`, then convert it back to
     /// a `VecCursor`.
-    pub fn as_nonempty_cursor(&self) -> Option<NonEmptyVecCursor<'a, T, I>> {
+    pub fn as_nonempty_cursor(&self) -> Option<NonEmptyVecCursor<'a, T, I, A>> {
         Some(NonEmptyVecCursor {
             index_la: self.index_la,
             current_pa: self.current_pa?,
-            list: &self.list,
+            list: self.list,
         })
     }
+
+    /// Converts the cursor into an iterator over the elements from the
+    /// front of the list up to and including the current element.
+    ///
+    /// Use `rev()` on the result to walk back from the cursor to the
+    /// front. If the cursor is pointing to the "ghost" non-element, the
+    /// iterator is empty.
+    #[must_use]
+    pub fn into_iter_back(self) -> Iter<'a, T, I, A> {
+        match self.current_pa {
+            Some(current) => Iter::new_range(
+                self.list,
+                self.list.head.map_or(0, |x| x.to_usize()),
+                current,
+                self.index_la + 1,
+            ),
+            None => Iter::empty(self.list),
+        }
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> IntoIterator for VecCursor<'a, T, I, A> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, I, A>;
+
+    /// Converts the cursor into an iterator over the elements from the
+    /// current element to the back of the list.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element, the iterator
+    /// is empty.
+    fn into_iter(self) -> Self::IntoIter {
+        match self.current_pa {
+            Some(current) => Iter::new_range(
+                self.list,
+                current,
+                self.list.tail.map_or(0, |x| x.to_usize()),
+                self.list.len() - self.index_la,
+            ),
+            None => Iter::empty(self.list),
+        }
+    }
 }
 
-impl<T, I: Copy + StoreIndex> Clone for VecCursor<'_, T, I> {
+impl<T, I: Copy + StoreIndex, A: Allocator> Clone for VecCursor<'_, T, I, A> {
     fn clone(&self) -> Self {
         // Destruct-assign self into individual variables
         // with same names as fields
@@ -189,24 +417,58 @@ impl<T, I: Copy + StoreIndex> Clone for VecCursor<'_, T, I> {
     }
 }
 
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> CursorOps for VecCursor<'a, T, I, A> {
+    type Item = T;
+
+    fn move_next(&mut self) {
+        Self::move_next(self)
+    }
+
+    fn move_prev(&mut self) {
+        Self::move_prev(self)
+    }
+
+    fn index_l(&self) -> Option<usize> {
+        Self::index_l(self)
+    }
+
+    fn index_p(&self) -> Option<usize> {
+        Self::index_p(self)
+    }
+
+    fn peek_current(&self) -> Option<&T> {
+        self.current()
+    }
+
+    fn peek_next(&self) -> Option<&T> {
+        Self::peek_next(self)
+    }
+
+    fn peek_prev(&self) -> Option<&T> {
+        Self::peek_prev(self)
+    }
+}
+
 #[derive(Debug)]
-pub struct VecCursorMut<'a, T: 'a, I: Copy + StoreIndex> {
+pub struct VecCursorMut<'a, T: 'a, I: Copy + StoreIndex, A: Allocator = Global> {
     pub(crate) index_la: usize,
     pub(crate) current_pa: Option<usize>, // Optionally replace usize with I
-    pub(crate) list: &'a mut LinkedVec<T, I>,
+    pub(crate) list: &'a mut LinkedVec<T, I, A>,
 }
 
-impl<'a, T: 'a, I: Copy + StoreIndex> VecCursorMut<'a, T, I> {
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> VecCursorMut<'a, T, I, A> {
     /// Returns a new cursor with known index_l and index_p.
     ///
     /// Usefull for upgrading from a VecCursor.
     ///
+    /// # Safety
+    ///
     /// index_l and index_p must both either be Some or None
     /// If they are Some, they must be corresponding index (index_l)
     /// and physical index (index_p) in list.
     #[must_use]
     pub unsafe fn new_with_index_unchecked(
-        list: &'a mut LinkedVec<T, I>,
+        list: &'a mut LinkedVec<T, I, A>,
         index_l: Option<usize>,
         index_p: Option<usize>,
     ) -> Self {
@@ -243,6 +505,17 @@ impl<'a, T: 'a, I: Copy + StoreIndex> VecCursorMut<'a, T, I> {
         self.current_pa
     }
 
+    /// Saves the position of the cursor, to resume it later with
+    /// [`LinkedVec::resume_mut`] once the borrow has ended.
+    #[must_use]
+    pub fn save(&self) -> CursorToken {
+        CursorToken {
+            index_l: self.index_l(),
+            index_p: self.index_p(),
+            version: self.list.version,
+        }
+    }
+
     /// Returns a reference to the element that the cursor is currently
     /// pointing to.
     ///
@@ -250,14 +523,158 @@ impl<'a, T: 'a, I: Copy + StoreIndex> VecCursorMut<'a, T, I> {
     /// "ghost" non-element.
     #[must_use]
     pub fn current(&mut self) -> Option<&mut T> {
-        Some(self.list.get_p_mut(self.current_pa?))
+        Some(self.list.index_p_mut(self.current_pa?))
+    }
+
+    /// Converts the cursor into a reference to the element it is pointing
+    /// to, borrowed for as long as the list was.
+    ///
+    /// This returns `None` if the cursor is currently pointing to the
+    /// "ghost" non-element.
+    #[must_use]
+    pub fn into_current(self) -> Option<&'a mut T> {
+        Some(self.list.index_p_mut(self.current_pa?))
+    }
+
+    /// Returns the previous, current and next elements at once, with only
+    /// the current one borrowed mutably.
+    ///
+    /// The neighbors are the elements [`peek_prev`](Self::peek_prev) and
+    /// [`peek_next`](Self::peek_next) return, so they can be read while the
+    /// current element is being changed.
+    #[must_use]
+    pub fn current_with_neighbors(&mut self) -> (Option<&T>, Option<&mut T>, Option<&T>) {
+        let current = self.current_pa.map(I::from_usize);
+        let prev = self.list.get_prev(current).map(|x| x.to_usize());
+        let next = self.list.get_next(current).map(|x| x.to_usize());
+        let Some(current) = self.current_pa else {
+            let data = &self.list.data;
+            return (
+                prev.map(|p| &data[p].payload),
+                None,
+                next.map(|p| &data[p].payload),
+            );
+        };
+
+        // The neighbors of an element are never the element itself
+        let (before, rest) = self.list.data.split_at_mut(current);
+        let (node, after) = rest.split_first_mut().unwrap();
+        let get = |p: usize| {
+            if p < current {
+                &before[p].payload
+            } else {
+                &after[p - current - 1].payload
+            }
+        };
+        (prev.map(get), Some(&mut node.payload), next.map(get))
+    }
+
+    /// Returns a cursor at the same position that borrows this one.
+    ///
+    /// Moving the returned cursor does not move this one. It is meant for
+    /// passing to functions that take a `VecCursorMut` by value.
+    #[must_use]
+    pub fn reborrow(&mut self) -> VecCursorMut<'_, T, I, A> {
+        VecCursorMut {
+            index_la: self.index_la,
+            current_pa: self.current_pa,
+            list: self.list,
+        }
     }
 
     /// Returns a reference to the list that the cursor is pointing
     /// to.
     #[must_use]
-    pub fn get_list(&self) -> &LinkedVec<T, I> {
+    pub fn get_list(&self) -> &LinkedVec<T, I, A> {
+        self.list
+    }
+
+    /// Inserts an element after the current one, last in the physical
+    /// array.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then the new
+    /// element is inserted at the front of the list. The cursor does not
+    /// move.
+    pub fn insert_after(&mut self, value: T) {
+        let inserted = self.list.push_p(value);
+        self.list
+            .insert_node_after(inserted, self.current_pa.map(I::from_usize));
+        paranoid_check!(self.list);
+    }
+
+    /// Inserts an element before the current one, last in the physical
+    /// array.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then the new
+    /// element is inserted at the back of the list. The cursor does not
+    /// move.
+    pub fn insert_before(&mut self, value: T) {
+        let inserted = self.list.push_p(value);
         self.list
+            .insert_node_before(inserted, self.current_pa.map(I::from_usize));
+        if self.current_pa.is_some() {
+            self.index_la += 1;
+        }
+        paranoid_check!(self.list);
+    }
+
+    /// Removes the current element and returns it, moving the cursor to
+    /// the next element.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then nothing
+    /// is removed and `None` is returned. The physically last node is moved
+    /// into the freed slot, as with [`LinkedVec::swap_remove`].
+    pub fn remove_current(&mut self) -> Option<T> {
+        let current = self.current_pa?;
+        let mut next = self.list.data[current].next.map(|x| x.to_usize());
+        let last = self.list.len() - 1;
+        let payload = self.list.in_swap_remove(current);
+        // The next node may have been moved into the freed slot
+        if next == Some(last) {
+            next = Some(current);
+        }
+        self.current_pa = next;
+        if next.is_none() {
+            self.index_la = self.list.len();
+        }
+        Some(payload)
+    }
+
+    /// Moves everything after the current element into `out`, in logical
+    /// order.
+    ///
+    /// `out` is cleared first, but keeps its allocation, so splitting
+    /// repeatedly into the same list does not allocate once it is large
+    /// enough. If the cursor is pointing to the "ghost" non-element then
+    /// the whole list is moved. The freed slots are filled from the end of
+    /// the physical array, as with [`LinkedVec::pop_back_n`].
+    pub fn split_after_into(&mut self, out: &mut LinkedVec<T, I, A>) {
+        let n = match self.current_pa {
+            Some(_) => self.list.len() - self.index_la - 1,
+            None => self.list.len(),
+        };
+        self.list.pop_end_into(n, true, out);
+        match self.current_pa {
+            Some(_) => self.current_pa = self.list.tail.map(|x| x.to_usize()),
+            None => self.index_la = 0,
+        }
+    }
+
+    /// Moves everything before the current element into `out`, in logical
+    /// order.
+    ///
+    /// See [`split_after_into`](Self::split_after_into). The current
+    /// element becomes the front of the list.
+    pub fn split_before_into(&mut self, out: &mut LinkedVec<T, I, A>) {
+        let n = match self.current_pa {
+            Some(_) => self.index_la,
+            None => self.list.len(),
+        };
+        self.list.pop_end_into(n, false, out);
+        self.index_la = 0;
+        if self.current_pa.is_some() {
+            self.current_pa = self.list.head.map(|x| x.to_usize());
+        }
     }
 
     /// Moves the cursor to the next element of the linked list.
@@ -266,19 +683,7 @@ impl<'a, T: 'a, I: Copy + StoreIndex> VecCursorMut<'a, T, I> {
     /// the first element of the list. If it is pointing to the last
     /// element of the list, then this will move it to the "ghost" non-element.
     pub fn move_next(&mut self) {
-        match self.current_pa {
-            // We had no current element; the cursor was sitting at the start position
-            // Next element should be the head of the list
-            None => {
-                self.current_pa = self.list.head.map(|x| x.to_usize());
-                self.index_la = 0;
-            }
-            // We had a previous element, so let's go to its next
-            Some(current) => {
-                self.current_pa = self.list.data[current].next.map(|x| x.to_usize());
-                self.index_la += 1;
-            }
-        }
+        step_next(self.list, &mut self.index_la, &mut self.current_pa)
     }
 
     /// Moves the cursor to the previous element of the linked list.
@@ -287,19 +692,27 @@ impl<'a, T: 'a, I: Copy + StoreIndex> VecCursorMut<'a, T, I> {
     /// the last element of the list. If it is pointing to the first
     /// element of the list, then this will move it to the "ghost" non-element.
     pub fn move_prev(&mut self) {
-        match self.current_pa {
-            // We had no current element; the cursor was sitting at the start position
-            // Next element should be the tail of the list
-            None => {
-                self.current_pa = self.list.tail.map(|x| x.to_usize());
-                self.index_la = self.list.len().checked_sub(1).unwrap_or(0);
-            }
-            // We had a previous element, so let's go to its prev
-            Some(current) => {
-                self.current_pa = self.list.data[current].prev.map(|x| x.to_usize());
-                self.index_la = self.index_la.checked_sub(1).unwrap_or(self.list.len());
-            }
-        }
+        step_prev(self.list, &mut self.index_la, &mut self.current_pa)
+    }
+
+    /// Moves the cursor forward while the next element satisfies `pred`,
+    /// and returns how many times it moved.
+    ///
+    /// The cursor ends on the last element of the run that satisfies
+    /// `pred`, or stays put if the next element does not. It never moves
+    /// onto the "ghost" non-element.
+    pub fn advance_while<F: FnMut(&T) -> bool>(&mut self, pred: F) -> usize {
+        advance_while(self.list, &mut self.index_la, &mut self.current_pa, pred)
+    }
+
+    /// Moves the cursor forward to the next element that satisfies `pred`,
+    /// and returns whether there was one.
+    ///
+    /// The current element is not checked, so calling this again finds
+    /// the following match. If no element after the cursor satisfies
+    /// `pred`, the cursor ends at the "ghost" non-element.
+    pub fn skip_to<F: FnMut(&T) -> bool>(&mut self, pred: F) -> bool {
+        skip_to(self.list, &mut self.index_la, &mut self.current_pa, pred)
     }
 
     /// Returns a reference to the next element.
@@ -314,7 +727,7 @@ impl<'a, T: 'a, I: Copy + StoreIndex> VecCursorMut<'a, T, I> {
             .list
             .get_next(self.current_pa.map(|x| I::from_usize(x)))?
             .to_usize();
-        Some(self.list.get_p_mut(next_p))
+        Some(self.list.index_p_mut(next_p))
     }
 
     /// Returns a reference to the previous element.
@@ -329,7 +742,30 @@ impl<'a, T: 'a, I: Copy + StoreIndex> VecCursorMut<'a, T, I> {
             .list
             .get_prev(self.current_pa.map(|x| I::from_usize(x)))?
             .to_usize();
-        Some(self.list.get_p_mut(prev_p))
+        Some(self.list.index_p_mut(prev_p))
+    }
+
+    /// Returns a reference to the element `n` places after the next one,
+    /// so `peek_nth(0)` is the same as [`peek_next`](Self::peek_next).
+    ///
+    /// This returns `None` if the walk reaches the "ghost" non-element.
+    /// The cursor is not moved.
+    #[must_use]
+    pub fn peek_nth(&mut self, n: usize) -> Option<&mut T> {
+        let index = peek_nth_p(self.list, self.current_pa, n, true)?;
+        Some(self.list.index_p_mut(index))
+    }
+
+    /// Returns a reference to the element `n` places before the previous
+    /// one, so `peek_prev_nth(0)` is the same as
+    /// [`peek_prev`](Self::peek_prev).
+    ///
+    /// This returns `None` if the walk reaches the "ghost" non-element.
+    /// The cursor is not moved.
+    #[must_use]
+    pub fn peek_prev_nth(&mut self, n: usize) -> Option<&mut T> {
+        let index = peek_nth_p(self.list, self.current_pa, n, false)?;
+        Some(self.list.index_p_mut(index))
     }
 
     /// Equivalint to `self.list().front()`
@@ -355,11 +791,11 @@ impl<'a, T: 'a, I: Copy + StoreIndex> VecCursorMut<'a, T, I> {
     }
 
     #[must_use]
-    pub fn as_cursor(&self) -> VecCursor<'_, T, I> {
+    pub fn as_cursor(&self) -> VecCursor<'_, T, I, A> {
         VecCursor {
             index_la: self.index_la,
             current_pa: self.current_pa,
-            list: &self.list,
+            list: self.list,
         }
     }
 
@@ -369,102 +805,398 @@ impl<'a, T: 'a, I: Copy + StoreIndex> VecCursorMut<'a, T, I> {
     /// Changing the state of the resulting cursor
     /// will not change the state of the mutable cursor.
     #[must_use]
-    pub fn as_nonempty_cursor(&self) -> Option<NonEmptyVecCursor<'_, T, I>> {
+    pub fn as_nonempty_cursor(&self) -> Option<NonEmptyVecCursor<'_, T, I, A>> {
         Some(NonEmptyVecCursor {
             index_la: self.index_la,
             current_pa: self.current_pa?,
-            list: &self.list,
+            list: self.list,
         })
     }
+
+    /// Creates an iterator which uses a closure to determine if an element
+    /// after the cursor should be removed.
+    ///
+    /// This works like [`LinkedVec::extract_if`], except that only the
+    /// elements strictly after the current one are visited. If the cursor
+    /// is pointing to the "ghost" non-element, the whole list is visited.
+    /// The cursor keeps pointing to the same element, even if removing an
+    /// element moves it on the physical array.
+    pub fn extract_if_after<F>(&mut self, filter: F) -> ExtractIf<'_, T, I, A, F>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let remaining = match self.current_pa {
+            Some(_) => self.list.len() - self.index_la - 1,
+            None => self.list.len(),
+        };
+        ExtractIf {
+            next_pa: self
+                .list
+                .get_next(self.current_pa.map(I::from_usize))
+                .map(|x| x.to_usize()),
+            remaining,
+            anchor_pa: self.current_pa.as_mut(),
+            list: self.list,
+            pred: filter,
+        }
+    }
+
+    /// Converts the cursor into an iterator with mutable references over
+    /// the elements from the current element to the back of the list.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element, the iterator
+    /// is empty.
+    #[must_use]
+    pub fn into_iter_mut(self) -> IterMut<'a, T, I> {
+        let Some(current) = self.current_pa else {
+            return IterMut::new_range(self.list, 0, 0, 0);
+        };
+        let tail = self.list.tail.map_or(0, |x| x.to_usize());
+        let len = self.list.len() - self.index_la;
+        IterMut::new_range(self.list, current, tail, len)
+    }
 }
 
-/// No "ghost" non-element
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> CursorOps for VecCursorMut<'a, T, I, A> {
+    type Item = T;
+
+    fn move_next(&mut self) {
+        Self::move_next(self)
+    }
+
+    fn move_prev(&mut self) {
+        Self::move_prev(self)
+    }
+
+    fn index_l(&self) -> Option<usize> {
+        Self::index_l(self)
+    }
+
+    fn index_p(&self) -> Option<usize> {
+        Self::index_p(self)
+    }
+
+    fn peek_current(&self) -> Option<&T> {
+        self.current_pa.map(|p| &self.list.data[p].payload)
+    }
+
+    fn peek_next(&self) -> Option<&T> {
+        peek_nth_p(self.list, self.current_pa, 0, true).map(|p| &self.list.data[p].payload)
+    }
+
+    fn peek_prev(&self) -> Option<&T> {
+        peek_nth_p(self.list, self.current_pa, 0, false).map(|p| &self.list.data[p].payload)
+    }
+}
+
+/// Two mutable cursors over the same list.
+///
+/// The cursors can never point to the same element at the
+/// same time, so both current elements can be borrowed mutably at once.
+/// Both cursors may sit on the "ghost" non-element.
 #[derive(Debug)]
-pub struct NonEmptyVecCursor<'a, T: 'a, I: Copy + StoreIndex> {
-    index_la: usize,
-    current_pa: usize, // Optionally replace usize with I
-    list: &'a LinkedVec<T, I>,
+pub struct VecCursorPairMut<'a, T: 'a, I: Copy + StoreIndex, A: Allocator = Global> {
+    pub(crate) first_la: usize,
+    pub(crate) first_pa: Option<usize>,
+    pub(crate) second_la: usize,
+    pub(crate) second_pa: Option<usize>,
+    pub(crate) list: &'a mut LinkedVec<T, I, A>,
 }
 
-impl<'a, T: 'a, I: Copy + StoreIndex> NonEmptyVecCursor<'a, T, I> {
-    /// Returns the cursor position within the linked list.
-    #[must_use]
-    pub fn index_l(&self) -> usize {
-        self.index_la
-    }
-    /// Returns the cursor position within the physical array.
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> VecCursorPairMut<'a, T, I, A> {
+    /// Returns a read-only view of the first cursor.
     #[must_use]
-    pub fn index_p(&self) -> usize {
-        self.current_pa
+    pub fn first(&self) -> VecCursor<'_, T, I, A> {
+        VecCursor {
+            index_la: self.first_la,
+            current_pa: self.first_pa,
+            list: self.list,
+        }
     }
 
-    /// Returns a reference to the element that the cursor is currently
-    /// pointing to.
+    /// Returns a read-only view of the second cursor.
     #[must_use]
-    pub fn current(&self) -> &'a T {
-        self.list.get_p(self.current_pa)
+    pub fn second(&self) -> VecCursor<'_, T, I, A> {
+        VecCursor {
+            index_la: self.second_la,
+            current_pa: self.second_pa,
+            list: self.list,
+        }
     }
 
-    /// Moves the cursor to the next element of the linked list.
+    /// Returns mutable references to the elements that the first and
+    /// second cursor are pointing to.
     ///
-    /// If it is pointing to the last
-    /// element of the list, then this will move it to the front
-    /// and return false.
-    pub fn move_next(&mut self) -> bool {
-        match self.list.data[self.current_pa].next {
-            // Next element should be the head of the list
-            None => {
-                self.current_pa = self.list.head.unwrap().to_usize();
-                self.index_la = 0;
-                false
-            }
-            Some(next) => {
-                self.current_pa = next.to_usize();
-                self.index_la += 1;
-                true
+    /// Either is `None` if that cursor is pointing to the "ghost" non-element.
+    #[must_use]
+    pub fn current(&mut self) -> (Option<&mut T>, Option<&mut T>) {
+        match (self.first_pa, self.second_pa) {
+            (Some(a), Some(b)) => {
+                // The cursors never point to the same element
+                let [a, b] = self.list.get_many_mut_p([a, b]).unwrap();
+                (Some(a), Some(b))
             }
+            (Some(a), None) => (Some(self.list.index_p_mut(a)), None),
+            (None, Some(b)) => (None, Some(self.list.index_p_mut(b))),
+            (None, None) => (None, None),
         }
     }
 
-    /// Moves the cursor to the previous element of the linked list.
+    /// Swaps the elements the two cursors are pointing to.
     ///
-    /// If it is pointing to the first
-    /// element of the list, then this will move it to the back
-    /// and return false.
-    pub fn move_prev(&mut self) -> bool {
-        match self.list.data[self.current_pa].prev {
-            // Next element should be the tail of the list
-            None => {
-                self.current_pa = self.list.tail.unwrap().to_usize();
-                self.index_la = self.list.len() - 1;
-                false
-            }
-            Some(prev) => {
-                self.current_pa = prev.to_usize();
-                self.index_la -= 1;
+    /// Only the payloads are moved, the cursors stay at their positions.
+    /// Returns false and does nothing if either cursor is pointing to the
+    /// "ghost" non-element.
+    pub fn swap_current(&mut self) -> bool {
+        match (self.first_pa, self.second_pa) {
+            (Some(a), Some(b)) => {
+                self.list.swap_p(a, b);
                 true
             }
+            _ => false,
         }
     }
 
-    /// Returns a `VecCursor` pointing to the current element.
+    /// Moves the first cursor to the next element of the linked list.
     ///
-    /// Changing the state of either self or the resulting cursor
-    /// will not change the state of the other. If you would like
-    /// to keep the state of `VecCursor`, then convert it back to
-    /// a `NonEmptyVecCursor`.
-    pub fn as_cursor(&self) -> VecCursor<'a, T, I> {
-        VecCursor {
-            index_la: self.index_la,
-            current_pa: Some(self.current_pa),
-            list: &self.list,
+    /// If that element is the one the second cursor is pointing to,
+    /// the first cursor is not moved and false is returned.
+    pub fn move_first_next(&mut self) -> bool {
+        let mut moved = self.first();
+        moved.move_next();
+        self.try_set_first(moved.index_la, moved.current_pa)
+    }
+
+    /// Moves the first cursor to the previous element of the linked list.
+    ///
+    /// If that element is the one the second cursor is pointing to,
+    /// the first cursor is not moved and false is returned.
+    pub fn move_first_prev(&mut self) -> bool {
+        let mut moved = self.first();
+        moved.move_prev();
+        self.try_set_first(moved.index_la, moved.current_pa)
+    }
+
+    /// Moves the second cursor to the next element of the linked list.
+    ///
+    /// If that element is the one the first cursor is pointing to,
+    /// the second cursor is not moved and false is returned.
+    pub fn move_second_next(&mut self) -> bool {
+        let mut moved = self.second();
+        moved.move_next();
+        self.try_set_second(moved.index_la, moved.current_pa)
+    }
+
+    /// Moves the second cursor to the previous element of the linked list.
+    ///
+    /// If that element is the one the first cursor is pointing to,
+    /// the second cursor is not moved and false is returned.
+    pub fn move_second_prev(&mut self) -> bool {
+        let mut moved = self.second();
+        moved.move_prev();
+        self.try_set_second(moved.index_la, moved.current_pa)
+    }
+
+    fn try_set_first(&mut self, index_la: usize, current_pa: Option<usize>) -> bool {
+        if current_pa.is_some() && current_pa == self.second_pa {
+            return false;
+        }
+        self.first_la = index_la;
+        self.first_pa = current_pa;
+        true
+    }
+
+    fn try_set_second(&mut self, index_la: usize, current_pa: Option<usize>) -> bool {
+        if current_pa.is_some() && current_pa == self.first_pa {
+            return false;
         }
+        self.second_la = index_la;
+        self.second_pa = current_pa;
+        true
     }
 }
 
-impl<T, I: Copy + StoreIndex> Clone for NonEmptyVecCursor<'_, T, I> {
-    fn clone(&self) -> Self {
-        // Destruct-assign self into individual variables
+/// Remembers the last logical index looked up in a [`LinkedVec`], so that
+/// lookups near it are cheap.
+///
+/// A lookup walks from whichever of the front, the back and the last
+/// index looked up is closest. Visiting the indices in order, forward or
+/// backward, costs *O*(1) per lookup. Since it borrows the list, the
+/// remembered position cannot go stale.
+#[derive(Debug)]
+pub struct Finger<'a, T: 'a, I: Copy + StoreIndex, A: Allocator = Global> {
+    list: &'a LinkedVec<T, I, A>,
+    last: Option<(usize, usize)>,
+}
+
+impl<T, I: Copy + StoreIndex, A: Allocator> Clone for Finger<'_, T, I, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, I: Copy + StoreIndex, A: Allocator> Copy for Finger<'_, T, I, A> {}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> Finger<'a, T, I, A> {
+    pub fn new(list: &'a LinkedVec<T, I, A>) -> Self {
+        Self { list, last: None }
+    }
+
+    /// Returns the physical index of the element at logical `index`, or
+    /// `None` if the index is out of bounds.
+    pub fn index_l_to_p(&mut self, index: usize) -> Option<usize> {
+        let index_p = self.list.nth_p_from(self.last, index)?;
+        self.last = Some((index, index_p));
+        Some(index_p)
+    }
+
+    /// Returns a reference to the element at logical `index`, or `None` if
+    /// the index is out of bounds.
+    pub fn get_l(&mut self, index: usize) -> Option<&'a T> {
+        let index_p = self.index_l_to_p(index)?;
+        Some(&self.list.data[index_p].payload)
+    }
+
+    /// Returns the list the finger points into.
+    #[must_use]
+    pub fn get_list(&self) -> &'a LinkedVec<T, I, A> {
+        self.list
+    }
+}
+
+/// No "ghost" non-element
+#[derive(Debug)]
+pub struct NonEmptyVecCursor<'a, T: 'a, I: Copy + StoreIndex, A: Allocator = Global> {
+    index_la: usize,
+    current_pa: usize, // Optionally replace usize with I
+    list: &'a LinkedVec<T, I, A>,
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> NonEmptyVecCursor<'a, T, I, A> {
+    /// Returns the cursor position within the linked list.
+    #[must_use]
+    pub fn index_l(&self) -> usize {
+        self.index_la
+    }
+    /// Returns the cursor position within the physical array.
+    #[must_use]
+    pub fn index_p(&self) -> usize {
+        self.current_pa
+    }
+
+    /// Returns a reference to the element that the cursor is currently
+    /// pointing to.
+    #[must_use]
+    pub fn current(&self) -> &'a T {
+        self.list.index_p(self.current_pa)
+    }
+
+    /// Moves the cursor to the next element of the linked list.
+    ///
+    /// If it is pointing to the last
+    /// element of the list, then this will move it to the front
+    /// and return false.
+    pub fn move_next(&mut self) -> bool {
+        self.step(step_next)
+    }
+
+    /// Moves the cursor to the previous element of the linked list.
+    ///
+    /// If it is pointing to the first
+    /// element of the list, then this will move it to the back
+    /// and return false.
+    pub fn move_prev(&mut self) -> bool {
+        self.step(step_prev)
+    }
+
+    /// Takes a step with `step`, and another one past the "ghost"
+    /// non-element if the first reaches it. Returns whether it did not
+    /// wrap around.
+    fn step(&mut self, step: fn(&LinkedVec<T, I, A>, &mut usize, &mut Option<usize>)) -> bool {
+        let mut current = Some(self.current_pa);
+        step(self.list, &mut self.index_la, &mut current);
+        let moved = current.is_some();
+        if !moved {
+            step(self.list, &mut self.index_la, &mut current);
+        }
+        // The list is not empty, so the second step reaches an element
+        self.current_pa = current.unwrap();
+        moved
+    }
+
+    /// Provides an iterator over one full cycle of the list, starting at
+    /// the current element and wrapping around from the back to the
+    /// front.
+    ///
+    /// Iterating does not move the cursor.
+    #[must_use]
+    pub fn iter(&self) -> CycleIter<'a, T, I, A> {
+        let back = match self.list.data[self.current_pa].prev {
+            Some(prev) => prev.to_usize(),
+            None => self.list.tail.unwrap().to_usize(),
+        };
+        CycleIter {
+            list: self.list,
+            front: self.current_pa,
+            back,
+            len: self.list.len(),
+        }
+    }
+
+    /// Returns a `VecCursor` pointing to the current element.
+    ///
+    /// Changing the state of either self or the resulting cursor
+    /// will not change the state of the other. If you would like
+    /// to keep the state of `VecCursor`, then convert it back to
+    /// a `NonEmptyVecCursor`.
+    pub fn as_cursor(&self) -> VecCursor<'a, T, I, A> {
+        VecCursor {
+            index_la: self.index_la,
+            current_pa: Some(self.current_pa),
+            list: self.list,
+        }
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> CursorOps for NonEmptyVecCursor<'a, T, I, A> {
+    type Item = T;
+
+    fn move_next(&mut self) {
+        Self::move_next(self);
+    }
+
+    fn move_prev(&mut self) {
+        Self::move_prev(self);
+    }
+
+    fn index_l(&self) -> Option<usize> {
+        Some(Self::index_l(self))
+    }
+
+    fn index_p(&self) -> Option<usize> {
+        Some(Self::index_p(self))
+    }
+
+    fn peek_current(&self) -> Option<&T> {
+        Some(self.current())
+    }
+
+    fn peek_next(&self) -> Option<&T> {
+        let mut next = self.clone();
+        next.move_next();
+        Some(next.current())
+    }
+
+    fn peek_prev(&self) -> Option<&T> {
+        let mut prev = self.clone();
+        prev.move_prev();
+        Some(prev.current())
+    }
+}
+
+impl<T, I: Copy + StoreIndex, A: Allocator> Clone for NonEmptyVecCursor<'_, T, I, A> {
+    fn clone(&self) -> Self {
+        // Destruct-assign self into individual variables
         // with same names as fields
         let Self {
             index_la,
@@ -482,35 +1214,170 @@ impl<T, I: Copy + StoreIndex> Clone for NonEmptyVecCursor<'_, T, I> {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-pub struct Iter<'a, T: 'a, I: Copy + StoreIndex> {
-    list: &'a LinkedVec<T, I>,
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> IntoIterator
+    for &NonEmptyVecCursor<'a, T, I, A>
+{
+    type Item = &'a T;
+    type IntoIter = CycleIter<'a, T, I, A>;
+
+    /// Same as [`NonEmptyVecCursor::iter`].
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over one full cycle of a list, in logical order, starting
+/// at a given element.
+///
+/// It is created by [`NonEmptyVecCursor::iter`].
+#[derive(Debug)]
+pub struct CycleIter<'a, T: 'a, I: Copy + StoreIndex, A: Allocator = Global> {
+    list: &'a LinkedVec<T, I, A>,
+    front: usize,
+    back: usize,
+    len: usize,
+}
+
+impl<T, I: Copy + StoreIndex, A: Allocator> Clone for CycleIter<'_, T, I, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, I: Copy + StoreIndex, A: Allocator> Copy for CycleIter<'_, T, I, A> {}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> Iterator for CycleIter<'a, T, I, A> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+
+        let node = &self.list.data[self.front];
+        self.front = node.next.or(self.list.head).unwrap().to_usize();
+        Some(&node.payload)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> DoubleEndedIterator for CycleIter<'a, T, I, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+
+        let node = &self.list.data[self.back];
+        self.back = node.prev.or(self.list.tail).unwrap().to_usize();
+        Some(&node.payload)
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> ExactSizeIterator for CycleIter<'a, T, I, A> {}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> FusedIterator for CycleIter<'a, T, I, A> {}
+
+/// A forward iterator over the elements of a list, in logical order.
+///
+/// It only borrows the nodes, so it also iterates over a
+/// [`LinkedSlice`](crate::slice::LinkedSlice), an
+/// [`ArrayLinkedVec`](crate::array::ArrayLinkedVec) or one list of a
+/// [`LinkedArena`](crate::arena::LinkedArena), where `A` is unused.
+pub struct Iter<'a, T: 'a, I: Copy + StoreIndex, A: Allocator = Global> {
+    nodes: &'a [VecNode<T, I>],
     head: usize, // Could be I,
     tail: usize, // Could be I,
     len: usize,
+    alloc: PhantomData<&'a A>,
+}
+
+impl<T, I: Copy + StoreIndex, A: Allocator> Clone for Iter<'_, T, I, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
 }
 
-impl<'a, T: 'a, I: Copy + StoreIndex> Iter<'a, T, I> {
-    pub fn new(list: &'a LinkedVec<T, I>) -> Self {
+impl<T, I: Copy + StoreIndex, A: Allocator> Copy for Iter<'_, T, I, A> {}
+
+impl<T: Debug, I: Copy + StoreIndex, A: Allocator> Debug for Iter<'_, T, I, A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Iter").field(&DebugIter(*self)).finish()
+    }
+}
+
+/// Lists the elements an [`Iter`] has left.
+struct DebugIter<'a, T, I: Copy + StoreIndex, A: Allocator>(Iter<'a, T, I, A>);
+
+impl<T: Debug, I: Copy + StoreIndex, A: Allocator> Debug for DebugIter<'_, T, I, A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.0).finish()
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> Iter<'a, T, I, A> {
+    pub fn new(list: &'a LinkedVec<T, I, A>) -> Self {
+        Self::from_nodes(&list.data, list.head, list.tail)
+    }
+
+    /// Iterates over all of `nodes`, which form a single list from `head`
+    /// to `tail`.
+    pub(crate) fn from_nodes(nodes: &'a [VecNode<T, I>], head: Option<I>, tail: Option<I>) -> Self {
+        Self::from_nodes_range(
+            nodes,
+            head.map_or(0, |x| x.to_usize()),
+            tail.map_or(0, |x| x.to_usize()),
+            nodes.len(),
+        )
+    }
+
+    /// Iterates over the `len` elements of `nodes` from physical index
+    /// `head` to physical index `tail`.
+    pub(crate) fn from_nodes_range(
+        nodes: &'a [VecNode<T, I>],
+        head: usize,
+        tail: usize,
+        len: usize,
+    ) -> Self {
         Self {
-            head: list.head.map_or(0, |x| x.to_usize()),
-            tail: list.tail.map_or(0, |x| x.to_usize()),
-            len: list.len(),
-            list,
+            nodes,
+            head,
+            tail,
+            len,
+            alloc: PhantomData,
         }
     }
+
+    /// Iterates over the `len` elements from physical index `head` to
+    /// physical index `tail`.
+    pub(crate) fn new_range(
+        list: &'a LinkedVec<T, I, A>,
+        head: usize,
+        tail: usize,
+        len: usize,
+    ) -> Self {
+        Self::from_nodes_range(&list.data, head, tail, len)
+    }
+
+    fn empty(list: &'a LinkedVec<T, I, A>) -> Self {
+        Self::new_range(list, 0, 0, 0)
+    }
 }
 
-impl<'a, T: 'a, I: Copy + StoreIndex> Iterator for Iter<'a, T, I> {
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> Iterator for Iter<'a, T, I, A> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.len <= 0 {
+        if self.len == 0 {
             return None;
         }
         self.len -= 1;
 
-        let last_node = &self.list.data[self.head];
+        let last_node = &self.nodes[self.head];
         self.head = last_node.next.map_or(0, |x| x.to_usize());
         Some(&last_node.payload)
     }
@@ -518,24 +1385,93 @@ impl<'a, T: 'a, I: Copy + StoreIndex> Iterator for Iter<'a, T, I> {
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.len, Some(self.len))
     }
+
+    fn count(self) -> usize {
+        self.len
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        (self.len != 0).then(|| &self.nodes[self.tail].payload)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let p = window_nth(
+            self.nodes,
+            (&mut self.head, &mut self.tail, &mut self.len),
+            n,
+            false,
+        )?;
+        Some(&self.nodes[p].payload)
+    }
 }
 
-impl<'a, T: 'a, I: Copy + StoreIndex> DoubleEndedIterator for Iter<'a, T, I> {
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> DoubleEndedIterator for Iter<'a, T, I, A> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.len <= 0 {
+        if self.len == 0 {
             return None;
         }
         self.len -= 1;
 
-        let last_node = &self.list.data[self.tail];
+        let last_node = &self.nodes[self.tail];
         self.tail = last_node.prev.map_or(0, |x| x.to_usize());
         Some(&last_node.payload)
     }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let p = window_nth(
+            self.nodes,
+            (&mut self.head, &mut self.tail, &mut self.len),
+            n,
+            true,
+        )?;
+        Some(&self.nodes[p].payload)
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> ExactSizeIterator for Iter<'a, T, I, A> {}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> FusedIterator for Iter<'a, T, I, A> {}
+
+/// Skips `n` elements of a window of `len` elements from `head` to `tail`,
+/// from the back if `back`, and returns the physical index of the next
+/// one.
+///
+/// Walks from whichever end of the window is closer, so it takes
+/// *O*(min(n, len - n)) time. The window is emptied if `n >= len`.
+fn window_nth<T, I: Copy + StoreIndex>(
+    nodes: &[VecNode<T, I>],
+    (head, tail, len): (&mut usize, &mut usize, &mut usize),
+    n: usize,
+    back: bool,
+) -> Option<usize> {
+    if n >= *len {
+        *len = 0;
+        return None;
+    }
+    let step = |p: usize, forward: bool| {
+        let node = &nodes[p];
+        let link = if forward { node.next } else { node.prev };
+        link.map_or(0, |x| x.to_usize())
+    };
+    let (near, far) = if back { (*tail, *head) } else { (*head, *tail) };
+    let rest = *len - 1 - n;
+    let target = if n <= rest {
+        (0..n).fold(near, |p, _| step(p, !back))
+    } else {
+        (0..rest).fold(far, |p, _| step(p, back))
+    };
+    *len = rest;
+    if back {
+        *tail = step(target, false);
+    } else {
+        *head = step(target, true);
+    }
+    Some(target)
 }
 
-impl<'a, T: 'a, I: Copy + StoreIndex> IntoIterator for &'a LinkedVec<T, I> {
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> IntoIterator for &'a LinkedVec<T, I, A> {
     type Item = &'a T;
-    type IntoIter = Iter<'a, T, I>;
+    type IntoIter = Iter<'a, T, I, A>;
 
     /// Consumes the list into an iterator yielding elements by value.
     fn into_iter(self) -> Self::IntoIter {
@@ -565,7 +1501,7 @@ impl<'a, T: 'a, I: Copy + StoreIndex> IntoIterator for &'a LinkedVec<T, I> {
 //     }
 // }
 
-impl<'a, T: 'a, I: Copy + StoreIndex> IntoIterator for &'a mut LinkedVec<T, I> {
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> IntoIterator for &'a mut LinkedVec<T, I, A> {
     type Item = &'a mut T;
     type IntoIter = IterMut<'a, T, I>;
 
@@ -595,14 +1531,25 @@ pub struct SafeIterMut<'a, T: 'a, I: Copy + StoreIndex> {
 
 impl<'a, T: 'a, I: Copy + StoreIndex> SafeIterMut<'a, T, I> {
     #[must_use]
-    pub fn new(list: &'a mut LinkedVec<T, I>) -> Self {
+    pub fn new<A: Allocator>(list: &'a mut LinkedVec<T, I, A>) -> Self {
         let len = list.len();
         let (head, tail) = match (list.head, list.tail) {
             (None, None) => (0, 0),
             (Some(h), Some(t)) => (h.to_usize(), t.to_usize()),
             _ => unreachable!(),
         };
-        let ref_slice: Vec<_> = list.data.iter_mut().map(|x| Some(x)).collect();
+        Self::new_range(list, head, tail, len)
+    }
+
+    /// Iterates over the `len` elements from physical index `head` to
+    /// physical index `tail`.
+    pub(crate) fn new_range<A: Allocator>(
+        list: &'a mut LinkedVec<T, I, A>,
+        head: usize,
+        tail: usize,
+        len: usize,
+    ) -> Self {
+        let ref_slice: Vec<_> = list.data.iter_mut().map(Some).collect();
         Self {
             ref_slice,
             head,
@@ -616,7 +1563,7 @@ impl<'a, T: 'a, I: Copy + StoreIndex> Iterator for SafeIterMut<'a, T, I> {
     type Item = &'a mut T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.len <= 0 {
+        if self.len == 0 {
             return None;
         }
         self.len -= 1;
@@ -633,7 +1580,7 @@ impl<'a, T: 'a, I: Copy + StoreIndex> Iterator for SafeIterMut<'a, T, I> {
 
 impl<'a, T: 'a, I: Copy + StoreIndex> DoubleEndedIterator for SafeIterMut<'a, T, I> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.len <= 0 {
+        if self.len == 0 {
             return None;
         }
         self.len -= 1;
@@ -645,11 +1592,11 @@ impl<'a, T: 'a, I: Copy + StoreIndex> DoubleEndedIterator for SafeIterMut<'a, T,
 }
 
 #[derive(Debug, Clone)]
-pub struct IntoIter<T, I: Copy + StoreIndex> {
-    list: LinkedVec<T, I>,
+pub struct IntoIter<T, I: Copy + StoreIndex, A: Allocator = Global> {
+    list: LinkedVec<T, I, A>,
 }
 
-impl<T, I: Copy + StoreIndex> Iterator for IntoIter<T, I> {
+impl<T, I: Copy + StoreIndex, A: Allocator> Iterator for IntoIter<T, I, A> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -659,68 +1606,137 @@ impl<T, I: Copy + StoreIndex> Iterator for IntoIter<T, I> {
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.list.len(), Some(self.list.len()))
     }
+
+    fn count(self) -> usize {
+        self.list.len()
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
 }
 
-impl<T, I: Copy + StoreIndex> DoubleEndedIterator for IntoIter<T, I> {
+impl<T, I: Copy + StoreIndex, A: Allocator> DoubleEndedIterator for IntoIter<T, I, A> {
     fn next_back(&mut self) -> Option<Self::Item> {
         self.list.pop_back()
     }
 }
 
-impl<T, I: Copy + StoreIndex> IntoIterator for LinkedVec<T, I> {
+impl<T, I: Copy + StoreIndex, A: Allocator> IntoIterator for LinkedVec<T, I, A> {
     type Item = T;
-    type IntoIter = IntoIter<T, I>;
+    type IntoIter = IntoIter<T, I, A>;
 
     /// Consumes the list into an iterator yielding elements by value.
-    fn into_iter(self) -> IntoIter<T, I> {
+    fn into_iter(self) -> IntoIter<T, I, A> {
         IntoIter { list: self }
     }
 }
 
-impl<A, I: StoreIndex + Copy> Extend<A> for LinkedVec<A, I> {
-    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+impl<T, I: StoreIndex + Copy, A: Allocator> Extend<T> for LinkedVec<T, I, A> {
+    fn extend<It: IntoIterator<Item = T>>(&mut self, iter: It) {
         let it = iter.into_iter();
 
         let l = it.size_hint().0;
         _ = self.data.try_reserve(l);
 
+        // Each element is linked as soon as it is pushed, so the list
+        // stays valid if the iterator panics.
         for v in it {
             self.push_back(v);
         }
     }
 }
 
-impl<'a, A: Copy, I: StoreIndex + Copy> Extend<&'a A> for LinkedVec<A, I> {
-    fn extend<T: IntoIterator<Item = &'a A>>(&mut self, iter: T) {
+impl<'a, T: Clone + 'a, I: StoreIndex + Copy, A: Allocator> Extend<&'a T> for LinkedVec<T, I, A> {
+    fn extend<It: IntoIterator<Item = &'a T>>(&mut self, iter: It) {
         let it = iter.into_iter();
 
         let l = it.size_hint().0;
         _ = self.data.try_reserve(l);
 
         for v in it {
-            self.push_back(*v);
+            self.push_back(v.clone());
+        }
+    }
+}
+
+impl<T, I: StoreIndex + Copy, A: Allocator, B: Allocator> Extend<LinkedVec<T, I, B>>
+    for LinkedVec<T, I, A>
+{
+    /// Appends each list in turn with [`append`](LinkedVec::append).
+    fn extend<It: IntoIterator<Item = LinkedVec<T, I, B>>>(&mut self, iter: It) {
+        for mut list in iter {
+            self.append(&mut list);
         }
     }
 }
 
-impl<A, I: StoreIndex + Copy> FromIterator<A> for LinkedVec<A, I> {
-    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+impl<T, I: StoreIndex + Copy> FromIterator<T> for LinkedVec<T, I> {
+    fn from_iter<It: IntoIterator<Item = T>>(iter: It) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<'a, T: Clone + 'a, I: StoreIndex + Copy> FromIterator<&'a T> for LinkedVec<T, I> {
+    fn from_iter<It: IntoIterator<Item = &'a T>>(iter: It) -> Self {
         let mut list = Self::new();
         list.extend(iter);
         list
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-pub struct IterP<'a, T: 'a, I: Copy + StoreIndex> {
-    list: &'a LinkedVec<T, I>,
+impl<T, I: StoreIndex + Copy, A: Allocator + Default, B: Allocator> Sum<LinkedVec<T, I, B>>
+    for LinkedVec<T, I, A>
+{
+    /// Concatenates the lists in order. The nodes of each list are moved
+    /// in bulk, see [`LinkedVec::append`].
+    fn sum<It: Iterator<Item = LinkedVec<T, I, B>>>(iter: It) -> Self {
+        let mut list = Self::default();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<'a, T: Clone + 'a, I: StoreIndex + Copy + 'a, A: Allocator + Default, B: Allocator + 'a>
+    Sum<&'a LinkedVec<T, I, B>> for LinkedVec<T, I, A>
+{
+    /// Concatenates clones of the elements of the lists, in logical order.
+    fn sum<It: Iterator<Item = &'a LinkedVec<T, I, B>>>(iter: It) -> Self {
+        let mut list = Self::default();
+        for other in iter {
+            list.extend(other.iter().cloned());
+        }
+        list
+    }
+}
+
+/// An iterator over the physical indices of the elements of a
+/// [`LinkedVec`], in logical order.
+///
+/// It is created by [`LinkedVec::indices_l`]. Every index it yields is in
+/// bounds of the physical array for as long as the iterator borrows the
+/// list, and each one is yielded exactly once.
+#[derive(Debug)]
+pub struct IterP<'a, T: 'a, I: Copy + StoreIndex, A: Allocator = Global> {
+    list: &'a LinkedVec<T, I, A>,
     head: usize, // Could be I,
     tail: usize, // Could be I,
     len: usize,
 }
 
-impl<'a, T: 'a, I: Copy + StoreIndex> IterP<'a, T, I> {
-    pub fn new(list: &'a LinkedVec<T, I>) -> Self {
+impl<T, I: Copy + StoreIndex, A: Allocator> Clone for IterP<'_, T, I, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, I: Copy + StoreIndex, A: Allocator> Copy for IterP<'_, T, I, A> {}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> IterP<'a, T, I, A> {
+    /// Same as [`LinkedVec::indices_l`].
+    pub fn new(list: &'a LinkedVec<T, I, A>) -> Self {
         Self {
             head: list.head.map_or(0, |x| x.to_usize()),
             tail: list.tail.map_or(0, |x| x.to_usize()),
@@ -730,11 +1746,11 @@ impl<'a, T: 'a, I: Copy + StoreIndex> IterP<'a, T, I> {
     }
 }
 
-impl<'a, T: 'a, I: Copy + StoreIndex> Iterator for IterP<'a, T, I> {
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> Iterator for IterP<'a, T, I, A> {
     type Item = usize;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.len <= 0 {
+        if self.len == 0 {
             return None;
         }
         self.len -= 1;
@@ -747,11 +1763,28 @@ impl<'a, T: 'a, I: Copy + StoreIndex> Iterator for IterP<'a, T, I> {
     fn size_hint(&self) -> (usize, Option<usize>) {
         (self.len, Some(self.len))
     }
+
+    fn count(self) -> usize {
+        self.len
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        (self.len != 0).then_some(self.tail)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        window_nth(
+            &self.list.data,
+            (&mut self.head, &mut self.tail, &mut self.len),
+            n,
+            false,
+        )
+    }
 }
 
-impl<'a, T: 'a, I: Copy + StoreIndex> DoubleEndedIterator for IterP<'a, T, I> {
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> DoubleEndedIterator for IterP<'a, T, I, A> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.len <= 0 {
+        if self.len == 0 {
             return None;
         }
         self.len -= 1;
@@ -760,4 +1793,639 @@ impl<'a, T: 'a, I: Copy + StoreIndex> DoubleEndedIterator for IterP<'a, T, I> {
         self.tail = self.list.data[last_index].prev.map_or(0, |x| x.to_usize());
         Some(last_index)
     }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        window_nth(
+            &self.list.data,
+            (&mut self.head, &mut self.tail, &mut self.len),
+            n,
+            true,
+        )
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> ExactSizeIterator for IterP<'a, T, I, A> {}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> FusedIterator for IterP<'a, T, I, A> {}
+
+/// A forward iterator yielding the physical index of each element along
+/// with the element.
+#[derive(Debug)]
+pub struct IterWithP<'a, T: 'a, I: Copy + StoreIndex, A: Allocator = Global> {
+    inner: IterP<'a, T, I, A>,
+}
+
+impl<T, I: Copy + StoreIndex, A: Allocator> Clone for IterWithP<'_, T, I, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, I: Copy + StoreIndex, A: Allocator> Copy for IterWithP<'_, T, I, A> {}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> IterWithP<'a, T, I, A> {
+    pub fn new(list: &'a LinkedVec<T, I, A>) -> Self {
+        Self {
+            inner: IterP::new(list),
+        }
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> Iterator for IterWithP<'a, T, I, A> {
+    type Item = (usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let p = self.inner.next()?;
+        Some((p, &self.inner.list.data[p].payload))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> DoubleEndedIterator for IterWithP<'a, T, I, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let p = self.inner.next_back()?;
+        Some((p, &self.inner.list.data[p].payload))
+    }
+}
+
+/// An iterator over the elements of a [`LinkedVec`] in no particular
+/// order. Returned by [`LinkedVec::iter_unordered`].
+#[derive(Debug)]
+pub struct IterUnordered<'a, T: 'a, I: Copy + StoreIndex> {
+    inner: core::slice::Iter<'a, VecNode<T, I>>,
+}
+
+impl<T, I: Copy + StoreIndex> Clone for IterUnordered<'_, T, I> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex> IterUnordered<'a, T, I> {
+    /// Same as [`LinkedVec::iter_unordered`].
+    pub fn new<A: Allocator>(list: &'a LinkedVec<T, I, A>) -> Self {
+        Self {
+            inner: list.data.iter(),
+        }
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex> Iterator for IterUnordered<'a, T, I> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|x| &x.payload)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth(n).map(|x| &x.payload)
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex> DoubleEndedIterator for IterUnordered<'a, T, I> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|x| &x.payload)
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex> ExactSizeIterator for IterUnordered<'a, T, I> {}
+
+impl<'a, T: 'a, I: Copy + StoreIndex> FusedIterator for IterUnordered<'a, T, I> {}
+
+/// An iterator with mutable references over the elements of a
+/// [`LinkedVec`] in no particular order. Returned by
+/// [`LinkedVec::iter_unordered_mut`].
+#[derive(Debug)]
+pub struct IterUnorderedMut<'a, T: 'a, I: Copy + StoreIndex> {
+    inner: core::slice::IterMut<'a, VecNode<T, I>>,
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex> IterUnorderedMut<'a, T, I> {
+    /// Same as [`LinkedVec::iter_unordered_mut`].
+    pub fn new<A: Allocator>(list: &'a mut LinkedVec<T, I, A>) -> Self {
+        Self {
+            inner: list.data.iter_mut(),
+        }
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex> Iterator for IterUnorderedMut<'a, T, I> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|x| &mut x.payload)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.inner.nth(n).map(|x| &mut x.payload)
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex> DoubleEndedIterator for IterUnorderedMut<'a, T, I> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|x| &mut x.payload)
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex> ExactSizeIterator for IterUnorderedMut<'a, T, I> {}
+
+impl<'a, T: 'a, I: Copy + StoreIndex> FusedIterator for IterUnorderedMut<'a, T, I> {}
+
+/// A forward iterator yielding the logical and physical index of each
+/// element along with the element.
+#[derive(Debug)]
+pub struct IterIndices<'a, T: 'a, I: Copy + StoreIndex, A: Allocator = Global> {
+    inner: IterWithP<'a, T, I, A>,
+    front_l: usize,
+}
+
+impl<T, I: Copy + StoreIndex, A: Allocator> Clone for IterIndices<'_, T, I, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, I: Copy + StoreIndex, A: Allocator> Copy for IterIndices<'_, T, I, A> {}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> IterIndices<'a, T, I, A> {
+    pub fn new(list: &'a LinkedVec<T, I, A>) -> Self {
+        Self {
+            inner: IterWithP::new(list),
+            front_l: 0,
+        }
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> Iterator for IterIndices<'a, T, I, A> {
+    type Item = (usize, usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (p, x) = self.inner.next()?;
+        self.front_l += 1;
+        Some((self.front_l - 1, p, x))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> DoubleEndedIterator
+    for IterIndices<'a, T, I, A>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (p, x) = self.inner.next_back()?;
+        // Everything still left comes before this element
+        Some((self.front_l + self.inner.inner.len, p, x))
+    }
+}
+
+/// A forward iterator yielding the logical and physical index of each
+/// element along with a mutable reference to the element. Returned by
+/// [`LinkedVec::iter_indices_mut`].
+#[derive(Debug)]
+pub struct IterIndicesMut<'a, T: 'a, I: Copy + StoreIndex> {
+    inner: SafeIterMut<'a, T, I>,
+    front_l: usize,
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex> IterIndicesMut<'a, T, I> {
+    pub fn new<A: Allocator>(list: &'a mut LinkedVec<T, I, A>) -> Self {
+        Self {
+            inner: SafeIterMut::new(list),
+            front_l: 0,
+        }
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex> Iterator for IterIndicesMut<'a, T, I> {
+    type Item = (usize, usize, &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let p = self.inner.head;
+        let x = self.inner.next()?;
+        self.front_l += 1;
+        Some((self.front_l - 1, p, x))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex> DoubleEndedIterator for IterIndicesMut<'a, T, I> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let p = self.inner.tail;
+        let x = self.inner.next_back()?;
+        // Everything still left comes before this element
+        Some((self.front_l + self.inner.len, p, x))
+    }
+}
+
+/// An iterator over overlapping runs of `size` elements, in logical
+/// order.
+///
+/// Each window is an [`Iter`] over its elements. Returned by
+/// [`LinkedVec::windows`].
+#[derive(Debug)]
+pub struct Windows<'a, T: 'a, I: Copy + StoreIndex, A: Allocator = Global> {
+    list: &'a LinkedVec<T, I, A>,
+    head: usize, // First element of the next window
+    tail: usize, // Last element of the next window
+    size: usize,
+    remaining: usize,
+}
+
+impl<T, I: Copy + StoreIndex, A: Allocator> Clone for Windows<'_, T, I, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, I: Copy + StoreIndex, A: Allocator> Copy for Windows<'_, T, I, A> {}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> Windows<'a, T, I, A> {
+    pub(crate) fn new(list: &'a LinkedVec<T, I, A>, size: usize) -> Self {
+        assert!(size != 0, "window size must be non-zero");
+        let remaining = (list.len() + 1).saturating_sub(size);
+        Self {
+            head: list.head.map_or(0, |x| x.to_usize()),
+            tail: list.nth_p(size - 1).unwrap_or(0),
+            size,
+            remaining,
+            list,
+        }
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> Iterator for Windows<'a, T, I, A> {
+    type Item = Iter<'a, T, I, A>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let window = Iter::new_range(self.list, self.head, self.tail, self.size);
+        if self.remaining > 0 {
+            let data = &self.list.data;
+            self.head = data[self.head].next.unwrap().to_usize();
+            self.tail = data[self.tail].next.unwrap().to_usize();
+        }
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> ExactSizeIterator for Windows<'a, T, I, A> {}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> FusedIterator for Windows<'a, T, I, A> {}
+
+/// An iterator over non-overlapping runs of `size` elements, in logical
+/// order. The last chunk is shorter if `size` does not divide the length.
+///
+/// Each chunk is an [`Iter`] over its elements. Returned by
+/// [`LinkedVec::chunks`].
+#[derive(Debug)]
+pub struct Chunks<'a, T: 'a, I: Copy + StoreIndex, A: Allocator = Global> {
+    list: &'a LinkedVec<T, I, A>,
+    head: usize, // First element of the next chunk
+    size: usize,
+    remaining: usize, // Elements, not chunks
+}
+
+impl<T, I: Copy + StoreIndex, A: Allocator> Clone for Chunks<'_, T, I, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, I: Copy + StoreIndex, A: Allocator> Copy for Chunks<'_, T, I, A> {}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> Chunks<'a, T, I, A> {
+    pub(crate) fn new(list: &'a LinkedVec<T, I, A>, size: usize) -> Self {
+        assert!(size != 0, "chunk size must be non-zero");
+        Self {
+            head: list.head.map_or(0, |x| x.to_usize()),
+            size,
+            remaining: list.len(),
+            list,
+        }
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> Iterator for Chunks<'a, T, I, A> {
+    type Item = Iter<'a, T, I, A>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let len = self.size.min(self.remaining);
+        self.remaining -= len;
+
+        let data = &self.list.data;
+        let mut tail = self.head;
+        for _ in 1..len {
+            tail = data[tail].next.unwrap().to_usize();
+        }
+        let chunk = Iter::new_range(self.list, self.head, tail, len);
+        self.head = data[tail].next.map_or(0, |x| x.to_usize());
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let chunks = self.remaining.div_ceil(self.size);
+        (chunks, Some(chunks))
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> ExactSizeIterator for Chunks<'a, T, I, A> {}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> FusedIterator for Chunks<'a, T, I, A> {}
+
+/// An iterator over the runs of adjacent elements that a predicate holds
+/// for, in logical order.
+///
+/// Each run is an [`Iter`] over its elements. Returned by
+/// [`LinkedVec::chunk_by`].
+pub struct ChunkBy<'a, T: 'a, I: Copy + StoreIndex, A: Allocator, F> {
+    list: &'a LinkedVec<T, I, A>,
+    head: usize,      // First element of the next run
+    tail: usize,      // Last element of the next run from the back
+    remaining: usize, // Elements, not runs
+    pred: F,
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator, F> ChunkBy<'a, T, I, A, F> {
+    pub(crate) fn new(list: &'a LinkedVec<T, I, A>, pred: F) -> Self {
+        Self {
+            head: list.head.map_or(0, |x| x.to_usize()),
+            tail: list.tail.map_or(0, |x| x.to_usize()),
+            remaining: list.len(),
+            list,
+            pred,
+        }
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator, F> Iterator for ChunkBy<'a, T, I, A, F>
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    type Item = Iter<'a, T, I, A>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let data = &self.list.data;
+        let mut tail = self.head;
+        let mut len = 1;
+        while len < self.remaining {
+            let next = data[tail].next.unwrap().to_usize();
+            if !(self.pred)(&data[tail].payload, &data[next].payload) {
+                break;
+            }
+            tail = next;
+            len += 1;
+        }
+        self.remaining -= len;
+
+        let run = Iter::new_range(self.list, self.head, tail, len);
+        self.head = data[tail].next.map_or(0, |x| x.to_usize());
+        Some(run)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::from(self.remaining != 0), Some(self.remaining))
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator, F> DoubleEndedIterator
+    for ChunkBy<'a, T, I, A, F>
+where
+    F: FnMut(&T, &T) -> bool,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let data = &self.list.data;
+        let mut head = self.tail;
+        let mut len = 1;
+        while len < self.remaining {
+            let prev = data[head].prev.unwrap().to_usize();
+            if !(self.pred)(&data[prev].payload, &data[head].payload) {
+                break;
+            }
+            head = prev;
+            len += 1;
+        }
+        self.remaining -= len;
+
+        let run = Iter::new_range(self.list, head, self.tail, len);
+        self.tail = data[head].prev.map_or(0, |x| x.to_usize());
+        Some(run)
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator, F> FusedIterator for ChunkBy<'a, T, I, A, F> where
+    F: FnMut(&T, &T) -> bool
+{
+}
+
+impl<T: Debug, I: Copy + StoreIndex, A: Allocator, F> Debug for ChunkBy<'_, T, I, A, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ChunkBy").field(&self.list).finish()
+    }
+}
+
+/// An iterator over runs of `N` elements as arrays of references, in
+/// logical order.
+///
+/// If `N` does not divide the length, the last elements are left out and
+/// can be reached with [`remainder`](Self::remainder). Returned by
+/// [`LinkedVec::array_chunks`].
+#[derive(Debug)]
+pub struct ArrayChunks<'a, T: 'a, I: Copy + StoreIndex, A: Allocator, const N: usize> {
+    inner: Iter<'a, T, I, A>,
+}
+
+impl<T, I: Copy + StoreIndex, A: Allocator, const N: usize> Clone for ArrayChunks<'_, T, I, A, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, I: Copy + StoreIndex, A: Allocator, const N: usize> Copy for ArrayChunks<'_, T, I, A, N> {}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator, const N: usize> ArrayChunks<'a, T, I, A, N> {
+    pub(crate) fn new(list: &'a LinkedVec<T, I, A>) -> Self {
+        assert!(N != 0, "chunk size must be non-zero");
+        Self {
+            inner: Iter::new(list),
+        }
+    }
+
+    /// Returns an iterator over the elements left over once every full
+    /// chunk has been yielded.
+    #[must_use]
+    pub fn remainder(&self) -> Iter<'a, T, I, A> {
+        let mut rest = self.inner;
+        for _ in 0..rest.len - rest.len % N {
+            rest.next();
+        }
+        rest
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator, const N: usize> Iterator
+    for ArrayChunks<'a, T, I, A, N>
+{
+    type Item = [&'a T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.inner.len < N {
+            return None;
+        }
+        Some(core::array::from_fn(|_| self.inner.next().unwrap()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let chunks = self.inner.len / N;
+        (chunks, Some(chunks))
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator, const N: usize> ExactSizeIterator
+    for ArrayChunks<'a, T, I, A, N>
+{
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator, const N: usize> FusedIterator
+    for ArrayChunks<'a, T, I, A, N>
+{
+}
+
+/// An iterator that moves runs of `N` elements out of a list as arrays,
+/// in logical order.
+///
+/// If `N` does not divide the length, the last elements are left in the
+/// list and can be taken back with [`into_remainder`](Self::into_remainder).
+/// Returned by [`LinkedVec::into_array_chunks`].
+#[derive(Debug)]
+pub struct IntoArrayChunks<T, I: Copy + StoreIndex, A: Allocator, const N: usize> {
+    list: LinkedVec<T, I, A>,
+}
+
+impl<T, I: Copy + StoreIndex, A: Allocator, const N: usize> IntoArrayChunks<T, I, A, N> {
+    pub(crate) fn new(list: LinkedVec<T, I, A>) -> Self {
+        assert!(N != 0, "chunk size must be non-zero");
+        Self { list }
+    }
+
+    /// Returns the list of the elements not yielded yet, which is only the
+    /// remainder once the iterator is exhausted.
+    #[must_use]
+    pub fn into_remainder(self) -> LinkedVec<T, I, A> {
+        self.list
+    }
+}
+
+impl<T, I: Copy + StoreIndex, A: Allocator, const N: usize> Iterator
+    for IntoArrayChunks<T, I, A, N>
+{
+    type Item = [T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.list.len() < N {
+            return None;
+        }
+        Some(core::array::from_fn(|_| self.list.pop_front().unwrap()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let chunks = self.list.len() / N;
+        (chunks, Some(chunks))
+    }
+}
+
+impl<T, I: Copy + StoreIndex, A: Allocator, const N: usize> ExactSizeIterator
+    for IntoArrayChunks<T, I, A, N>
+{
+}
+
+impl<T, I: Copy + StoreIndex, A: Allocator, const N: usize> FusedIterator
+    for IntoArrayChunks<T, I, A, N>
+{
+}
+
+/// An iterator produced by calling `extract_if` on a `LinkedVec`.
+pub struct ExtractIf<'a, T: 'a, I: Copy + StoreIndex, A: Allocator, F> {
+    pub(crate) list: &'a mut LinkedVec<T, I, A>,
+    pub(crate) next_pa: Option<usize>,
+    pub(crate) remaining: usize,
+    pub(crate) pred: F,
+    /// The physical index of a cursor to keep pointing to the same node
+    pub(crate) anchor_pa: Option<&'a mut usize>,
+}
+
+impl<T, I: Copy + StoreIndex, A: Allocator, F> Iterator for ExtractIf<'_, T, I, A, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while self.remaining > 0 {
+            self.remaining -= 1;
+            let current = self.next_pa?;
+            let node = &mut self.list.data[current];
+            self.next_pa = node.next.map(|x| x.to_usize());
+
+            if (self.pred)(&mut node.payload) {
+                let last = self.list.len() - 1;
+                let payload = self.list.in_swap_remove(current);
+                // The last node may have been moved into the freed slot
+                if self.next_pa == Some(last) {
+                    self.next_pa = Some(current);
+                }
+                if let Some(anchor) = &mut self.anchor_pa {
+                    if **anchor == last {
+                        **anchor = current;
+                    }
+                }
+                return Some(payload);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining))
+    }
+}
+
+impl<T: Debug, I: Copy + StoreIndex, A: Allocator, F> Debug for ExtractIf<'_, T, I, A, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ExtractIf").field(&self.list).finish()
+    }
 }
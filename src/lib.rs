@@ -1,109 +1,796 @@
 #![no_std]
+#![cfg_attr(feature = "allocator-api", feature(allocator_api))]
+// The panicking methods stay in use internally, where they cannot panic
+#![cfg_attr(feature = "no-panic", allow(deprecated))]
 
 extern crate alloc;
 
+/// Panics if the links of the list are corrupted.
+///
+/// This only does something in debug builds with the `paranoid-checks`
+/// feature. Use it at the end of public methods that change the links.
+macro_rules! paranoid_check {
+    ($list:expr) => {
+        #[cfg(all(feature = "paranoid-checks", debug_assertions))]
+        if let Err(err) = $list.validate_links() {
+            panic!("link corruption: {err}");
+        }
+    };
+}
+
+mod allocator;
+pub mod arena;
+pub mod array;
+#[cfg(feature = "dot")]
+mod dot;
+pub mod entry;
+mod error;
+pub mod frozen;
+#[cfg(feature = "hashbrown")]
+mod hash;
+pub mod indexed;
+#[macro_use]
 mod inner_types;
 pub mod iterators;
+mod links;
+pub mod map;
+#[cfg(feature = "ops")]
+pub mod ops;
+mod packed;
+#[cfg(feature = "rayon")]
+mod par_iter;
+pub mod positioned;
+pub mod range;
+mod raw;
+pub mod set;
+pub mod slice;
+pub mod small;
+#[cfg(feature = "bytemuck")]
+mod snapshot;
+mod sort;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+#[cfg(any(test, feature = "testkit"))]
+pub mod testkit;
 mod tests;
+mod validate;
+
+use alloc::boxed::Box;
+use allocator::Vec;
+use core::{
+    fmt::{Debug, Display},
+    ops::{Bound, Deref, DerefMut, RangeBounds},
+};
+use inner_types::StoreIndex;
+use iterators::{
+    ArrayChunks, ChunkBy, Chunks, CursorToken, ExtractIf, Finger, IntoArrayChunks, IntoIter, Iter,
+    IterIndices, IterIndicesMut, IterMut, IterP, IterUnordered, IterUnorderedMut, IterWithP,
+    VecCursor, VecCursorMut, VecCursorPairMut, Windows,
+};
+use links::{Links, LinksMut};
+use slice::LinkedSlice;
 
-use alloc::{collections, vec::Vec};
-use core::{fmt::Debug, ptr};
-use inner_types::{StoreIndex, VecNode};
-use iterators::{Iter, IterMut, IterP, VecCursor, VecCursorMut};
+pub use allocator::{Allocator, Global};
+pub use error::{Error, TryError};
+pub use inner_types::VecNode;
+pub use packed::{U24, U48};
+pub use validate::LinkCorruption;
 
-pub struct LinkedVec<T, I: StoreIndex + Copy = usize> {
-    data: Vec<VecNode<T, I>>,
+/// A doubly linked list whose nodes are stored in a single `Vec`.
+///
+/// Every element has a logical index, its position in the list, and a
+/// physical index, its position in the backing array. Removing an
+/// element moves the physically last node into the freed slot, so
+/// physical indices are not stable.
+///
+/// Each node stores two `Option<I>` links next to its payload. For
+/// zero-sized payloads those links are all that is stored, so a
+/// `LinkedVec<(), I>` costs `2 * size_of::<Option<I>>()` bytes per element.
+/// Zero-sized payloads are not special-cased otherwise: the links still
+/// back physical indices, cursors and the logical order, so walking the
+/// list costs the same as for any payload. If the elements carry no
+/// information, a plain counter is cheaper.
+///
+/// For the primitive integers, `Option<I>` is twice as large as `I`. The
+/// `nonmax` index types, such as `nonmax::NonMaxU16`, keep `None` in a
+/// niche instead, so both links of a node fit in `2 * size_of::<I>()`
+/// bytes, as much as packing them into one integer of twice the width
+/// would take, at the cost of a single index value.
+///
+/// The physical array is allocated with `A`. Allocators other than
+/// [`Global`] need the `allocator-api` or `allocator-api2` feature.
+///
+/// The `no-panic` feature deprecates the panicking methods that have a
+/// fallible counterpart, such as [`push_back`](Self::push_back) for
+/// [`try_push_back`](Self::try_push_back), so that their uses show up as
+/// warnings. It does not cover every way to panic: methods without a
+/// counterpart, like [`windows`](Self::windows) and the ones taking
+/// ranges, still panic on invalid arguments, and so do trait impls like
+/// `Extend` and `FromIterator` when `I` overflows.
+pub struct LinkedVec<T, I: StoreIndex + Copy = usize, A: Allocator = Global> {
+    data: Vec<VecNode<T, I>, A>,
     head: Option<I>,
     tail: Option<I>,
+    /// Bumped by every structural change, see `version`
+    version: u64,
+    overflow_policy: OverflowPolicy,
+    /// The length at which the evicting pushes start evicting
+    max_len: Option<usize>,
+}
+
+/// What pushing to a list does when its index type `I` cannot address
+/// another element. See [`LinkedVec::set_overflow_policy`].
+///
+/// Only [`push_front`], [`push_back`] and their `try_` versions follow
+/// the policy. Other insertions always treat a full list as an error.
+///
+/// [`push_front`]: LinkedVec::push_front
+/// [`push_back`]: LinkedVec::push_back
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OverflowPolicy {
+    /// Panic, even in [`try_push_front`](LinkedVec::try_push_front) and
+    /// [`try_push_back`](LinkedVec::try_push_back).
+    Panic,
+    /// Make the `try_` pushes give the value back. The other pushes have
+    /// no way to report the error, so they panic.
+    #[default]
+    Error,
+    /// Pop the element at the given end to make room.
+    Evict(End),
+}
+
+/// One end of a list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum End {
+    Front,
+    Back,
+}
+
+impl<T> LinkedVec<T> {
+    /// Creates an empty list indexed with `J` instead of `usize`.
+    ///
+    /// This saves spelling out the allocator in `LinkedVec<T, J>::new()`
+    /// where the type cannot be inferred, like in `const` contexts.
+    pub const fn with_index_type<J: StoreIndex + Copy>() -> LinkedVec<T, J> {
+        LinkedVec::new()
+    }
 }
 
 impl<T, I: StoreIndex + Copy> LinkedVec<T, I> {
+    /// An empty list, for initializing statics and arrays of lists.
+    pub const EMPTY: Self = Self::new();
+
     pub const fn new() -> Self {
         Self {
             data: Vec::new(),
             head: None,
             tail: None,
+            version: 0,
+            overflow_policy: OverflowPolicy::Error,
+            max_len: None,
+        }
+    }
+
+    /// Decomposes the list into its raw components.
+    ///
+    /// Returns the pointer to the physical array of [`VecNode`]s, its
+    /// length and capacity in nodes, and the physical indices of the head
+    /// and tail. The first `len` nodes are initialized and linked into a
+    /// single list from `head` to `tail`, both of which are `None` if and
+    /// only if `len` is zero.
+    ///
+    /// The caller becomes responsible for the memory. The only way to
+    /// release it is to rebuild the list with [`from_raw_parts`].
+    ///
+    /// [`from_raw_parts`]: LinkedVec::from_raw_parts
+    #[must_use = "losing the pointer will leak memory"]
+    pub fn into_raw_parts(self) -> (*mut VecNode<T, I>, usize, usize, Option<I>, Option<I>) {
+        let (head, tail) = (self.head, self.tail);
+        let data = core::mem::ManuallyDrop::new(self).take_data();
+        let (ptr, len, capacity) = raw::into_raw_parts(data);
+        (ptr, len, capacity, head, tail)
+    }
+
+    /// Converts the list into a boxed slice of [`VecNode`]s, dropping the
+    /// spare capacity, along with the physical indices of the head and
+    /// tail.
+    ///
+    /// The layout of the nodes is described by
+    /// [`into_raw_parts`](LinkedVec::into_raw_parts).
+    #[allow(clippy::type_complexity)]
+    pub fn into_boxed_parts(mut self) -> (Box<[VecNode<T, I>]>, Option<I>, Option<I>) {
+        self.data.shrink_to_fit();
+        let (head, tail) = (self.head, self.tail);
+        (raw::into_boxed_slice(self.take_data()), head, tail)
+    }
+
+    /// Consumes and leaks the list, returning a mutable view of it that
+    /// lives for `'a`.
+    ///
+    /// This is meant for lists built once and kept until the program
+    /// exits. The spare capacity is released first. The memory is never
+    /// freed.
+    pub fn leak<'a>(self) -> LinkedSlice<'a, T, I> {
+        let (nodes, head, tail) = self.into_boxed_parts();
+        LinkedSlice::new(Box::leak(nodes), head, tail)
+    }
+
+    /// Rebuilds a list from its raw components.
+    ///
+    /// # Safety
+    ///
+    /// `ptr`, `len` and `capacity` must satisfy the requirements of
+    /// [`Vec::from_raw_parts`](alloc::vec::Vec::from_raw_parts) for
+    /// [`VecNode<T, I>`]. Additionally, the first `len` nodes must form a
+    /// single list from `head` to `tail` as described in
+    /// [`into_raw_parts`](LinkedVec::into_raw_parts), with every link a
+    /// valid physical index below `len`.
+    pub unsafe fn from_raw_parts(
+        ptr: *mut VecNode<T, I>,
+        len: usize,
+        capacity: usize,
+        head: Option<I>,
+        tail: Option<I>,
+    ) -> Self {
+        let ret = Self {
+            // Safety: Ensured by the caller
+            data: unsafe { raw::from_raw_parts(ptr, len, capacity) },
+            head,
+            tail,
+            version: 0,
+            overflow_policy: OverflowPolicy::Error,
+            max_len: None,
+        };
+        paranoid_check!(ret);
+        ret
+    }
+
+    /// Takes the physical array, leaving the list empty.
+    fn take_data(&mut self) -> Vec<VecNode<T, I>> {
+        self.head = None;
+        self.tail = None;
+        core::mem::replace(&mut self.data, Vec::new())
+    }
+}
+
+impl<T, I: StoreIndex + Copy, A: Allocator> LinkedVec<T, I, A> {
+    /// Creates an empty list whose physical array is allocated with `alloc`.
+    pub const fn new_in(alloc: A) -> Self {
+        Self {
+            data: Vec::new_in(alloc),
+            head: None,
+            tail: None,
+            version: 0,
+            overflow_policy: OverflowPolicy::Error,
+            max_len: None,
         }
     }
 
+    /// Returns a reference to the underlying allocator.
+    pub fn allocator(&self) -> &A {
+        self.data.allocator()
+    }
+
     /// Moves all elements from `other` to the end of the list.
     ///
-    /// After this operation, `other` becomes empty.
+    /// After this operation, `other` becomes empty. The nodes of `other`
+    /// keep their relative physical order and are placed after the nodes
+    /// of `self`.
     ///
-    /// While in regular linked lists, this is *O*(1),
-    /// this is *O*(n). It is provided only for API consistency.
-    pub fn append(&mut self, other: &mut Self) {
-        let mut third = Self::new();
-        core::mem::swap(other, &mut third);
-        self.extend(third)
+    /// While in regular linked lists, this is *O*(1), this is *O*(m) in
+    /// the length of `other`. The nodes are moved in bulk and their links
+    /// offset, without relinking node by node.
+    pub fn append<B: Allocator>(&mut self, other: &mut LinkedVec<T, I, B>) {
+        if other.is_empty() {
+            return;
+        }
+        let offset = self.len();
+        if offset + other.len() - 1 > I::MAX_USIZE {
+            capacity_overflow()
+        }
+
+        let shift = |x: Option<I>| x.map(|x| I::from_usize(x.to_usize() + offset));
+        let head = other.head.take();
+        let tail = other.tail.take();
+        self.data.reserve(other.len());
+        self.data.extend(other.data.drain(..).map(|mut node| {
+            node.next = shift(node.next);
+            node.prev = shift(node.prev);
+            node
+        }));
+        self.pair(self.tail, shift(head));
+        self.tail = shift(tail);
+        paranoid_check!(self);
     }
 
     pub fn len(&self) -> usize {
         self.data.len()
     }
 
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
+    pub const fn is_empty(&self) -> bool {
+        // The head is only missing when there are no nodes
+        self.head.is_none()
+    }
+
+    /// Returns a stamp that changes with every structural change to the
+    /// list.
+    ///
+    /// Inserting, removing and relinking elements all change the version,
+    /// and so may methods that end up changing nothing, like clearing an
+    /// empty list. Changing elements in place, swapping payloads with
+    /// [`swap_p`](Self::swap_p) and reserving capacity do not. Two equal
+    /// versions of the same list mean that every element kept both its
+    /// logical and its physical index in between.
+    ///
+    /// The counter wraps around after `u64::MAX` changes. It starts at zero
+    /// for new lists, so it says nothing when comparing different lists.
+    #[must_use]
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Returns what pushing does when `I` cannot index another element.
+    #[must_use]
+    pub fn overflow_policy(&self) -> OverflowPolicy {
+        self.overflow_policy
+    }
+
+    /// Sets what pushing does when `I` cannot index another element.
+    ///
+    /// With [`OverflowPolicy::Evict`], a list of a small index type like
+    /// `u8` turns into a bounded recency list. New lists start with
+    /// [`OverflowPolicy::Error`], including lists split off from this one.
+    /// Clones keep the policy.
+    pub fn set_overflow_policy(&mut self, policy: OverflowPolicy) {
+        self.overflow_policy = policy;
+    }
+
+    /// Returns the length at which
+    /// [`push_front_evicting`](Self::push_front_evicting) and
+    /// [`push_back_evicting`](Self::push_back_evicting) start evicting, if
+    /// any.
+    #[must_use]
+    pub fn max_len(&self) -> Option<usize> {
+        self.max_len
+    }
+
+    /// Sets the length at which
+    /// [`push_front_evicting`](Self::push_front_evicting) and
+    /// [`push_back_evicting`](Self::push_back_evicting) start evicting.
+    ///
+    /// This makes the list a fixed-size recency buffer that does not
+    /// depend on the capacity. Nothing is removed right away if the list
+    /// is already longer; the evicting pushes then keep its length. Other
+    /// insertions ignore the maximum. New lists have none, including
+    /// lists split off from this one. Clones keep it.
+    pub fn set_max_len(&mut self, max_len: Option<usize>) {
+        self.max_len = max_len;
+    }
+
+    /// Returns the number of nodes the physical array can hold without
+    /// reallocating.
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+
+    /// Returns the size in bytes of one node of the physical array, which
+    /// is what each element costs.
+    ///
+    /// See [`VecNode`] for the sizes it is guaranteed not to exceed.
+    #[must_use]
+    pub const fn node_size() -> usize {
+        core::mem::size_of::<VecNode<T, I>>()
+    }
+
+    /// Returns the number of heap bytes allocated for the physical array.
+    ///
+    /// This counts the whole capacity, not only the nodes in use. It does
+    /// not count the list itself, which may be on the stack.
+    pub fn allocated_bytes(&self) -> usize {
+        self.capacity() * Self::node_size()
+    }
+
+    /// Returns the number of heap bytes taken up by the nodes in use.
+    ///
+    /// Heap memory owned by the elements themselves is not counted.
+    pub fn used_bytes(&self) -> usize {
+        self.len() * Self::node_size()
+    }
+
+    /// Returns a reference to the element at `index` on the physical array,
+    /// or `None` if the index is out of bounds.
+    #[must_use]
+    pub fn get_p(&self, index: usize) -> Option<&T> {
+        self.data.get(index).map(|x| &x.payload)
+    }
+
+    /// Returns a mutable reference to the element at `index` on the physical
+    /// array, or `None` if the index is out of bounds.
+    #[must_use]
+    pub fn get_p_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.data.get_mut(index).map(|x| &mut x.payload)
     }
 
-    pub fn get_p(&self, index: usize) -> &T {
+    /// Returns a reference to the element at `index` on the physical array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    #[must_use]
+    #[cfg_attr(feature = "no-panic", deprecated(note = "can panic, use `get_p`"))]
+    pub fn index_p(&self, index: usize) -> &T {
+        if index >= self.len() {
+            index_out_of_bounds(index, self.len())
+        }
         &self.data[index].payload
     }
 
-    pub fn get_p_mut(&mut self, index: usize) -> &mut T {
+    /// Returns a mutable reference to the element at `index` on the physical
+    /// array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    #[must_use]
+    #[cfg_attr(feature = "no-panic", deprecated(note = "can panic, use `get_p_mut`"))]
+    pub fn index_p_mut(&mut self, index: usize) -> &mut T {
+        if index >= self.len() {
+            index_out_of_bounds(index, self.len())
+        }
         &mut self.data[index].payload
     }
 
-    /// Provides a reference to the front element, or `None` if the list is
-    /// empty.
+    /// Returns a reference to the element at logical `index`, or `None` if
+    /// the index is out of bounds.
+    ///
+    /// This operation should compute in *O*(min(index, len - index)) time.
+    /// Use a [`Finger`] to look up many nearby indices.
+    #[must_use]
+    pub fn get_l(&self, index: usize) -> Option<&T> {
+        self.nth_p(index).map(|p| &self.data[p].payload)
+    }
+
+    /// Returns a mutable reference to the element at logical `index`, or
+    /// `None` if the index is out of bounds.
+    ///
+    /// This operation should compute in *O*(min(index, len - index)) time.
+    #[must_use]
+    pub fn get_l_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.nth_p(index).map(|p| &mut self.data[p].payload)
+    }
+
+    /// Returns a reference to the element `n` places after the front, or
+    /// `None` if there are not that many elements.
+    ///
+    /// This is the same as [`get_l`](Self::get_l), and walks from whichever
+    /// end is closer.
+    #[must_use]
+    pub fn nth_front(&self, n: usize) -> Option<&T> {
+        self.get_l(n)
+    }
+
+    /// Returns a mutable reference to the element `n` places after the
+    /// front, or `None` if there are not that many elements.
+    #[must_use]
+    pub fn nth_front_mut(&mut self, n: usize) -> Option<&mut T> {
+        self.get_l_mut(n)
+    }
+
+    /// Returns a reference to the element `n` places before the back, so
+    /// `nth_back(0)` is the same as [`back`](Self::back), or `None` if
+    /// there are not that many elements.
+    ///
+    /// This walks from whichever end is closer.
+    #[must_use]
+    pub fn nth_back(&self, n: usize) -> Option<&T> {
+        let index = self.len().checked_sub(n)?.checked_sub(1)?;
+        self.get_l(index)
+    }
+
+    /// Returns a mutable reference to the element `n` places before the
+    /// back, or `None` if there are not that many elements.
+    #[must_use]
+    pub fn nth_back_mut(&mut self, n: usize) -> Option<&mut T> {
+        let index = self.len().checked_sub(n)?.checked_sub(1)?;
+        self.get_l_mut(index)
+    }
+
+    /// Provides a [`Finger`] for fast lookups of nearby logical indices.
+    #[must_use]
+    pub fn finger(&self) -> Finger<'_, T, I, A> {
+        Finger::new(self)
+    }
+
+    /// Returns mutable references to many elements at once, by their
+    /// indices on the physical array.
+    ///
+    /// Returns `None` if any index is out of bounds, or if the same index
+    /// was passed more than once.
+    #[must_use]
+    pub fn get_many_mut_p<const N: usize>(&mut self, indices: [usize; N]) -> Option<[&mut T; N]> {
+        let nodes = self.data.get_disjoint_mut(indices).ok()?;
+        Some(nodes.map(|x| &mut x.payload))
+    }
+
+    /// Returns the physical index of the front element, or `None` if the
+    /// list is empty.
+    #[must_use]
+    pub fn head_p(&self) -> Option<usize> {
+        self.head.map(|x| x.to_usize())
+    }
+
+    /// Returns the physical index of the back element, or `None` if the
+    /// list is empty.
+    #[must_use]
+    pub fn tail_p(&self) -> Option<usize> {
+        self.tail.map(|x| x.to_usize())
+    }
+
+    /// Returns the physical indices of the elements before and after the
+    /// one at physical `index`, as `(prev, next)`, or `None` if the index
+    /// is out of bounds.
+    ///
+    /// The front element has no `prev` and the back element no `next`.
+    #[must_use]
+    pub fn links_of(&self, index: usize) -> Option<(Option<usize>, Option<usize>)> {
+        let node = self.data.get(index)?;
+        Some((
+            node.prev.map(|x| x.to_usize()),
+            node.next.map(|x| x.to_usize()),
+        ))
+    }
+
+    /// Returns the physical index of the element after the one at
+    /// physical `index`, or `None` if it is the back element.
+    ///
+    /// Together with [`head_p`](Self::head_p), this allows walking the
+    /// links without the bookkeeping of a cursor.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be less than `len`.
+    #[must_use]
+    pub unsafe fn next_p_unchecked(&self, index: usize) -> Option<usize> {
+        debug_assert!(index < self.len());
+        // Safety: Ensured by the caller
+        let node = unsafe { self.data.get_unchecked(index) };
+        node.next.map(|x| x.to_usize())
+    }
+
+    /// Returns the physical index of the element before the one at
+    /// physical `index`, or `None` if it is the front element.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be less than `len`.
+    #[must_use]
+    pub unsafe fn prev_p_unchecked(&self, index: usize) -> Option<usize> {
+        debug_assert!(index < self.len());
+        // Safety: Ensured by the caller
+        let node = unsafe { self.data.get_unchecked(index) };
+        node.prev.map(|x| x.to_usize())
+    }
+
+    /// Provides a reference to the front element, the first in logical
+    /// order, or `None` if the list is empty.
     ///
     /// This operation should compute in *O*(1) time.
     #[must_use]
     pub fn front(&self) -> Option<&T> {
-        self.head.map(|x| self.get_p(x.to_usize()))
+        self.head.map(|x| self.index_p(x.to_usize()))
     }
 
-    /// Provides a mutable reference to the front element, or `None` if the list is
-    /// empty.
+    /// Provides a mutable reference to the front element, the first in
+    /// logical order, or `None` if the list is empty.
     ///
     /// This operation should compute in *O*(1) time.
     #[must_use]
     pub fn front_mut(&mut self) -> Option<&mut T> {
-        self.head.map(|x| self.get_p_mut(x.to_usize()))
+        self.head.map(|x| self.index_p_mut(x.to_usize()))
     }
 
-    /// Provides a reference to the back element, or `None` if the list is
-    /// empty.
+    /// Provides a reference to the back element, the last in logical
+    /// order, or `None` if the list is empty.
     ///
     /// This operation should compute in *O*(1) time.
     #[must_use]
     pub fn back(&self) -> Option<&T> {
-        self.tail.map(|x| self.get_p(x.to_usize()))
+        self.tail.map(|x| self.index_p(x.to_usize()))
     }
 
-    /// Provides a mutable reference to the back element, or `None` if the list is
-    /// empty.
+    /// Provides a mutable reference to the back element, the last in
+    /// logical order, or `None` if the list is empty.
     ///
     /// This operation should compute in *O*(1) time.
     #[must_use]
     pub fn back_mut(&mut self) -> Option<&mut T> {
-        self.tail.map(|x| self.get_p_mut(x.to_usize()))
+        self.tail.map(|x| self.index_p_mut(x.to_usize()))
+    }
+
+    /// Provides a reference to the first element of the physical array,
+    /// or `None` if the list is empty.
+    #[must_use]
+    pub fn front_p(&self) -> Option<&T> {
+        self.data.first().map(|x| &x.payload)
+    }
+
+    /// Provides a mutable reference to the first element of the physical
+    /// array, or `None` if the list is empty.
+    #[must_use]
+    pub fn front_p_mut(&mut self) -> Option<&mut T> {
+        self.data.first_mut().map(|x| &mut x.payload)
+    }
+
+    /// Provides a reference to the last element of the physical array, or
+    /// `None` if the list is empty.
+    ///
+    /// This is the element that [`pop`](Self::pop) removes next, and the
+    /// one that [`swap_remove`](Self::swap_remove) moves into the freed
+    /// slot.
+    #[must_use]
+    pub fn back_p(&self) -> Option<&T> {
+        self.data.last().map(|x| &x.payload)
+    }
+
+    /// Provides a mutable reference to the last element of the physical
+    /// array, or `None` if the list is empty.
+    #[must_use]
+    pub fn back_p_mut(&mut self) -> Option<&mut T> {
+        self.data.last_mut().map(|x| &mut x.payload)
     }
 
     /// Inserts an element first in the linked list and last in the physical array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `I` cannot index another element, unless the
+    /// [`OverflowPolicy`] is `Evict`.
+    #[cfg_attr(
+        feature = "no-panic",
+        deprecated(note = "can panic, use `try_push_front`")
+    )]
     pub fn push_front(&mut self, value: T) {
+        if self.len() > I::MAX_USIZE {
+            drop(self.make_room());
+        }
         let inserted = self.push_p(value);
 
         // Insert at head = Insert before whatever is currently pointed to by head.
-        self.insert_node_before(inserted, self.head)
+        self.insert_node_before(inserted, self.head);
+        paranoid_check!(self);
     }
 
     /// Inserts an element last in the linked list and last in the physical array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `I` cannot index another element, unless the
+    /// [`OverflowPolicy`] is `Evict`.
+    #[cfg_attr(
+        feature = "no-panic",
+        deprecated(note = "can panic, use `try_push_back`")
+    )]
     pub fn push_back(&mut self, value: T) {
+        if self.len() > I::MAX_USIZE {
+            drop(self.make_room());
+        }
         let inserted: I = self.push_p(value);
 
         // Insert at tail = Insert after whatever is currently pointed to by tail.
-        self.insert_node_after(inserted, self.tail)
+        self.insert_node_after(inserted, self.tail);
+        paranoid_check!(self);
+    }
+
+    /// Inserts an element first in the linked list, first popping the
+    /// back if the list is full.
+    ///
+    /// The list is full when it holds [`max_len`](Self::max_len) elements,
+    /// or when `I` cannot index another element. Returns the evicted
+    /// element, if any. With a maximum length of zero, `value` itself is
+    /// returned. Since the evicted slot is reused, a full list does not
+    /// reallocate.
+    pub fn push_front_evicting(&mut self, value: T) -> Option<T> {
+        if self.max_len == Some(0) {
+            return Some(value);
+        }
+        let evicted = if self.is_full() {
+            self.pop_back()
+        } else {
+            None
+        };
+        self.push_front(value);
+        evicted
+    }
+
+    /// Inserts an element last in the linked list, first popping the front
+    /// if the list is full. See
+    /// [`push_front_evicting`](Self::push_front_evicting).
+    pub fn push_back_evicting(&mut self, value: T) -> Option<T> {
+        if self.max_len == Some(0) {
+            return Some(value);
+        }
+        let evicted = if self.is_full() {
+            self.pop_front()
+        } else {
+            None
+        };
+        self.push_back(value);
+        evicted
+    }
+
+    /// Returns `true` if the list is not empty and pushing would either
+    /// pass the maximum length or overflow `I`.
+    fn is_full(&self) -> bool {
+        !self.is_empty()
+            && (self.max_len.is_some_and(|max| self.len() >= max) || self.len() > I::MAX_USIZE)
+    }
+
+    /// Inserts the items of `iter` at the front of the list, keeping
+    /// their order.
+    ///
+    /// The items are placed last in the physical array, in order, and
+    /// then linked in one pass. If the iterator panics, the items taken
+    /// so far are dropped and the list is left as it was.
+    pub fn extend_front<It: IntoIterator<Item = T>>(&mut self, iter: It) {
+        let start = self.len();
+        let guard = TruncateOnDrop(self, start);
+        guard.0.data.extend(iter.into_iter().map(VecNode::new));
+        core::mem::forget(guard);
+        self.link_prefix(start);
+        paranoid_check!(self);
+    }
+
+    /// Clones the elements of `other` onto the back of the list, keeping
+    /// their order.
+    ///
+    /// Reserves exactly `other.len()` more slots, and links the clones in
+    /// one pass once they are all on the physical array. If a clone
+    /// panics, the clones made so far are dropped and the list is left as
+    /// it was.
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        let start = self.len();
+        self.data.reserve_exact(other.len());
+        let guard = TruncateOnDrop(self, start);
+        guard
+            .0
+            .data
+            .extend(other.iter().map(|x| VecNode::new(x.clone())));
+        core::mem::forget(guard);
+        self.link_suffix(start);
+        paranoid_check!(self);
+    }
+
+    /// Clones the elements in the given logical range onto the back of the
+    /// list, keeping their order.
+    ///
+    /// This operation should compute in *O*(min(start, len - start) +
+    /// range length) time. See [`extend_from_slice`](Self::extend_from_slice).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than the end, or if
+    /// the end is greater than `len`.
+    pub fn extend_from_within<R: RangeBounds<usize>>(&mut self, range: R)
+    where
+        T: Clone,
+    {
+        let (start, end) = range_l(range, self.len());
+        let len = self.len();
+        self.data.reserve_exact(end - start);
+        let mut current = self.nth_p(start);
+        let guard = TruncateOnDrop(self, len);
+        for _ in start..end {
+            let p = current.unwrap();
+            current = guard.0.data[p].next.map(|x| x.to_usize());
+            let value = guard.0.data[p].payload.clone();
+            guard.0.data.push(VecNode::new(value));
+        }
+        core::mem::forget(guard);
+        self.link_suffix(len);
+        paranoid_check!(self);
     }
 
     /// Remove and return first element in the linked list, if any.
@@ -130,15 +817,22 @@ impl<T, I: StoreIndex + Copy> LinkedVec<T, I> {
 
     /// Remove and return last element in the physical array, if any.
     pub fn pop(&mut self) -> Option<T> {
-        if self.is_empty() {
+        // Checks the array itself, which the unchecked pop below relies on
+        if self.data.is_empty() {
             return None;
         };
         self.remove_node_p(self.len() - 1);
         // Safety: Already checked that data.len() is not empty
-        Some(unsafe { self.data.pop().unwrap_unchecked().payload })
+        let payload = unsafe { self.data.pop().unwrap_unchecked().payload };
+        paranoid_check!(self);
+        Some(payload)
     }
 
     /// Remove and return the element pointed to by the index on the physical array.
+    #[cfg_attr(
+        feature = "no-panic",
+        deprecated(note = "can panic, use `try_swap_remove`")
+    )]
     pub fn swap_remove(&mut self, index: usize) -> T {
         if index >= self.len() {
             index_out_of_bounds(index, self.len())
@@ -146,42 +840,845 @@ impl<T, I: StoreIndex + Copy> LinkedVec<T, I> {
         self.in_swap_remove(index)
     }
 
-    /// Provides a forward iterator.
-    #[must_use]
-    pub fn iter(&self) -> Iter<'_, T, I> {
-        Iter::new(self)
+    /// Remove and return the element at physical `index` in the array,
+    /// shifting the nodes after it down by one.
+    ///
+    /// Unlike [`swap_remove`](Self::swap_remove), the remaining elements
+    /// keep their relative physical order, like with `Vec::remove`. Every
+    /// link is patched to match.
+    ///
+    /// This operation should compute in *O*(n) time, since every node may
+    /// link to a shifted one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    #[cfg_attr(
+        feature = "no-panic",
+        deprecated(note = "can panic, use `try_remove_p`")
+    )]
+    pub fn remove_p(&mut self, index: usize) -> T {
+        if index >= self.len() {
+            index_out_of_bounds(index, self.len())
+        }
+        self.remove_node_p(index);
+        let payload = self.data.remove(index).payload;
+        self.remap_links(|x| if x > index { x - 1 } else { x });
+        paranoid_check!(self);
+        payload
     }
 
-    /// Provides a forward iterator with mutable references.
-    #[must_use]
-    pub fn iter_mut(&mut self) -> IterMut<'_, T, I> {
-        IterMut::new(self)
+    /// Inserts an element at logical `index` in the linked list and last
+    /// in the physical array.
+    ///
+    /// This operation should compute in *O*(min(index, len - index)) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    #[cfg_attr(
+        feature = "no-panic",
+        deprecated(note = "can panic, use `try_insert_l`")
+    )]
+    pub fn insert_l(&mut self, index: usize, value: T) {
+        if index > self.len() {
+            index_out_of_bounds(index, self.len())
+        }
+        self.insert_before_p(self.nth_p(index), value);
     }
 
-    pub fn clear(&mut self) {
-        // This doesn't clear in a particular order.
-        // FIXME: Should it?
-        self.data.clear();
-        self.head = None;
-        self.tail = None;
+    /// Inserts an element at physical `index` in the array and last in the
+    /// linked list.
+    ///
+    /// The nodes from `index` on are shifted up by one, keeping the
+    /// relative physical order of all elements, and every link is patched
+    /// to match. Use [`push_back`](Self::push_back) if the physical
+    /// position does not matter.
+    ///
+    /// This operation should compute in *O*(n) time, since every node may
+    /// link to a shifted one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    #[cfg_attr(
+        feature = "no-panic",
+        deprecated(note = "can panic, use `try_insert_p`")
+    )]
+    pub fn insert_p(&mut self, index: usize, value: T) {
+        let len = self.len();
+        if index > len {
+            index_out_of_bounds(index, len)
+        }
+        if len > I::MAX_USIZE {
+            capacity_overflow()
+        }
+        self.data.insert(index, VecNode::new(value));
+        self.remap_links(|x| if x >= index { x + 1 } else { x });
+        self.insert_node_after(I::from_usize(index), self.tail);
+        paranoid_check!(self);
     }
 
-    pub fn contains(&self, x: &T) -> bool
-    where
-        T: PartialEq<T>,
-    {
-        self.iter().any(|e| e == x)
+    /// Remove and return the element at logical `index` in the linked list.
+    ///
+    /// This operation should compute in *O*(min(index, len - index)) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    #[cfg_attr(
+        feature = "no-panic",
+        deprecated(note = "can panic, use `try_remove_l`")
+    )]
+    pub fn remove_l(&mut self, index: usize) -> T {
+        match self.nth_p(index) {
+            Some(index_p) => self.in_swap_remove(index_p),
+            None => index_out_of_bounds(index, self.len()),
+        }
     }
 
-    pub fn cursor_front(&self) -> VecCursor<'_, T, I> {
-        VecCursor {
-            index_la: 0,
-            current_pa: self.head.map(|x| x.to_usize()),
-            list: self,
+    /// Remove and return the element pointed to by the index on the physical array.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::IndexOutOfBounds`] if `index >= len`.
+    pub fn try_swap_remove(&mut self, index: usize) -> Result<T, Error> {
+        if index >= self.len() {
+            return Err(Error::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
         }
+        Ok(self.in_swap_remove(index))
     }
 
-    pub fn cursor_front_mut(&mut self) -> VecCursorMut<'_, T, I> {
+    /// Inserts an element first in the linked list and last in the physical
+    /// array.
+    ///
+    /// # Errors
+    ///
+    /// Gives the value back with [`Error::CapacityOverflow`] if `I` cannot
+    /// index another element and the [`OverflowPolicy`] is `Error`, or
+    /// with the error of [`try_reserve`](Self::try_reserve) if the physical
+    /// array cannot grow.
+    pub fn try_push_front(&mut self, value: T) -> Result<(), TryError<T>> {
+        if self.len() > I::MAX_USIZE {
+            if self.overflow_policy == OverflowPolicy::Error {
+                return Err(TryError::new(Error::CapacityOverflow, value));
+            }
+            drop(self.make_room());
+        }
+        if let Err(err) = self.try_reserve(1) {
+            return Err(TryError::new(err, value));
+        }
+        self.push_front(value);
+        Ok(())
+    }
+
+    /// Inserts an element last in the linked list and last in the physical
+    /// array.
+    ///
+    /// # Errors
+    ///
+    /// Gives the value back with [`Error::CapacityOverflow`] if `I` cannot
+    /// index another element and the [`OverflowPolicy`] is `Error`, or
+    /// with the error of [`try_reserve`](Self::try_reserve) if the physical
+    /// array cannot grow.
+    pub fn try_push_back(&mut self, value: T) -> Result<(), TryError<T>> {
+        if self.len() > I::MAX_USIZE {
+            if self.overflow_policy == OverflowPolicy::Error {
+                return Err(TryError::new(Error::CapacityOverflow, value));
+            }
+            drop(self.make_room());
+        }
+        if let Err(err) = self.try_reserve(1) {
+            return Err(TryError::new(err, value));
+        }
+        self.push_back(value);
+        Ok(())
+    }
+
+    /// Inserts an element at logical `index` in the linked list and last
+    /// in the physical array. See [`insert_l`](Self::insert_l).
+    ///
+    /// # Errors
+    ///
+    /// Gives the value back with [`Error::IndexOutOfBounds`] if
+    /// `index > len`, or with the error of [`try_reserve`](Self::try_reserve)
+    /// if the list cannot grow.
+    pub fn try_insert_l(&mut self, index: usize, value: T) -> Result<(), TryError<T>> {
+        if let Err(err) = self.check_insert(index) {
+            return Err(TryError::new(err, value));
+        }
+        self.insert_l(index, value);
+        Ok(())
+    }
+
+    /// Inserts an element at physical `index` in the array and last in the
+    /// linked list. See [`insert_p`](Self::insert_p).
+    ///
+    /// # Errors
+    ///
+    /// Gives the value back with [`Error::IndexOutOfBounds`] if
+    /// `index > len`, or with the error of [`try_reserve`](Self::try_reserve)
+    /// if the list cannot grow.
+    pub fn try_insert_p(&mut self, index: usize, value: T) -> Result<(), TryError<T>> {
+        if let Err(err) = self.check_insert(index) {
+            return Err(TryError::new(err, value));
+        }
+        self.insert_p(index, value);
+        Ok(())
+    }
+
+    /// Remove and return the element at logical `index` in the linked list.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::IndexOutOfBounds`] if `index >= len`.
+    pub fn try_remove_l(&mut self, index: usize) -> Result<T, Error> {
+        match self.nth_p(index) {
+            Some(index_p) => Ok(self.in_swap_remove(index_p)),
+            None => Err(Error::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            }),
+        }
+    }
+
+    /// Remove and return the element at physical `index` in the array,
+    /// shifting the nodes after it down by one. See
+    /// [`remove_p`](Self::remove_p).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::IndexOutOfBounds`] if `index >= len`.
+    pub fn try_remove_p(&mut self, index: usize) -> Result<T, Error> {
+        if index >= self.len() {
+            return Err(Error::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        Ok(self.remove_p(index))
+    }
+
+    /// Checks that an element can be inserted at `index`, reserving room
+    /// for it.
+    fn check_insert(&mut self, index: usize) -> Result<(), Error> {
+        if index > self.len() {
+            return Err(Error::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        self.try_reserve(1)
+    }
+
+    /// Provides an adapter whose `Debug` output maps the physical index of
+    /// each element to the element, like `{2: a, 0: b, 1: c}`.
+    #[must_use]
+    pub fn debug_layout(&self) -> DebugLayout<'_, T, I, A> {
+        DebugLayout(self)
+    }
+
+    /// Provides an adapter that displays the elements in logical order,
+    /// separated by `separator`.
+    ///
+    /// Formatting options such as width and precision apply to each
+    /// element.
+    #[must_use]
+    pub fn display<'a>(&'a self, separator: &'a str) -> DisplayList<'a, T, I, A> {
+        DisplayList {
+            list: self,
+            separator,
+        }
+    }
+
+    /// Provides a forward iterator.
+    ///
+    /// Elements are yielded in logical order, which follows the links from
+    /// node to node. Use [`iter_unordered`](Self::iter_unordered) when the
+    /// order does not matter.
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_, T, I, A> {
+        Iter::new(self)
+    }
+
+    /// Provides an iterator over the elements in no particular order.
+    ///
+    /// This scans the physical array from start to end without following
+    /// any links, so it is faster than [`iter`](Self::iter) for sums,
+    /// lookups and filling sets or maps. The order it currently uses is
+    /// physical order, but that is not guaranteed.
+    pub fn iter_unordered(&self) -> IterUnordered<'_, T, I> {
+        IterUnordered::new(self)
+    }
+
+    /// Provides an iterator with mutable references over the elements in
+    /// no particular order.
+    ///
+    /// See [`iter_unordered`](Self::iter_unordered).
+    pub fn iter_unordered_mut(&mut self) -> IterUnorderedMut<'_, T, I> {
+        IterUnorderedMut::new(self)
+    }
+
+    /// Makes this list a copy of `source`, layout included.
+    ///
+    /// This is a faster [`clone_from`](Clone::clone_from) for `Copy`
+    /// elements. The whole node buffer is copied at once, with no work
+    /// per node.
+    pub fn copy_from<B: Allocator>(&mut self, source: &LinkedVec<T, I, B>)
+    where
+        T: Copy,
+    {
+        self.clear_unordered();
+        raw::copy_nodes(&mut self.data, &source.data);
+        self.head = source.head;
+        self.tail = source.tail;
+        paranoid_check!(self);
+    }
+
+    /// Calls `f` on every element in place, in physical order.
+    ///
+    /// This is faster than going through [`iter_mut`](Self::iter_mut)
+    /// when the order does not matter.
+    pub fn map_in_place<F: FnMut(&mut T)>(&mut self, mut f: F) {
+        self.data.iter_mut().for_each(|x| f(&mut x.payload));
+    }
+
+    /// Replaces the contents of the list with the items of `vec`, and
+    /// returns the old elements in logical order.
+    ///
+    /// The new elements are in the same order both logically and
+    /// physically. The allocation of `vec` is reused for the returned
+    /// elements, and the physical array keeps its own allocation.
+    ///
+    /// This operation should compute in *O*(n + m) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `vec` has more elements than `I` can index. The list is
+    /// left unchanged.
+    pub fn swap_with_vec(&mut self, mut vec: alloc::vec::Vec<T>) -> alloc::vec::Vec<T> {
+        let new_len = vec.len();
+        if new_len > 0 && new_len - 1 > I::MAX_USIZE {
+            capacity_overflow()
+        }
+        vec.reserve(self.len());
+
+        let head = self.head.take();
+        self.tail = None;
+        self.bump_version();
+        // Safety: The links visit every node exactly once. `vec` has room
+        // for every element, so pushing cannot panic.
+        unsafe { raw::drain_linked(&mut self.data, head, |x| vec.push(x)) };
+
+        self.data.reserve(new_len);
+        self.data.extend(vec.drain(..new_len).map(VecNode::new));
+        self.link_suffix(0);
+        paranoid_check!(self);
+        vec
+    }
+
+    /// Provides a forward iterator yielding `(index_p, &T)` pairs, where
+    /// `index_p` is the element's index on the physical array.
+    ///
+    /// The physical index can be used later with methods like
+    /// [`swap_remove`](Self::swap_remove), as long as no element has been
+    /// removed in between.
+    #[must_use]
+    pub fn iter_with_p(&self) -> IterWithP<'_, T, I, A> {
+        IterWithP::new(self)
+    }
+
+    /// Provides a forward iterator over the physical indices of the
+    /// elements, in logical order.
+    ///
+    /// To remove several elements by physical index, collect the indices
+    /// first and remove them from the highest down with
+    /// [`swap_remove`](Self::swap_remove). Removing an index only moves the
+    /// physically last node, so the lower indices stay valid.
+    #[must_use]
+    pub fn indices_l(&self) -> IterP<'_, T, I, A> {
+        IterP::new(self)
+    }
+
+    /// Provides a forward iterator yielding `(index_l, index_p, &T)`
+    /// triples, with both the logical and the physical index of each
+    /// element.
+    #[must_use]
+    pub fn iter_indices(&self) -> IterIndices<'_, T, I, A> {
+        IterIndices::new(self)
+    }
+
+    /// Provides a forward iterator yielding `(index_l, index_p, &mut T)`
+    /// triples, with both the logical and the physical index of each
+    /// element.
+    ///
+    /// This lets a single mutable pass note which elements to remove, to
+    /// then remove them with [`swap_remove`](Self::swap_remove) from the
+    /// highest physical index down, without walking the links again.
+    #[must_use]
+    pub fn iter_indices_mut(&mut self) -> IterIndicesMut<'_, T, I> {
+        IterIndicesMut::new(self)
+    }
+
+    /// Provides an iterator over all contiguous windows of `size`
+    /// elements, in logical order.
+    ///
+    /// Each window is an iterator over its elements. There are no windows
+    /// if the list is shorter than `size`. Moving to the next window takes
+    /// *O*(1) time, and nothing is copied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    #[must_use]
+    pub fn windows(&self, size: usize) -> Windows<'_, T, I, A> {
+        Windows::new(self, size)
+    }
+
+    /// Provides an iterator over runs of `size` elements, in logical
+    /// order, starting at the front.
+    ///
+    /// Each chunk is an iterator over its elements. The last chunk has
+    /// fewer than `size` elements if `size` does not divide the length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    #[must_use]
+    pub fn chunks(&self, size: usize) -> Chunks<'_, T, I, A> {
+        Chunks::new(self, size)
+    }
+
+    /// Provides an iterator over the runs of adjacent elements for which
+    /// `pred` holds, in logical order, like [`<[T]>::chunk_by`].
+    ///
+    /// `pred` is called on each pair of neighbours, and a new run starts
+    /// wherever it returns `false`. Each run is an iterator over its
+    /// elements.
+    #[must_use]
+    pub fn chunk_by<F: FnMut(&T, &T) -> bool>(&self, pred: F) -> ChunkBy<'_, T, I, A, F> {
+        ChunkBy::new(self, pred)
+    }
+
+    /// Provides an iterator over runs of `N` elements as arrays of
+    /// references, in logical order, starting at the front.
+    ///
+    /// If `N` does not divide the length, the last `len % N` elements are
+    /// not yielded. See [`ArrayChunks::remainder`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    #[must_use]
+    pub fn array_chunks<const N: usize>(&self) -> ArrayChunks<'_, T, I, A, N> {
+        ArrayChunks::new(self)
+    }
+
+    /// Consumes the list into an iterator over runs of `N` elements as
+    /// arrays, in logical order, starting at the front.
+    ///
+    /// If `N` does not divide the length, the last `len % N` elements are
+    /// not yielded. See [`IntoArrayChunks::into_remainder`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    #[must_use]
+    pub fn into_array_chunks<const N: usize>(self) -> IntoArrayChunks<T, I, A, N> {
+        IntoArrayChunks::new(self)
+    }
+
+    /// Provides a forward iterator with mutable references.
+    #[must_use]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, I> {
+        IterMut::new(self)
+    }
+
+    /// Removes all elements from the list.
+    ///
+    /// The elements are dropped in logical order, front to back.
+    pub fn clear(&mut self) {
+        self.truncate(0)
+    }
+
+    /// Removes all elements from the list, dropping them in no particular
+    /// order.
+    ///
+    /// This is faster than [`clear`](Self::clear) for types that need to
+    /// be dropped.
+    pub fn clear_unordered(&mut self) {
+        self.data.clear();
+        self.head = None;
+        self.tail = None;
+        self.bump_version();
+        paranoid_check!(self);
+    }
+
+    /// Shortens the list, keeping the first `len` elements in logical order
+    /// and dropping the rest.
+    ///
+    /// The removed elements are dropped in logical order, front to back.
+    /// If `len` is greater or equal to the list's current length, this has
+    /// no effect.
+    pub fn truncate(&mut self, len: usize) {
+        if !core::mem::needs_drop::<T>() && len == 0 {
+            return self.clear_unordered();
+        }
+        let mut current = self.nth_p(len);
+        while let Some(p) = current {
+            let next = self.data[p].next.map(|x| x.to_usize());
+            let last = self.len() - 1;
+            drop(self.in_swap_remove(p));
+            // The last node may have been moved into the freed slot
+            current = if next == Some(last) { Some(p) } else { next };
+        }
+    }
+
+    /// Provides a guard for a batch of speculative pushes.
+    ///
+    /// The guard dereferences to the list. Unless
+    /// [`commit`](PushGuard::commit) is called, dropping the guard removes
+    /// every node added since, with [`truncate_p`](Self::truncate_p). This
+    /// also happens if a panic unwinds past the guard.
+    ///
+    /// Only pushes are rolled back cleanly. Other changes made through the
+    /// guard may move nodes in or out of the rolled back range.
+    #[must_use = "dropping the guard rolls back right away"]
+    pub fn push_guard(&mut self) -> PushGuard<'_, T, I, A> {
+        PushGuard {
+            len: self.len(),
+            list: self,
+        }
+    }
+
+    /// Shortens the physical array, keeping the first `len` nodes and
+    /// dropping the rest.
+    ///
+    /// Each removed node is unlinked first, so the remaining elements keep
+    /// both their logical order and their physical indices. This undoes
+    /// pushes made since the list had `len` elements. The removed elements
+    /// are dropped in physical order. If `len` is greater or equal to the
+    /// list's current length, this has no effect.
+    ///
+    /// This operation should compute in *O*(k) time, in the number of
+    /// elements removed.
+    pub fn truncate_p(&mut self, len: usize) {
+        for index in (len..self.len()).rev() {
+            self.remove_node_p(index);
+        }
+        self.data.truncate(len);
+        paranoid_check!(self);
+    }
+
+    /// Returns `true` if the list contains an element equal to `x`.
+    ///
+    /// The physical array is scanned in order, without following links.
+    pub fn contains(&self, x: &T) -> bool
+    where
+        T: PartialEq<T>,
+    {
+        self.data.iter().any(|e| e.payload == *x)
+    }
+
+    /// Returns `true` if the list contains an element equal to `x`,
+    /// comparing the elements in logical order.
+    ///
+    /// This is slower than [`contains`](Self::contains), but stops at the
+    /// first match from the front, which matters if comparisons have side
+    /// effects.
+    pub fn contains_ordered(&self, x: &T) -> bool
+    where
+        T: PartialEq<T>,
+    {
+        self.iter().any(|e| e == x)
+    }
+
+    /// Returns the number of elements matching `pred`.
+    ///
+    /// `pred` is called on every element in physical order.
+    pub fn count_matching<P: FnMut(&T) -> bool>(&self, mut pred: P) -> usize {
+        self.data.iter().filter(|x| pred(&x.payload)).count()
+    }
+
+    /// Returns `true` if any element matches `pred`.
+    ///
+    /// The elements are visited in physical order, stopping at the first
+    /// match. Use `iter().any(pred)` to visit them in logical order.
+    pub fn any_p<P: FnMut(&T) -> bool>(&self, mut pred: P) -> bool {
+        self.data.iter().any(|x| pred(&x.payload))
+    }
+
+    /// Returns `true` if every element matches `pred`, or if the list is
+    /// empty.
+    ///
+    /// The elements are visited in physical order, stopping at the first
+    /// mismatch. Use `iter().all(pred)` to visit them in logical order.
+    pub fn all_p<P: FnMut(&T) -> bool>(&self, mut pred: P) -> bool {
+        self.data.iter().all(|x| pred(&x.payload))
+    }
+
+    /// Returns `true` if the first elements of the list, in logical order,
+    /// are equal to the items of `prefix`.
+    ///
+    /// `prefix` can be a slice, another list, or any iterator of
+    /// references.
+    pub fn starts_with<'b, It>(&self, prefix: It) -> bool
+    where
+        It: IntoIterator<Item = &'b T>,
+        T: PartialEq + 'b,
+    {
+        let mut it = self.iter();
+        prefix.into_iter().all(|x| it.next() == Some(x))
+    }
+
+    /// Returns `true` if the last elements of the list, in logical order,
+    /// are equal to the items of `suffix`.
+    ///
+    /// `suffix` can be a slice, another list, or any double-ended
+    /// iterator of references.
+    pub fn ends_with<'b, It>(&self, suffix: It) -> bool
+    where
+        It: IntoIterator<Item = &'b T>,
+        It::IntoIter: DoubleEndedIterator,
+        T: PartialEq + 'b,
+    {
+        let mut it = self.iter().rev();
+        suffix.into_iter().rev().all(|x| it.next() == Some(x))
+    }
+
+    /// Removes the elements at the given physical indices and returns them
+    /// in the same order as `indices`.
+    ///
+    /// All the nodes are unlinked first, then the physical array is
+    /// compacted in a single sweep. Unlike [`swap_remove`](Self::swap_remove),
+    /// the remaining elements keep their relative physical order.
+    ///
+    /// This operation should compute in *O*(n) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index is out of bounds or appears more than once. The
+    /// list is left unchanged.
+    pub fn remove_batch_p(&mut self, indices: &[usize]) -> alloc::vec::Vec<T> {
+        let len = self.len();
+        // The position in `indices` of every removed node
+        let mut slots = alloc::vec![usize::MAX; len];
+        for (slot, &index) in indices.iter().enumerate() {
+            if index >= len {
+                index_out_of_bounds(index, len)
+            }
+            if slots[index] != usize::MAX {
+                panic!("physical index {index} appears more than once");
+            }
+            slots[index] = slot;
+        }
+        for &index in indices {
+            self.remove_node_p(index);
+        }
+
+        // Move the survivors to the front, remembering where each node
+        // came from and where each survivor went.
+        let mut origins: alloc::vec::Vec<usize> = (0..len).collect();
+        let mut moved_to = alloc::vec![0; len];
+        let mut write = 0;
+        for read in 0..len {
+            if slots[read] == usize::MAX {
+                self.data.swap(write, read);
+                origins.swap(write, read);
+                moved_to[read] = write;
+                write += 1;
+            }
+        }
+
+        let mut removed: alloc::vec::Vec<Option<T>> = indices.iter().map(|_| None).collect();
+        while self.len() > write {
+            let origin = origins[self.len() - 1];
+            let node = self.data.pop().unwrap();
+            removed[slots[origin]] = Some(node.payload);
+        }
+        let remap = |x: Option<I>| x.map(|x| I::from_usize(moved_to[x.to_usize()]));
+        for node in self.data.iter_mut() {
+            node.next = remap(node.next);
+            node.prev = remap(node.prev);
+        }
+        self.head = remap(self.head);
+        self.tail = remap(self.tail);
+        paranoid_check!(self);
+        removed.into_iter().map(Option::unwrap).collect()
+    }
+
+    /// Removes the elements at the given logical indices and returns them
+    /// in the same order as `indices`.
+    ///
+    /// The indices refer to the positions before any element is removed.
+    /// See [`remove_batch_p`](Self::remove_batch_p).
+    ///
+    /// This operation should compute in *O*(n + k log k) time, where k is
+    /// the number of indices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index is out of bounds or appears more than once. The
+    /// list is left unchanged.
+    pub fn remove_batch_l(&mut self, indices: &[usize]) -> alloc::vec::Vec<T> {
+        let mut order: alloc::vec::Vec<usize> = (0..indices.len()).collect();
+        order.sort_unstable_by_key(|&slot| indices[slot]);
+        if let Some(&last) = order.last() {
+            if indices[last] >= self.len() {
+                index_out_of_bounds(indices[last], self.len())
+            }
+        }
+
+        let mut indices_p = alloc::vec![0; indices.len()];
+        let mut iter_p = IterP::new(self).enumerate();
+        for slot in order {
+            indices_p[slot] = match iter_p.find(|&(l, _)| l == indices[slot]) {
+                Some((_, p)) => p,
+                None => panic!("logical index {} appears more than once", indices[slot]),
+            };
+        }
+        self.remove_batch_p(&indices_p)
+    }
+
+    /// Creates an iterator which uses a closure to determine if an element
+    /// should be removed.
+    ///
+    /// If the closure returns true, then the element is removed and yielded.
+    /// If the closure returns false, the element will remain in the list and
+    /// will not be yielded by the iterator. Elements are visited in logical
+    /// order.
+    ///
+    /// If the returned `ExtractIf` is not exhausted, e.g. because it is
+    /// dropped without iterating or the iteration short-circuits, then the
+    /// remaining elements will be retained.
+    pub fn extract_if<F>(&mut self, filter: F) -> ExtractIf<'_, T, I, A, F>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        ExtractIf {
+            next_pa: self.head.map(|x| x.to_usize()),
+            remaining: self.len(),
+            list: self,
+            pred: filter,
+            anchor_pa: None,
+        }
+    }
+
+    /// Retains only the elements for which `f` returns `true`, passing
+    /// the logical index of each element along with it.
+    ///
+    /// Elements are visited in logical order, and the indices are their
+    /// positions from before any element is removed.
+    pub fn retain_with_index<F: FnMut(usize, &mut T) -> bool>(&mut self, mut f: F) {
+        let mut index = 0;
+        self.extract_if(|x| {
+            let keep = f(index, x);
+            index += 1;
+            !keep
+        })
+        .for_each(drop);
+    }
+
+    /// Returns `true` if every element's physical index is its logical
+    /// index.
+    ///
+    /// This operation should compute in *O*(n) time.
+    pub fn is_contiguous(&self) -> bool {
+        IterP::new(self).enumerate().all(|(l, p)| l == p)
+    }
+
+    /// Binary searches the physical array for `x`, and returns the
+    /// physical index of a matching element, or of where it could be
+    /// inserted while keeping the array sorted.
+    ///
+    /// The elements must be sorted in physical order, regardless of their
+    /// logical order. This is the case for a list sorted in logical order
+    /// if [`is_contiguous`](Self::is_contiguous) is `true`. Otherwise the
+    /// result is unspecified, as with `binary_search` on slices.
+    ///
+    /// This operation should compute in *O*(log n) time.
+    pub fn binary_search_p(&self, x: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.binary_search_by_p(|y| y.cmp(x))
+    }
+
+    /// Binary searches the physical array with a comparator function, and
+    /// returns a physical index like [`binary_search_p`](Self::binary_search_p).
+    ///
+    /// `f` returns whether an element is less than, equal to or greater
+    /// than the target. The elements must be sorted in physical order
+    /// according to it.
+    pub fn binary_search_by_p<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> core::cmp::Ordering,
+    {
+        self.data.binary_search_by(|node| f(&node.payload))
+    }
+
+    /// Returns the physical index of the first element for which `pred`
+    /// is `false`, assuming that the physical array is partitioned with
+    /// the elements for which it is `true` first.
+    ///
+    /// See [`binary_search_p`](Self::binary_search_p) for when the physical
+    /// array is sorted.
+    ///
+    /// This operation should compute in *O*(log n) time.
+    pub fn partition_point_p<P: FnMut(&T) -> bool>(&self, mut pred: P) -> usize {
+        self.data.partition_point(|node| pred(&node.payload))
+    }
+
+    /// Returns the logical index of the first element matching `pred`,
+    /// searching from the front.
+    pub fn position<P: FnMut(&T) -> bool>(&self, pred: P) -> Option<usize> {
+        self.iter().position(pred)
+    }
+
+    /// Returns the logical index of the last element matching `pred`,
+    /// searching from the back.
+    pub fn rposition<P: FnMut(&T) -> bool>(&self, pred: P) -> Option<usize> {
+        let from_back = self.iter().rev().position(pred)?;
+        Some(self.len() - 1 - from_back)
+    }
+
+    /// Returns a cursor pointing to the first element matching `pred`,
+    /// or `None` if no element matches.
+    pub fn find_cursor<P: FnMut(&T) -> bool>(&self, pred: P) -> Option<VecCursor<'_, T, I, A>> {
+        let (index_la, current_pa) = self.find_l_p(pred)?;
+        Some(VecCursor {
+            index_la,
+            current_pa: Some(current_pa),
+            list: self,
+        })
+    }
+
+    /// Returns a mutable cursor pointing to the first element matching
+    /// `pred`, or `None` if no element matches.
+    pub fn find_cursor_mut<P: FnMut(&T) -> bool>(
+        &mut self,
+        pred: P,
+    ) -> Option<VecCursorMut<'_, T, I, A>> {
+        let (index_la, current_pa) = self.find_l_p(pred)?;
+        Some(VecCursorMut {
+            index_la,
+            current_pa: Some(current_pa),
+            list: self,
+        })
+    }
+
+    pub fn cursor_front(&self) -> VecCursor<'_, T, I, A> {
+        VecCursor {
+            index_la: 0,
+            current_pa: self.head.map(|x| x.to_usize()),
+            list: self,
+        }
+    }
+
+    pub fn cursor_front_mut(&mut self) -> VecCursorMut<'_, T, I, A> {
         VecCursorMut {
             index_la: 0,
             current_pa: self.head.map(|x| x.to_usize()),
@@ -189,7 +1686,7 @@ impl<T, I: StoreIndex + Copy> LinkedVec<T, I> {
         }
     }
 
-    pub fn cursor_back(&self) -> VecCursor<'_, T, I> {
+    pub fn cursor_back(&self) -> VecCursor<'_, T, I, A> {
         match self.tail {
             // list nonempty
             Some(tail) => VecCursor {
@@ -207,7 +1704,7 @@ impl<T, I: StoreIndex + Copy> LinkedVec<T, I> {
         }
     }
 
-    pub fn cursor_back_mut(&mut self) -> VecCursorMut<'_, T, I> {
+    pub fn cursor_back_mut(&mut self) -> VecCursorMut<'_, T, I, A> {
         match self.tail {
             // list nonempty
             Some(tail) => VecCursorMut {
@@ -216,36 +1713,360 @@ impl<T, I: StoreIndex + Copy> LinkedVec<T, I> {
                 list: self,
             },
 
-            // list empty
-            None => VecCursorMut {
-                index_la: 0,
-                current_pa: None,
-                list: self,
-            },
+            // list empty
+            None => VecCursorMut {
+                index_la: 0,
+                current_pa: None,
+                list: self,
+            },
+        }
+    }
+
+    /// Returns a cursor at the position saved in `token`, or `None` if the
+    /// list has been structurally changed since the token was saved.
+    ///
+    /// The token should come from a cursor over this list. A token from
+    /// another list gives an unspecified position, or `None`.
+    #[must_use]
+    pub fn resume(&self, token: CursorToken) -> Option<VecCursor<'_, T, I, A>> {
+        let (index_la, current_pa) = self.check_token(token)?;
+        Some(VecCursor {
+            index_la,
+            current_pa,
+            list: self,
+        })
+    }
+
+    /// Returns a mutable cursor at the position saved in `token`, or `None`
+    /// if the list has been structurally changed since the token was saved.
+    ///
+    /// See [`resume`](Self::resume).
+    #[must_use]
+    pub fn resume_mut(&mut self, token: CursorToken) -> Option<VecCursorMut<'_, T, I, A>> {
+        let (index_la, current_pa) = self.check_token(token)?;
+        Some(VecCursorMut {
+            index_la,
+            current_pa,
+            list: self,
+        })
+    }
+
+    /// Returns a cursor at the position saved in `token`, finding it again
+    /// by its logical index if the list has changed since.
+    ///
+    /// If the saved logical index is now out of bounds, the cursor points
+    /// to the "ghost" non-element.
+    ///
+    /// This operation should compute in *O*(1) time if the token is fresh,
+    /// and *O*(min(index, len - index)) time otherwise.
+    #[must_use]
+    pub fn reanchor(&self, token: CursorToken) -> VecCursor<'_, T, I, A> {
+        if let Some(cursor) = self.resume(token) {
+            return cursor;
+        }
+        let current_pa = token.index_l.and_then(|l| self.nth_p(l));
+        VecCursor {
+            index_la: token
+                .index_l
+                .filter(|_| current_pa.is_some())
+                .unwrap_or(self.len()),
+            current_pa,
+            list: self,
+        }
+    }
+
+    /// Returns a token for the element at logical `index`, or `None` if the
+    /// index is out of bounds.
+    ///
+    /// This is the same token a cursor at that element would
+    /// [`save`](VecCursor::save), without having to borrow one. It is meant
+    /// for code that cannot hold a borrow of the list across calls, such as
+    /// when the list is in a `RefCell` or `Mutex`.
+    ///
+    /// This operation should compute in *O*(min(index, len - index)) time.
+    #[must_use]
+    pub fn token_l(&self, index: usize) -> Option<CursorToken> {
+        let index_p = self.nth_p(index)?;
+        Some(CursorToken {
+            index_l: Some(index),
+            index_p: Some(index_p),
+            version: self.version,
+        })
+    }
+
+    /// Returns a token for the element at physical `index`, or `None` if
+    /// the index is out of bounds.
+    ///
+    /// See [`token_l`](Self::token_l). Finding the logical index takes
+    /// *O*(n) time.
+    #[must_use]
+    pub fn token_p(&self, index: usize) -> Option<CursorToken> {
+        let index_l = self.indices_l().position(|p| p == index)?;
+        Some(CursorToken {
+            index_l: Some(index_l),
+            index_p: Some(index),
+            version: self.version,
+        })
+    }
+
+    /// Returns the element saved in `token`, or `None` if the list has
+    /// been structurally changed since the token was saved or the token is
+    /// at the "ghost" non-element.
+    ///
+    /// Unlike [`resume`](Self::resume), this does not hand out a cursor, so
+    /// the list can be borrowed only for as long as the element is used.
+    /// This operation should compute in *O*(1) time.
+    #[must_use]
+    pub fn reacquire(&self, token: CursorToken) -> Option<&T> {
+        let (_, index_p) = self.check_token(token)?;
+        Some(&self.data[index_p?].payload)
+    }
+
+    /// Returns a mutable reference to the element saved in `token`, or
+    /// `None` if the list has been structurally changed since the token was
+    /// saved or the token is at the "ghost" non-element.
+    ///
+    /// See [`reacquire`](Self::reacquire).
+    #[must_use]
+    pub fn reacquire_mut(&mut self, token: CursorToken) -> Option<&mut T> {
+        let (_, index_p) = self.check_token(token)?;
+        Some(&mut self.data[index_p?].payload)
+    }
+
+    /// Gets the `(index_la, current_pa)` of a cursor saved in `token`, if
+    /// the token is fresh.
+    fn check_token(&self, token: CursorToken) -> Option<(usize, Option<usize>)> {
+        if token.version != self.version {
+            return None;
+        }
+        match (token.index_l, token.index_p) {
+            (None, None) => Some((self.len(), None)),
+            (Some(l), Some(p)) if l < self.len() && p < self.len() => Some((l, Some(p))),
+            _ => None,
+        }
+    }
+
+    /// Provides two mutable cursors pointing to the elements at logical
+    /// indices `a_l` and `b_l`, or `None` if the indices are equal or
+    /// either is out of bounds.
+    ///
+    /// This operation should compute in *O*(n) time.
+    pub fn cursor_pair_mut(
+        &mut self,
+        a_l: usize,
+        b_l: usize,
+    ) -> Option<VecCursorPairMut<'_, T, I, A>> {
+        if a_l == b_l {
+            return None;
+        }
+        let first_pa = self.nth_p(a_l)?;
+        let second_pa = self.nth_p(b_l)?;
+        Some(VecCursorPairMut {
+            first_la: a_l,
+            first_pa: Some(first_pa),
+            second_la: b_l,
+            second_pa: Some(second_pa),
+            list: self,
+        })
+    }
+
+    /// Swaps two elements in the slice.
+    ///
+    /// If `a` equals to `b`, it's guaranteed that elements won't change value.
+    ///
+    /// # Arguments
+    ///
+    /// * a - The index of the first element
+    /// * b - The index of the second element
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` are out of bounds.
+    #[cfg_attr(feature = "no-panic", deprecated(note = "can panic, use `try_swap_p`"))]
+    pub fn swap_p(&mut self, a: usize, b: usize) {
+        for index in [a, b] {
+            if index >= self.len() {
+                index_out_of_bounds(index, self.len())
+            }
+        }
+        raw::swap_payloads(&mut self.data, a, b);
+    }
+
+    /// Swaps two elements in the slice. See [`swap_p`](Self::swap_p).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::IndexOutOfBounds`] if `a` or `b` are out of bounds.
+    pub fn try_swap_p(&mut self, a: usize, b: usize) -> Result<(), Error> {
+        for index in [a, b] {
+            if index >= self.len() {
+                return Err(Error::IndexOutOfBounds {
+                    index,
+                    len: self.len(),
+                });
+            }
+        }
+        self.swap_p(a, b);
+        Ok(())
+    }
+
+    /// Exchanges the elements in two logical ranges of the list.
+    ///
+    /// Only the links at the ends of the ranges change, so no element is
+    /// moved on the physical array and the ranges can have different
+    /// lengths. Finding the ends takes *O*(n) time, and the relinking
+    /// *O*(1) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either range is decreasing or goes past `len`, or if the
+    /// ranges overlap.
+    pub fn swap_ranges_l<R1, R2>(&mut self, a: R1, b: R2)
+    where
+        R1: RangeBounds<usize>,
+        R2: RangeBounds<usize>,
+    {
+        let a = range_l(a, self.len());
+        let b = range_l(b, self.len());
+        let ((s1, e1), (s2, e2)) = if a <= b { (a, b) } else { (b, a) };
+        assert!(e1 <= s2, "ranges {s1}..{e1} and {s2}..{e2} overlap");
+        if s1 == e1 && s2 == e2 {
+            return;
+        }
+
+        // Walk from the start of the first range, keeping the last position
+        // as a hint
+        let mut hint = None;
+        let mut at = |list: &Self, index: usize| {
+            let p = list.nth_p_from(hint, index);
+            if let Some(p) = p {
+                hint = Some((index, p));
+            }
+            p.map(I::from_usize)
+        };
+        let before = s1.checked_sub(1).and_then(|x| at(self, x));
+        let mut segment = |list: &Self, start: usize, end: usize| {
+            (start < end).then(|| (at(list, start).unwrap(), at(list, end - 1).unwrap()))
+        };
+        let first = segment(self, s1, e1);
+        let middle = segment(self, e1, s2);
+        let second = segment(self, s2, e2);
+        let after = at(self, e2);
+
+        let mut last = before;
+        for (head, tail) in [second, middle, first].into_iter().flatten() {
+            self.pair(last, Some(head));
+            last = Some(tail);
+        }
+        self.pair(last, after);
+        paranoid_check!(self);
+    }
+
+    /// Moves the elements in a logical range so that they come right before
+    /// the element at logical index `dest`, or to the back if `dest` is
+    /// `len`.
+    ///
+    /// `dest` is an index from before the move. Like with
+    /// [`swap_ranges_l`](Self::swap_ranges_l), only the links at the ends
+    /// change, so this takes *O*(n) time to find them and *O*(1) time to
+    /// relink.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is decreasing or goes past `len`, if `dest` is
+    /// greater than `len`, or if `dest` is strictly inside the range.
+    pub fn move_range_l<R: RangeBounds<usize>>(&mut self, range: R, dest: usize) {
+        let (start, end) = range_l(range, self.len());
+        if dest > self.len() {
+            index_out_of_bounds(dest, self.len())
+        }
+        assert!(
+            dest <= start || dest >= end,
+            "destination {dest} is inside the range {start}..{end}"
+        );
+        if dest < start {
+            self.swap_ranges_l(dest..start, start..end);
+        } else if dest > end {
+            self.swap_ranges_l(start..end, end..dest);
+        }
+    }
+
+    /// Exchanges the elements in a logical range of this list with those in
+    /// a logical range of equal length of `other`.
+    ///
+    /// Nodes cannot move between lists, so the elements are swapped in
+    /// place instead, and both lists keep their links. This takes *O*(n)
+    /// time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either range is decreasing or goes past the length of its
+    /// list, or if the ranges have different lengths.
+    pub fn swap_ranges_l_with<B: Allocator, R1, R2>(
+        &mut self,
+        range: R1,
+        other: &mut LinkedVec<T, I, B>,
+        other_range: R2,
+    ) where
+        R1: RangeBounds<usize>,
+        R2: RangeBounds<usize>,
+    {
+        let (s1, e1) = range_l(range, self.len());
+        let (s2, e2) = range_l(other_range, other.len());
+        assert!(
+            e1 - s1 == e2 - s2,
+            "ranges {s1}..{e1} and {s2}..{e2} have different lengths"
+        );
+        if s1 == e1 {
+            return;
         }
+        let mut x = self.nth_p(s1).unwrap();
+        let mut y = other.nth_p(s2).unwrap();
+        for _ in 1..e1 - s1 {
+            core::mem::swap(&mut self.data[x].payload, &mut other.data[y].payload);
+            x = self.data[x].next.unwrap().to_usize();
+            y = other.data[y].next.unwrap().to_usize();
+        }
+        core::mem::swap(&mut self.data[x].payload, &mut other.data[y].payload);
     }
 
-    /// Swaps two elements in the slice.
+    /// Moves the element at physical `index` to the front of the linked
+    /// list.
     ///
-    /// If `a` equals to `b`, it's guaranteed that elements won't change value.
+    /// The element keeps its physical index.
+    /// This operation should compute in *O*(1) time.
     ///
-    /// # Arguments
+    /// # Panics
     ///
-    /// * a - The index of the first element
-    /// * b - The index of the second element
+    /// Panics if `index >= len`.
+    pub fn move_to_front_p(&mut self, index: usize) {
+        if index >= self.len() {
+            index_out_of_bounds(index, self.len())
+        }
+        if self.head.map(|x| x.to_usize()) != Some(index) {
+            self.remove_node_p(index);
+            self.insert_node_after(I::from_usize(index), None);
+            paranoid_check!(self);
+        }
+    }
+
+    /// Moves the element at physical `index` to the back of the linked
+    /// list.
+    ///
+    /// The element keeps its physical index.
+    /// This operation should compute in *O*(1) time.
     ///
     /// # Panics
     ///
-    /// Panics if `a` or `b` are out of bounds.
-    pub fn swap_p(&mut self, a: usize, b: usize) {
-        let pa = ptr::addr_of_mut!(self.data[a].payload);
-        let pb = ptr::addr_of_mut!(self.data[b].payload);
-        // SAFETY: `pa` and `pb` have been created from safe mutable references and refer
-        // to elements in the slice and therefore are guaranteed to be valid and aligned.
-        // Note that accessing the elements behind `a` and `b` is checked and will
-        // panic when out of bounds.
-        unsafe {
-            ptr::swap(pa, pb);
+    /// Panics if `index >= len`.
+    pub fn move_to_back_p(&mut self, index: usize) {
+        if index >= self.len() {
+            index_out_of_bounds(index, self.len())
+        }
+        if self.tail.map(|x| x.to_usize()) != Some(index) {
+            self.remove_node_p(index);
+            self.insert_node_before(I::from_usize(index), None);
+            paranoid_check!(self);
         }
     }
 
@@ -258,15 +2079,20 @@ impl<T, I: StoreIndex + Copy> LinkedVec<T, I> {
     ///
     /// # Errors
     ///
-    /// If the capacity overflows, or the allocator reports a failure, then an error
-    /// is returned.
-    pub fn try_reserve(&mut self, additional: usize) -> Result<(), collections::TryReserveError> {
-        if I::MAX_USIZE.saturating_add(1) - self.len() < additional {
-            // A hacky way to instantiate TryReserveErrorKind::CapacityOverflow
-            self.data.try_reserve(usize::MAX)
-        } else {
-            self.data.try_reserve(additional)
+    /// Returns [`Error::CapacityOverflow`] if `I` cannot index that many
+    /// elements or the allocation would be too large, and
+    /// [`Error::AllocError`] if the allocator reports a failure.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), Error> {
+        let too_many = I::MAX_USIZE.saturating_add(1) - self.len() < additional;
+        let too_large = (self.len().saturating_add(additional))
+            .checked_mul(core::mem::size_of::<VecNode<T, I>>())
+            .is_none_or(|bytes| bytes > isize::MAX as usize);
+        if too_many || too_large {
+            return Err(Error::CapacityOverflow);
         }
+        self.data
+            .try_reserve(additional)
+            .map_err(|_| Error::AllocError)
     }
 
     fn push_p(&mut self, value: T) -> I {
@@ -280,6 +2106,192 @@ impl<T, I: StoreIndex + Copy> LinkedVec<T, I> {
         unsafe { I::from_usize_unchecked(start_len) }
     }
 
+    /// Pushes `value` to the physical array and links it before the node
+    /// at physical index `target`, or at the tail if `None`.
+    fn insert_before_p(&mut self, target: Option<usize>, value: T) {
+        let target = target.map(I::from_usize);
+        let inserted = self.push_p(value);
+        self.insert_node_before(inserted, target);
+        paranoid_check!(self);
+    }
+
+    /// Links the unlinked nodes from physical index `start` to the end
+    /// of the array before the head, in physical order.
+    fn link_prefix(&mut self, start: usize) {
+        if start == self.len() {
+            return;
+        }
+        self.chain_from(start);
+        self.pair(Some(I::from_usize(self.len() - 1)), self.head);
+        self.head = Some(I::from_usize(start));
+    }
+
+    /// Links the unlinked nodes from physical index `start` to the end
+    /// of the array after the tail, in physical order.
+    fn link_suffix(&mut self, start: usize) {
+        if start == self.len() {
+            return;
+        }
+        self.chain_from(start);
+        self.pair(self.tail, Some(I::from_usize(start)));
+        self.tail = Some(I::from_usize(self.len() - 1));
+    }
+
+    /// Links the nodes from physical index `start` to the end of the
+    /// array to each other, in physical order.
+    ///
+    /// Drops those nodes and panics if they cannot all be indexed by `I`.
+    fn chain_from(&mut self, start: usize) {
+        let len = self.len();
+        if len - 1 > I::MAX_USIZE {
+            self.data.truncate(start);
+            capacity_overflow()
+        }
+
+        for p in start..len {
+            let node = &mut self.data[p];
+            node.prev = if p == start {
+                None
+            } else {
+                Some(I::from_usize(p - 1))
+            };
+            node.next = if p + 1 == len {
+                None
+            } else {
+                Some(I::from_usize(p + 1))
+            };
+        }
+    }
+
+    /// Replaces every link, including `head` and `tail`, with `f` of it.
+    ///
+    /// Used after nodes have been shifted on the physical array.
+    fn remap_links<F: Fn(usize) -> usize>(&mut self, f: F) {
+        let remap = |x: Option<I>| x.map(|x| I::from_usize(f(x.to_usize())));
+        for node in self.data.iter_mut() {
+            node.next = remap(node.next);
+            node.prev = remap(node.prev);
+        }
+        self.head = remap(self.head);
+        self.tail = remap(self.tail);
+    }
+
+    /// Gets the physical index of the element at logical index `index_l`,
+    /// walking from whichever end is closer.
+    fn nth_p(&self, index_l: usize) -> Option<usize> {
+        self.nth_p_from(None, index_l)
+    }
+
+    /// Gets the physical index of the element at logical index `index_l`,
+    /// walking from whichever of the ends and `hint` is closest.
+    ///
+    /// `hint` is a known `(index_l, index_p)` pair.
+    fn nth_p_from(&self, hint: Option<(usize, usize)>, index_l: usize) -> Option<usize> {
+        let len = self.len();
+        if index_l >= len {
+            return None;
+        }
+        let mut start = if index_l <= len - 1 - index_l {
+            (0, self.head?.to_usize())
+        } else {
+            (len - 1, self.tail?.to_usize())
+        };
+        if let Some(hint) = hint {
+            if hint.0.abs_diff(index_l) < start.0.abs_diff(index_l) {
+                start = hint;
+            }
+        }
+
+        let (mut current_l, mut current_p) = start;
+        while current_l < index_l {
+            current_p = self.data[current_p].next?.to_usize();
+            current_l += 1;
+        }
+        while current_l > index_l {
+            current_p = self.data[current_p].prev?.to_usize();
+            current_l -= 1;
+        }
+        Some(current_p)
+    }
+
+    /// Gets the logical and physical index of the first element matching `pred`.
+    fn find_l_p<P: FnMut(&T) -> bool>(&self, mut pred: P) -> Option<(usize, usize)> {
+        IterP::new(self)
+            .enumerate()
+            .find(|&(_, p)| pred(self.index_p(p)))
+    }
+
+    /// Moves the first or last `n` elements into `out`, in logical order
+    /// and reusing its allocation. `out` is cleared first.
+    fn pop_end_into(&mut self, n: usize, back: bool, out: &mut Self) {
+        out.clear();
+        let n = n.min(self.len());
+        if n == self.len() {
+            // Both lists keep their own allocation
+            out.data.reserve_exact(n);
+            let head = self.head.take();
+            self.tail = None;
+            // Safety: The links from the head visit every node exactly once
+            unsafe {
+                raw::drain_linked(&mut self.data, head, |payload| {
+                    out.data.push(VecNode::new(payload))
+                })
+            };
+            out.link_suffix(0);
+            self.bump_version();
+            out.bump_version();
+            paranoid_check!(out);
+            return;
+        }
+        if n == 0 {
+            return;
+        }
+
+        // The physical indices of the run, from the end inward
+        let mut run = alloc::vec::Vec::with_capacity(n);
+        let mut current = if back { self.tail } else { self.head };
+        for _ in 0..n {
+            let index = current.unwrap().to_usize();
+            run.push(index);
+            current = if back {
+                self.data[index].prev
+            } else {
+                self.data[index].next
+            };
+        }
+        if back {
+            self.tail = current;
+            self.set_next(current, None);
+        } else {
+            self.head = current;
+            self.set_prev(current, None);
+        }
+
+        // Fill the slots from the highest down, so that the node moved into
+        // a slot is never part of the run.
+        let mut order: alloc::vec::Vec<usize> = (0..n).collect();
+        order.sort_unstable_by_key(|&k| core::cmp::Reverse(run[k]));
+        let mut payloads: alloc::vec::Vec<Option<T>> = (0..n).map(|_| None).collect();
+        for k in order {
+            let index = run[k];
+            let last = self.len() - 1;
+            payloads[k] = Some(self.data.swap_remove(index).payload);
+            if index != last {
+                self.move_node_p(index);
+            }
+        }
+
+        let payloads = payloads.into_iter().map(|x| VecNode::new(x.unwrap()));
+        if back {
+            out.data.extend(payloads.rev());
+        } else {
+            out.data.extend(payloads);
+        }
+        out.link_suffix(0);
+        paranoid_check!(self);
+        paranoid_check!(out);
+    }
+
     fn in_swap_remove(&mut self, index: usize) -> T {
         self.remove_node_p(index);
         let payload;
@@ -289,125 +2301,678 @@ impl<T, I: StoreIndex + Copy> LinkedVec<T, I> {
         } else {
             payload = self.data.remove(index).payload;
         }
+        paranoid_check!(self);
         payload
     }
 
     /// Ensure the node in the new spots referants are pointing back.
     fn move_node_p(&mut self, index: usize) {
-        let stored = Some(I::from_usize(index));
-        self.set_next(self.data[index].prev, stored);
-        self.set_prev(self.data[index].next, stored);
+        self.links_mut().relink_moved(index);
     }
 
     fn insert_node_before(&mut self, inserted: I, target: Option<I>) {
-        let other = self.get_prev(target);
-        self.pair(other, Some(inserted));
-        self.pair(Some(inserted), target);
+        self.links_mut().insert_before(inserted, target);
     }
 
     fn insert_node_after(&mut self, inserted: I, target: Option<I>) {
-        let other = self.get_next(target);
-        self.pair(target, Some(inserted));
-        self.pair(Some(inserted), other);
+        self.links_mut().insert_after(inserted, target);
     }
 
     fn remove_node_p(&mut self, target: usize) {
-        self.pair(self.data[target].prev, self.data[target].next);
+        self.links_mut().unlink(target);
     }
 
     /// Gets `next` of the indexed node or `head` if `None`.
     fn get_next(&self, target: Option<I>) -> Option<I> {
-        match target {
-            Some(i) => self.data[i.to_usize()].next,
-            None => self.head,
-        }
+        self.links().get_next(target)
     }
 
     /// Gets `prev` of the indexed node or `tail` if `None`.
     fn get_prev(&self, target: Option<I>) -> Option<I> {
-        match target {
-            Some(i) => self.data[i.to_usize()].prev,
-            None => self.tail,
+        self.links().get_prev(target)
+    }
+
+    fn links(&self) -> Links<'_, T, I> {
+        Links {
+            nodes: &self.data,
+            head: self.head,
+            tail: self.tail,
+        }
+    }
+
+    /// Lends out the links for a structural change.
+    fn links_mut(&mut self) -> LinksMut<'_, T, I> {
+        self.bump_version();
+        LinksMut {
+            nodes: &mut self.data,
+            head: &mut self.head,
+            tail: &mut self.tail,
+        }
+    }
+
+    /// Frees a slot in a full list according to the overflow policy, and
+    /// returns the evicted element.
+    fn make_room(&mut self) -> T {
+        match self.overflow_policy {
+            OverflowPolicy::Panic | OverflowPolicy::Error => capacity_overflow(),
+            OverflowPolicy::Evict(End::Front) => self.pop_front().unwrap(),
+            OverflowPolicy::Evict(End::Back) => self.pop_back().unwrap(),
         }
     }
 
+    /// Marks a structural change, so that saved cursor tokens go stale.
+    fn bump_version(&mut self) {
+        self.version = self.version.wrapping_add(1);
+    }
+
     /// Sets `next` of the indexed node or `head` if `None`.
     fn set_next(&mut self, target: Option<I>, value: Option<I>) {
-        if let Some(i) = target {
-            self.data[i.to_usize()].next = value
-        } else {
-            self.head = value
-        }
+        self.links_mut().set_next(target, value);
     }
 
     /// Sets `prev` of the indexed node or `tail` if `None`.
     fn set_prev(&mut self, target: Option<I>, value: Option<I>) {
-        if let Some(i) = target {
-            self.data[i.to_usize()].prev = value
+        self.links_mut().set_prev(target, value);
+    }
+
+    fn pair(&mut self, first: Option<I>, second: Option<I>) {
+        self.links_mut().pair(first, second);
+    }
+}
+
+impl<T, I: StoreIndex + Copy, A: Allocator + Clone> LinkedVec<T, I, A> {
+    /// Creates a list of the elements of this one, in logical order,
+    /// repeated `n` times.
+    ///
+    /// The clones are placed in logical order on the physical array and
+    /// linked in one pass.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new list would overflow `I` or `usize`.
+    #[must_use]
+    pub fn repeat(&self, n: usize) -> Self
+    where
+        T: Clone,
+    {
+        let total = self
+            .len()
+            .checked_mul(n)
+            .unwrap_or_else(|| capacity_overflow());
+        if total > 0 && total - 1 > I::MAX_USIZE {
+            capacity_overflow()
+        }
+        let mut repeated = Self::new_in(self.allocator().clone());
+        repeated.data.reserve_exact(total);
+        let guard = TruncateOnDrop(&mut repeated, 0);
+        for _ in 0..n {
+            guard
+                .0
+                .data
+                .extend(self.iter().map(|x| VecNode::new(x.clone())));
+        }
+        core::mem::forget(guard);
+        repeated.link_suffix(0);
+        paranoid_check!(repeated);
+        repeated
+    }
+
+    /// Splits the list into two at the given logical index.
+    ///
+    /// The first list contains the elements `[0, at)` and the second
+    /// contains `[at, len)`. Each list keeps the relative physical order
+    /// of its elements.
+    ///
+    /// This operation should compute in *O*(n) time. The physical array
+    /// is partitioned in a single pass, without relinking node by node.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    #[must_use]
+    pub fn split_at_l(mut self, at: usize) -> (Self, Self) {
+        let len = self.len();
+        if at > len {
+            index_out_of_bounds(at, len)
+        }
+        if at == 0 {
+            return (Self::new_in(self.allocator().clone()), self);
+        }
+        if at == len {
+            let alloc = self.allocator().clone();
+            return (self, Self::new_in(alloc));
+        }
+
+        // Mark the second part, walking from whichever end is closer
+        let mut in_second = alloc::vec![at < len - at; len];
+        if at < len - at {
+            IterP::new(&self)
+                .take(at)
+                .for_each(|p| in_second[p] = false);
         } else {
-            self.tail = value
+            IterP::new(&self)
+                .rev()
+                .take(len - at)
+                .for_each(|p| in_second[p] = true);
+        }
+        let first_tail = self.nth_p(at - 1).unwrap();
+        let second_head = self.data[first_tail].next.unwrap().to_usize();
+
+        // Map each physical index to its index in its new list
+        let mut counts = [0, 0];
+        let new_index: alloc::vec::Vec<usize> = in_second
+            .iter()
+            .map(|&second| {
+                counts[second as usize] += 1;
+                counts[second as usize] - 1
+            })
+            .collect();
+        let remap = |x: Option<I>| x.map(|x| I::from_usize(new_index[x.to_usize()]));
+
+        let alloc = self.allocator().clone();
+        let mut first = Self::new_in(alloc.clone());
+        let mut second = Self::new_in(alloc.clone());
+        first.data.reserve_exact(counts[0]);
+        second.data.reserve_exact(counts[1]);
+        first.head = remap(self.head);
+        first.tail = remap(Some(I::from_usize(first_tail)));
+        second.head = remap(Some(I::from_usize(second_head)));
+        second.tail = remap(self.tail);
+
+        let data = core::mem::replace(&mut self.data, Vec::new_in(alloc));
+        for (p, mut node) in data.into_iter().enumerate() {
+            node.next = if p == first_tail {
+                None
+            } else {
+                remap(node.next)
+            };
+            node.prev = if p == second_head {
+                None
+            } else {
+                remap(node.prev)
+            };
+            if in_second[p] {
+                second.data.push(node);
+            } else {
+                first.data.push(node);
+            }
         }
+        paranoid_check!(first);
+        paranoid_check!(second);
+        (first, second)
     }
 
-    fn pair(&mut self, first: Option<I>, second: Option<I>) {
-        self.set_next(first, second);
-        self.set_prev(second, first);
+    /// Splits the list into two at the given logical index. Returns
+    /// everything after the given index, including the index.
+    ///
+    /// This operation should compute in *O*(n) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    #[cfg_attr(
+        feature = "no-panic",
+        deprecated(note = "can panic, use `try_split_off`")
+    )]
+    pub fn split_off(&mut self, at: usize) -> Self {
+        let version = self.version;
+        let policy = self.overflow_policy;
+        let max_len = self.max_len;
+        let (first, mut second) =
+            core::mem::replace(self, Self::new_in(self.allocator().clone())).split_at_l(at);
+        *self = first;
+        self.version = version.wrapping_add(1);
+        self.overflow_policy = policy;
+        self.max_len = max_len;
+        second.overflow_policy = OverflowPolicy::Error;
+        second
+    }
+
+    /// Splits the list into two at the given logical index. See
+    /// [`split_off`](Self::split_off).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::IndexOutOfBounds`] if `at > len`.
+    pub fn try_split_off(&mut self, at: usize) -> Result<Self, Error> {
+        if at > self.len() {
+            return Err(Error::IndexOutOfBounds {
+                index: at,
+                len: self.len(),
+            });
+        }
+        Ok(self.split_off(at))
+    }
+
+    /// Removes the first `n` elements and returns them as a new list, or
+    /// all elements if there are fewer.
+    ///
+    /// The run is detached with a single relink, and the freed slots are
+    /// filled from the end of the physical array. The returned list has its
+    /// elements in logical order on its physical array.
+    ///
+    /// This operation should compute in *O*(n log n) time, in the number
+    /// of elements removed.
+    #[must_use = "use `truncate` to drop the elements"]
+    pub fn pop_front_n(&mut self, n: usize) -> Self {
+        self.pop_end_n(n, false)
+    }
+
+    /// Removes the last `n` elements and returns them as a new list, or
+    /// all elements if there are fewer.
+    ///
+    /// See [`pop_front_n`](Self::pop_front_n).
+    #[must_use = "use `truncate` to drop the elements"]
+    pub fn pop_back_n(&mut self, n: usize) -> Self {
+        self.pop_end_n(n, true)
+    }
+
+    fn pop_end_n(&mut self, n: usize, back: bool) -> Self {
+        let mut popped = Self::new_in(self.allocator().clone());
+        self.pop_end_into(n, back, &mut popped);
+        popped
+    }
+
+    /// Replaces the elements in the given logical range with the items of
+    /// `replace_with`, and returns the removed elements in logical order.
+    ///
+    /// Unlike `Vec::splice`, the replacement is inserted right away; the
+    /// returned iterator only yields the removed elements. The new
+    /// elements are placed last in the physical array.
+    ///
+    /// This operation should compute in *O*(n) time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than the end, or if
+    /// the end is greater than `len`.
+    pub fn splice_range_l<R, It>(&mut self, range: R, replace_with: It) -> IntoIter<T, I, A>
+    where
+        R: RangeBounds<usize>,
+        It: IntoIterator<Item = T>,
+    {
+        let (start, end) = range_l(range, self.len());
+        let mut removed = Self::new_in(self.allocator().clone());
+        _ = removed.data.try_reserve(end - start);
+
+        // The physical index of the element before the seam
+        let mut before = start.checked_sub(1).and_then(|l| self.nth_p(l));
+        let mut current = self.get_next(before.map(I::from_usize));
+        for _ in start..end {
+            let p = current.unwrap().to_usize();
+            let mut next = self.data[p].next.map(|x| x.to_usize());
+            let last = self.len() - 1;
+            removed.push_back(self.in_swap_remove(p));
+            // The last node may have been moved into the freed slot
+            if next == Some(last) {
+                next = Some(p);
+            }
+            if before == Some(last) {
+                before = Some(p);
+            }
+            current = next.map(I::from_usize);
+        }
+
+        let mut before = before.map(I::from_usize);
+        for value in replace_with {
+            let inserted = self.push_p(value);
+            self.insert_node_after(inserted, before);
+            before = Some(inserted);
+        }
+        paranoid_check!(self);
+        removed.into_iter()
+    }
+
+    /// Transforms every element with `f`, keeping the links as they are.
+    ///
+    /// Each element keeps both its logical and its physical index. `f` is
+    /// called in physical order.
+    ///
+    /// This operation should compute in *O*(n) time, without relinking.
+    pub fn map<U, F: FnMut(T) -> U>(mut self, mut f: F) -> LinkedVec<U, I, A> {
+        // Unlink first, so that self is empty if f panics
+        let head = self.head.take();
+        let tail = self.tail.take();
+        let alloc = self.allocator().clone();
+        let data = core::mem::replace(&mut self.data, Vec::new_in(alloc.clone()));
+
+        let mut mapped = LinkedVec::new_in(alloc);
+        mapped.data.reserve_exact(data.len());
+        for node in data {
+            mapped.data.push(VecNode {
+                payload: f(node.payload),
+                next: node.next,
+                prev: node.prev,
+            });
+        }
+        mapped.head = head;
+        mapped.tail = tail;
+        paranoid_check!(mapped);
+        mapped
+    }
+
+    /// Transforms every element with `f`, dropping those for which it
+    /// returns `None`.
+    ///
+    /// `f` is called in logical order, and the new list is laid out in
+    /// logical order.
+    pub fn filter_map<U, F: FnMut(T) -> Option<U>>(self, f: F) -> LinkedVec<U, I, A> {
+        let mut filtered = LinkedVec::new_in(self.allocator().clone());
+        filtered.extend(self.into_iter().filter_map(f));
+        filtered
+    }
+}
+
+impl<X, Y, I: StoreIndex + Copy, A: Allocator + Clone> LinkedVec<(X, Y), I, A> {
+    /// Pairs up the elements of two lists in logical order.
+    ///
+    /// The new list is laid out in logical order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lists have different lengths.
+    pub fn zip_into(a: LinkedVec<X, I, A>, b: LinkedVec<Y, I, A>) -> Self {
+        assert_eq!(a.len(), b.len(), "zipped lists should have the same length");
+        let mut zipped = Self::new_in(a.allocator().clone());
+        zipped.extend(a.into_iter().zip(b));
+        zipped
+    }
+
+    /// Splits a list of pairs into two lists.
+    ///
+    /// Both lists keep the links of this one, so each element keeps its
+    /// logical and physical index.
+    pub fn unzip(mut self) -> (LinkedVec<X, I, A>, LinkedVec<Y, I, A>) {
+        let head = self.head.take();
+        let tail = self.tail.take();
+        let alloc = self.allocator().clone();
+        let data = core::mem::replace(&mut self.data, Vec::new_in(alloc.clone()));
+
+        let mut first = LinkedVec::new_in(alloc.clone());
+        let mut second = LinkedVec::new_in(alloc);
+        first.data.reserve_exact(data.len());
+        second.data.reserve_exact(data.len());
+        for node in data {
+            let (x, y) = node.payload;
+            first.data.push(VecNode {
+                payload: x,
+                next: node.next,
+                prev: node.prev,
+            });
+            second.data.push(VecNode {
+                payload: y,
+                next: node.next,
+                prev: node.prev,
+            });
+        }
+        first.head = head;
+        first.tail = tail;
+        second.head = head;
+        second.tail = tail;
+        paranoid_check!(first);
+        paranoid_check!(second);
+        (first, second)
+    }
+}
+
+impl<T, I: StoreIndex + Copy> LinkedVec<T, I> {
+    /// Creates a list from the items of `iter`, in order.
+    ///
+    /// # Errors
+    ///
+    /// If `I` cannot index all the items, returns the list of the items
+    /// that fit together with the first item that did not. The rest of
+    /// the iterator is not consumed.
+    pub fn try_from_iter<It: IntoIterator<Item = T>>(
+        iter: It,
+    ) -> Result<Self, TryError<(Self, T)>> {
+        let mut list = Self::new();
+        for value in iter {
+            if list.len() > I::MAX_USIZE {
+                return Err(TryError::new(Error::CapacityOverflow, (list, value)));
+            }
+            list.push_back(value);
+        }
+        Ok(list)
+    }
+
+    /// Concatenates the lists in order.
+    ///
+    /// The first list is reused, and the nodes of each of the others are
+    /// moved onto it in bulk, see [`append`](Self::append).
+    pub fn concat<It: IntoIterator<Item = Self>>(lists: It) -> Self {
+        let mut lists = lists.into_iter();
+        let mut list = lists.next().unwrap_or_default();
+        list.extend(lists);
+        list
+    }
+}
+
+impl<T, I: StoreIndex + Copy> TryFrom<alloc::vec::Vec<T>> for LinkedVec<T, I> {
+    type Error = TryError<alloc::vec::Vec<T>>;
+
+    /// Creates a list with the elements of `vec`, in order. The physical
+    /// order matches the logical order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `vec` back if `I` cannot index all its elements.
+    fn try_from(vec: alloc::vec::Vec<T>) -> Result<Self, Self::Error> {
+        if vec.len() > I::MAX_USIZE.saturating_add(1) {
+            return Err(TryError::new(Error::CapacityOverflow, vec));
+        }
+        let mut list = Self::new();
+        list.data.reserve_exact(vec.len());
+        list.data.extend(vec.into_iter().map(VecNode::new));
+        list.link_suffix(0);
+        paranoid_check!(list);
+        Ok(list)
+    }
+}
+
+impl<T, I: StoreIndex + Copy, A: Allocator> Drop for LinkedVec<T, I, A> {
+    /// Drops the elements in logical order, front to back.
+    fn drop(&mut self) {
+        self.clear()
     }
 }
 
-impl<T, I: StoreIndex> Default for LinkedVec<T, I>
-where
-    I: Copy + TryFrom<usize, Error: Debug> + Into<usize>,
-{
+impl<T, I: StoreIndex + Copy, A: Allocator + Default> Default for LinkedVec<T, I, A> {
     fn default() -> Self {
-        Self::new()
+        Self::new_in(A::default())
     }
 }
 
-impl<T: Clone, I: StoreIndex + Copy> Clone for LinkedVec<T, I> {
+impl<T: Clone, I: StoreIndex + Copy, A: Allocator + Clone> Clone for LinkedVec<T, I, A> {
     fn clone(&self) -> Self {
-        let mut ret = Self::new();
+        let mut ret = Self::new_in(self.allocator().clone());
         ret.clone_from(self);
         ret
     }
 
     fn clone_from(&mut self, source: &Self) {
-        self.head = source.head;
-        self.tail = source.tail;
+        self.clear();
+        self.overflow_policy = source.overflow_policy;
+        self.max_len = source.max_len;
 
-        self.data.clear();
-        self.data.extend(source.data.iter().map(|x| x.not_clone()));
+        // The nodes are cloned with their links, so they are only valid
+        // once all of them are there. If a clone panics, the guard
+        // leaves the list empty instead.
+        let guard = ClearOnDrop(self);
+        guard
+            .0
+            .data
+            .extend(source.data.iter().map(|x| x.not_clone()));
+        guard.0.head = source.head;
+        guard.0.tail = source.tail;
+        guard.0.bump_version();
+        paranoid_check!(guard.0);
+        core::mem::forget(guard);
     }
 }
 
-impl<T: PartialOrd, I: StoreIndex + Copy> PartialEq for LinkedVec<T, I> {
+impl<T: PartialEq, I: StoreIndex + Copy, A: Allocator> PartialEq for LinkedVec<T, I, A> {
     fn eq(&self, other: &Self) -> bool {
         self.iter().eq(other.iter())
     }
 }
 
-impl<T: PartialOrd, I: StoreIndex + Copy> PartialOrd for LinkedVec<T, I> {
+impl<T: Eq, I: StoreIndex + Copy, A: Allocator> Eq for LinkedVec<T, I, A> {}
+
+impl<T: PartialOrd, I: StoreIndex + Copy, A: Allocator> PartialOrd for LinkedVec<T, I, A> {
     fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         self.iter().partial_cmp(other.iter())
     }
 }
 
-impl<T: Debug, I: StoreIndex + Copy> Debug for LinkedVec<T, I> {
+impl<T: Debug, I: StoreIndex + Copy, A: Allocator> Debug for LinkedVec<T, I, A> {
+    /// Formats the elements in logical order, like `[a, b, c]`.
+    ///
+    /// Use [`debug_layout`](LinkedVec::debug_layout) to see the physical
+    /// index of each element.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Formats a list as a map from physical index to element, in logical
+/// order.
+///
+/// Returned by [`LinkedVec::debug_layout`].
+pub struct DebugLayout<'a, T, I: StoreIndex + Copy, A: Allocator>(&'a LinkedVec<T, I, A>);
+
+impl<T: Debug, I: StoreIndex + Copy, A: Allocator> Debug for DebugLayout<'_, T, I, A> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        // FIXME: Should the format be changed?
         f.debug_map()
-            .entries(IterP::new(self).map(|i| (i, self.get_p(i))))
+            .entries(IterP::new(self.0).map(|i| (i, self.0.index_p(i))))
+            .finish()
+    }
+}
+
+/// Displays the elements of a list in logical order.
+///
+/// Returned by [`LinkedVec::display`].
+pub struct DisplayList<'a, T, I: StoreIndex + Copy, A: Allocator> {
+    list: &'a LinkedVec<T, I, A>,
+    separator: &'a str,
+}
+
+impl<T: Display, I: StoreIndex + Copy, A: Allocator> Display for DisplayList<'_, T, I, A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, x) in self.list.iter().enumerate() {
+            if i != 0 {
+                f.write_str(self.separator)?;
+            }
+            x.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Rolls back the pushes made to a list when dropped, unless committed.
+///
+/// Returned by [`LinkedVec::push_guard`].
+pub struct PushGuard<'a, T, I: StoreIndex + Copy, A: Allocator> {
+    list: &'a mut LinkedVec<T, I, A>,
+    len: usize,
+}
+
+impl<T, I: StoreIndex + Copy, A: Allocator> PushGuard<'_, T, I, A> {
+    /// Keeps the pushes made through the guard.
+    pub fn commit(self) {
+        core::mem::forget(self)
+    }
+
+    /// Removes the nodes added through the guard so far, and keeps the
+    /// guard for another attempt.
+    pub fn rollback(&mut self) {
+        self.list.truncate_p(self.len)
+    }
+}
+
+impl<T, I: StoreIndex + Copy, A: Allocator> Deref for PushGuard<'_, T, I, A> {
+    type Target = LinkedVec<T, I, A>;
+
+    fn deref(&self) -> &Self::Target {
+        self.list
+    }
+}
+
+impl<T, I: StoreIndex + Copy, A: Allocator> DerefMut for PushGuard<'_, T, I, A> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.list
+    }
+}
+
+impl<T, I: StoreIndex + Copy, A: Allocator> Drop for PushGuard<'_, T, I, A> {
+    fn drop(&mut self) {
+        self.rollback()
+    }
+}
+
+impl<T: Debug, I: StoreIndex + Copy, A: Allocator> Debug for PushGuard<'_, T, I, A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PushGuard")
+            .field("list", &self.list)
+            .field("len", &self.len)
             .finish()
     }
 }
 
+/// Empties the list when dropped.
+///
+/// Guards operations that build many nodes before linking them up.
+/// Forget it once the list is consistent again.
+struct ClearOnDrop<'a, T, I: StoreIndex + Copy, A: Allocator>(&'a mut LinkedVec<T, I, A>);
+
+impl<T, I: StoreIndex + Copy, A: Allocator> Drop for ClearOnDrop<'_, T, I, A> {
+    fn drop(&mut self) {
+        self.0.clear_unordered()
+    }
+}
+
+/// Resolves a range of logical indices into `(start, end)`.
+///
+/// Panics if the range is decreasing or goes past `len`.
+fn range_l<R: RangeBounds<usize>>(range: R, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&x) => x,
+        Bound::Excluded(&x) => x.checked_add(1).unwrap_or_else(|| capacity_overflow()),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&x) => x.checked_add(1).unwrap_or_else(|| capacity_overflow()),
+        Bound::Excluded(&x) => x,
+        Bound::Unbounded => len,
+    };
+    if end > len {
+        index_out_of_bounds(end, len)
+    }
+    if start > end {
+        index_out_of_bounds(start, end)
+    }
+    (start, end)
+}
+
+/// Drops the nodes from the given physical index on when dropped.
+///
+/// Guards operations that push many nodes before linking them up.
+/// Forget it once the nodes are linked.
+struct TruncateOnDrop<'a, T, I: StoreIndex + Copy, A: Allocator>(&'a mut LinkedVec<T, I, A>, usize);
+
+impl<T, I: StoreIndex + Copy, A: Allocator> Drop for TruncateOnDrop<'_, T, I, A> {
+    fn drop(&mut self) {
+        self.0.data.truncate(self.1)
+    }
+}
+
 #[inline(never)]
 fn index_out_of_bounds(index: impl Into<usize>, len: usize) -> ! {
     let index: usize = index.into();
-    panic!("index (is {index}) should be < or <= len (is {len})");
+    panic!("{}", Error::IndexOutOfBounds { index, len });
 }
 
 #[cold]
 fn capacity_overflow() -> ! {
-    panic!("capacity overflow");
+    panic!("{}", Error::CapacityOverflow);
 }
@@ -0,0 +1,149 @@
+//! The link logic shared by every container that keeps its nodes in one
+//! physical array.
+//!
+//! [`LinkedVec`](crate::LinkedVec),
+//! [`ArrayLinkedVec`](crate::array::ArrayLinkedVec) and
+//! [`LinkedArena`](crate::arena::LinkedArena) store their nodes
+//! differently, but each list comes down to a slice of nodes plus a head
+//! and a tail. The containers lend those out as a [`Links`] or
+//! [`LinksMut`] view and keep only their own bookkeeping.
+
+use crate::inner_types::{StoreIndex, VecNode};
+
+/// A read-only view of a list as its nodes and its ends.
+pub(crate) struct Links<'a, T, I> {
+    pub(crate) nodes: &'a [VecNode<T, I>],
+    pub(crate) head: Option<I>,
+    pub(crate) tail: Option<I>,
+}
+
+impl<T, I: StoreIndex + Copy> Links<'_, T, I> {
+    /// Gets `next` of the indexed node or `head` if `None`.
+    pub(crate) fn get_next(&self, target: Option<I>) -> Option<I> {
+        match target {
+            Some(i) => self.nodes[i.to_usize()].next,
+            None => self.head,
+        }
+    }
+
+    /// Gets `prev` of the indexed node or `tail` if `None`.
+    pub(crate) fn get_prev(&self, target: Option<I>) -> Option<I> {
+        match target {
+            Some(i) => self.nodes[i.to_usize()].prev,
+            None => self.tail,
+        }
+    }
+
+    /// Moves a cursor position to the next element, or to the head from
+    /// the "ghost" non-element.
+    ///
+    /// Every node must belong to the list.
+    pub(crate) fn step_next(&self, index_la: &mut usize, current_pa: &mut Option<usize>) {
+        match *current_pa {
+            // We had no current element; the cursor was sitting at the start position
+            // Next element should be the head of the list
+            None => {
+                *current_pa = self.head.map(|x| x.to_usize());
+                *index_la = 0;
+            }
+            // We had a previous element, so let's go to its next
+            Some(current) => {
+                *current_pa = self.nodes[current].next.map(|x| x.to_usize());
+                *index_la += 1;
+            }
+        }
+    }
+
+    /// Moves a cursor position to the previous element, or to the tail
+    /// from the "ghost" non-element.
+    ///
+    /// Every node must belong to the list.
+    pub(crate) fn step_prev(&self, index_la: &mut usize, current_pa: &mut Option<usize>) {
+        match *current_pa {
+            // We had no current element; the cursor was sitting at the start position
+            // Next element should be the tail of the list
+            None => {
+                *current_pa = self.tail.map(|x| x.to_usize());
+                *index_la = self.nodes.len().saturating_sub(1);
+            }
+            // We had a previous element, so let's go to its prev
+            Some(current) => {
+                *current_pa = self.nodes[current].prev.map(|x| x.to_usize());
+                *index_la = index_la.checked_sub(1).unwrap_or(self.nodes.len());
+            }
+        }
+    }
+}
+
+/// A mutable view of a list as its nodes and its ends.
+///
+/// The nodes may be shared with other lists, as in a `LinkedArena`, as
+/// long as every link that is followed stays within this one.
+pub(crate) struct LinksMut<'a, T, I> {
+    pub(crate) nodes: &'a mut [VecNode<T, I>],
+    pub(crate) head: &'a mut Option<I>,
+    pub(crate) tail: &'a mut Option<I>,
+}
+
+impl<T, I: StoreIndex + Copy> LinksMut<'_, T, I> {
+    fn as_links(&self) -> Links<'_, T, I> {
+        Links {
+            nodes: self.nodes,
+            head: *self.head,
+            tail: *self.tail,
+        }
+    }
+
+    /// Sets `next` of the indexed node or `head` if `None`.
+    pub(crate) fn set_next(&mut self, target: Option<I>, value: Option<I>) {
+        match target {
+            Some(i) => self.nodes[i.to_usize()].next = value,
+            None => *self.head = value,
+        }
+    }
+
+    /// Sets `prev` of the indexed node or `tail` if `None`.
+    pub(crate) fn set_prev(&mut self, target: Option<I>, value: Option<I>) {
+        match target {
+            Some(i) => self.nodes[i.to_usize()].prev = value,
+            None => *self.tail = value,
+        }
+    }
+
+    pub(crate) fn pair(&mut self, first: Option<I>, second: Option<I>) {
+        self.set_next(first, second);
+        self.set_prev(second, first);
+    }
+
+    /// Links the unlinked node `inserted` before `target`, or at the tail
+    /// if `None`.
+    pub(crate) fn insert_before(&mut self, inserted: I, target: Option<I>) {
+        let other = self.as_links().get_prev(target);
+        self.pair(other, Some(inserted));
+        self.pair(Some(inserted), target);
+    }
+
+    /// Links the unlinked node `inserted` after `target`, or at the head
+    /// if `None`.
+    pub(crate) fn insert_after(&mut self, inserted: I, target: Option<I>) {
+        let other = self.as_links().get_next(target);
+        self.pair(target, Some(inserted));
+        self.pair(Some(inserted), other);
+    }
+
+    /// Links the neighbours of the node at `target` to each other, leaving
+    /// the node itself out of the list.
+    pub(crate) fn unlink(&mut self, target: usize) {
+        let VecNode { prev, next, .. } = self.nodes[target];
+        self.pair(prev, next);
+    }
+
+    /// Points the neighbours of the node that was just moved to `index`
+    /// back at it.
+    pub(crate) fn relink_moved(&mut self, index: usize) {
+        let stored = Some(I::from_usize(index));
+        let VecNode { prev, next, .. } = self.nodes[index];
+        self.set_next(prev, stored);
+        self.set_prev(next, stored);
+    }
+}
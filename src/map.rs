@@ -0,0 +1,283 @@
+use alloc::collections::BTreeMap;
+use core::{borrow::Borrow, fmt::Debug};
+
+use crate::{inner_types::StoreIndex, iterators, LinkedVec};
+
+/// A map that remembers the order its keys were inserted in.
+///
+/// The entries live in a [`LinkedVec`], and a [`BTreeMap`] maps every key
+/// to the physical index of its entry. Lookups cost *O*(log n) time. Once
+/// an entry is found, moving it to either end of the order costs *O*(1)
+/// time, so the map doubles as an LRU cache. Removal swaps the last entry
+/// into the freed slot, like [`LinkedVec::swap_remove`].
+///
+/// Every key is stored twice, so `K` must be [`Clone`].
+pub struct LinkedVecMap<K, V, I: StoreIndex + Copy = usize> {
+    list: LinkedVec<(K, V), I>,
+    index: BTreeMap<K, usize>,
+}
+
+impl<K, V, I: StoreIndex + Copy> LinkedVecMap<K, V, I> {
+    pub const fn new() -> Self {
+        Self {
+            list: LinkedVec::new(),
+            index: BTreeMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Provides the first entry in insertion order, or `None` if the map
+    /// is empty.
+    #[must_use]
+    pub fn front(&self) -> Option<(&K, &V)> {
+        self.list.front().map(|(k, v)| (k, v))
+    }
+
+    /// Provides the last entry in insertion order, or `None` if the map is
+    /// empty.
+    #[must_use]
+    pub fn back(&self) -> Option<(&K, &V)> {
+        self.list.back().map(|(k, v)| (k, v))
+    }
+
+    /// Provides a forward iterator over the entries in insertion order.
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_, K, V, I> {
+        Iter(self.list.iter())
+    }
+
+    /// Provides a forward iterator over the keys in insertion order.
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = &K> {
+        self.list.iter().map(|(k, _)| k)
+    }
+
+    /// Provides a forward iterator over the values in insertion order.
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = &V> {
+        self.list.iter().map(|(_, v)| v)
+    }
+
+    /// Provides a forward iterator over mutable references to the values
+    /// in insertion order.
+    pub fn values_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut V> {
+        self.list.iter_mut().map(|(_, v)| v)
+    }
+
+    /// Removes all entries from the map.
+    pub fn clear(&mut self) {
+        self.list.clear();
+        self.index.clear();
+    }
+}
+
+impl<K: Ord + Clone, V, I: StoreIndex + Copy> LinkedVecMap<K, V, I> {
+    /// Returns a reference to the value of `key`, if any.
+    #[must_use]
+    pub fn get<Q: Ord + ?Sized>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        let p = *self.index.get(key)?;
+        Some(&self.list.data[p].payload.1)
+    }
+
+    /// Returns a mutable reference to the value of `key`, if any.
+    #[must_use]
+    pub fn get_mut<Q: Ord + ?Sized>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+    {
+        let p = *self.index.get(key)?;
+        Some(&mut self.list.data[p].payload.1)
+    }
+
+    /// Returns a mutable reference to the value of `key`, if any, and
+    /// moves its entry to the back of the order.
+    pub fn get_refresh<Q: Ord + ?Sized>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+    {
+        let p = *self.index.get(key)?;
+        self.list.move_to_back_p(p);
+        Some(&mut self.list.data[p].payload.1)
+    }
+
+    pub fn contains_key<Q: Ord + ?Sized>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.index.contains_key(key)
+    }
+
+    /// Inserts a key-value pair into the map.
+    ///
+    /// If the map already had `key`, its value is replaced and returned,
+    /// and the entry keeps its place in the order. Otherwise the entry is
+    /// inserted at the back.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(&p) = self.index.get(&key) {
+            return Some(core::mem::replace(&mut self.list.data[p].payload.1, value));
+        }
+        let index_key = key.clone();
+        let inserted = self.list.push_p((key, value));
+        self.list.insert_node_after(inserted, self.list.tail);
+        paranoid_check!(self.list);
+        self.index.insert(index_key, inserted.to_usize());
+        None
+    }
+
+    /// Moves the entry of `key` to the front of the order.
+    ///
+    /// Returns `false` if the map does not have `key`.
+    pub fn move_to_front<Q: Ord + ?Sized>(&mut self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        match self.index.get(key) {
+            Some(&p) => {
+                self.list.move_to_front_p(p);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Moves the entry of `key` to the back of the order.
+    ///
+    /// Returns `false` if the map does not have `key`.
+    pub fn move_to_back<Q: Ord + ?Sized>(&mut self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        match self.index.get(key) {
+            Some(&p) => {
+                self.list.move_to_back_p(p);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes `key` from the map, returning its value if it was there.
+    pub fn remove<Q: Ord + ?Sized>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+    {
+        self.remove_entry(key).map(|(_, v)| v)
+    }
+
+    /// Removes `key` from the map, returning the stored key and value if
+    /// it was there.
+    pub fn remove_entry<Q: Ord + ?Sized>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+    {
+        let p = self.index.remove(key)?;
+        Some(self.swap_remove_p(p))
+    }
+
+    /// Removes and returns the first entry in insertion order, if any.
+    pub fn pop_front(&mut self) -> Option<(K, V)> {
+        let p = self.list.head?.to_usize();
+        self.index.remove(&self.list.data[p].payload.0);
+        Some(self.swap_remove_p(p))
+    }
+
+    /// Removes and returns the last entry in insertion order, if any.
+    pub fn pop_back(&mut self) -> Option<(K, V)> {
+        let p = self.list.tail?.to_usize();
+        self.index.remove(&self.list.data[p].payload.0);
+        Some(self.swap_remove_p(p))
+    }
+
+    /// Removes the entry at physical index `p`, whose key is already out
+    /// of the index, and points the index at the entry moved into its slot.
+    fn swap_remove_p(&mut self, p: usize) -> (K, V) {
+        let entry = self.list.in_swap_remove(p);
+        if let Some((moved, _)) = self.list.data.get(p).map(|node| &node.payload) {
+            *self.index.get_mut(moved).unwrap() = p;
+        }
+        entry
+    }
+}
+
+impl<K: Clone, V: Clone, I: StoreIndex + Copy> Clone for LinkedVecMap<K, V, I> {
+    fn clone(&self) -> Self {
+        // The list keeps its physical layout, so the index stays valid.
+        Self {
+            list: self.list.clone(),
+            index: self.index.clone(),
+        }
+    }
+}
+
+impl<K, V, I: StoreIndex + Copy> Default for LinkedVecMap<K, V, I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Debug, V: Debug, I: StoreIndex + Copy> Debug for LinkedVecMap<K, V, I> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Ord + Clone, V, I: StoreIndex + Copy> Extend<(K, V)> for LinkedVecMap<K, V, I> {
+    fn extend<It: IntoIterator<Item = (K, V)>>(&mut self, iter: It) {
+        for (k, v) in iter {
+            self.insert(k, v);
+        }
+    }
+}
+
+impl<K: Ord + Clone, V, I: StoreIndex + Copy> FromIterator<(K, V)> for LinkedVecMap<K, V, I> {
+    fn from_iter<It: IntoIterator<Item = (K, V)>>(iter: It) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<'a, K, V, I: StoreIndex + Copy> IntoIterator for &'a LinkedVecMap<K, V, I> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V, I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Forward iterator over the entries of a [`LinkedVecMap`].
+#[derive(Debug)]
+pub struct Iter<'a, K: 'a, V: 'a, I: Copy + StoreIndex>(iterators::Iter<'a, (K, V), I>);
+
+impl<K, V, I: Copy + StoreIndex> Clone for Iter<'_, K, V, I> {
+    fn clone(&self) -> Self {
+        Self(self.0)
+    }
+}
+
+impl<'a, K: 'a, V: 'a, I: Copy + StoreIndex> Iterator for Iter<'a, K, V, I> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(k, v)| (k, v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, K: 'a, V: 'a, I: Copy + StoreIndex> DoubleEndedIterator for Iter<'a, K, V, I> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(k, v)| (k, v))
+    }
+}
@@ -0,0 +1,73 @@
+//! Mutations of a [`LinkedVec`] as values.
+//!
+//! A sequence of [`Op`]s can be recorded and replayed with
+//! [`LinkedVec::apply_all`], which makes a run reproducible. Replaying the
+//! same ops on a `Vec` or `LinkedList` model is a simple differential test.
+
+use crate::{inner_types::StoreIndex, Allocator, LinkedVec};
+
+/// A single mutation of a [`LinkedVec`].
+///
+/// Indices suffixed with `L` are logical and the others are physical, as
+/// in the method each variant stands for.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Op<T> {
+    /// [`LinkedVec::push_front`]
+    PushFront(T),
+    /// [`LinkedVec::push_back`]
+    PushBack(T),
+    /// [`LinkedVec::pop_front`]
+    PopFront,
+    /// [`LinkedVec::pop_back`]
+    PopBack,
+    /// [`LinkedVec::pop`]
+    Pop,
+    /// [`LinkedVec::swap_remove`]
+    SwapRemove(usize),
+    /// [`LinkedVec::swap_p`]
+    SwapP(usize, usize),
+    /// [`LinkedVec::insert_l`]
+    InsertL(usize, T),
+    /// [`LinkedVec::remove_l`]
+    RemoveL(usize),
+    /// [`LinkedVec::truncate`]
+    Truncate(usize),
+    /// [`LinkedVec::clear`]
+    Clear,
+}
+
+impl<T, I: StoreIndex + Copy, A: Allocator> LinkedVec<T, I, A> {
+    /// Performs `op` and returns the element it removed, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the method `op` stands for panics, such as with an index
+    /// out of bounds.
+    pub fn apply(&mut self, op: Op<T>) -> Option<T> {
+        match op {
+            Op::PushFront(value) => self.push_front(value),
+            Op::PushBack(value) => self.push_back(value),
+            Op::PopFront => return self.pop_front(),
+            Op::PopBack => return self.pop_back(),
+            Op::Pop => return self.pop(),
+            Op::SwapRemove(index) => return Some(self.swap_remove(index)),
+            Op::SwapP(a, b) => self.swap_p(a, b),
+            Op::InsertL(index, value) => self.insert_l(index, value),
+            Op::RemoveL(index) => return Some(self.remove_l(index)),
+            Op::Truncate(len) => self.truncate(len),
+            Op::Clear => self.clear(),
+        }
+        None
+    }
+
+    /// Performs every op of `ops` in order, dropping the removed elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any op panics. The ops before it are kept.
+    pub fn apply_all<It: IntoIterator<Item = Op<T>>>(&mut self, ops: It) {
+        for op in ops {
+            self.apply(op);
+        }
+    }
+}
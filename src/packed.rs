@@ -0,0 +1,140 @@
+//! Index types narrower than the next primitive integer.
+//!
+//! The top byte is stored inverted in a `NonZeroU8`, so that `Option` of
+//! each type is as small as the type. This gives up every value whose top
+//! byte is `0xFF`, which is 2^16 values for `U24` and 2^40 for `U48`, and
+//! leaves a contiguous range from zero.
+
+use core::{cmp::Ordering, fmt, num::NonZeroU8};
+
+use crate::{inner_types::StoreIndex, Error};
+
+macro_rules! packed_index {
+    ($(#[$attr:meta])* $name:ident, $prim:ty, $bytes:literal, $max:literal) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct $name {
+            /// The lower bytes, little endian
+            low: [u8; $bytes - 1],
+            /// The top byte, inverted
+            high: NonZeroU8,
+        }
+
+        impl $name {
+            /// The largest value.
+            pub const MAX: Self = match Self::new($max) {
+                Some(x) => x,
+                None => unreachable!(),
+            };
+
+            /// Creates the index, or returns `None` if `value` is greater
+            /// than [`MAX`](Self::MAX).
+            #[must_use]
+            pub const fn new(value: $prim) -> Option<Self> {
+                if value > $max {
+                    return None;
+                }
+                let bytes = value.to_le_bytes();
+                let mut low = [0; $bytes - 1];
+                let mut i = 0;
+                while i < low.len() {
+                    low[i] = bytes[i];
+                    i += 1;
+                }
+                match NonZeroU8::new(!bytes[$bytes - 1]) {
+                    Some(high) => Some(Self { low, high }),
+                    None => None,
+                }
+            }
+
+            /// Returns the value of the index.
+            #[must_use]
+            pub const fn get(self) -> $prim {
+                let mut bytes = [0; core::mem::size_of::<$prim>()];
+                let mut i = 0;
+                while i < self.low.len() {
+                    bytes[i] = self.low[i];
+                    i += 1;
+                }
+                bytes[$bytes - 1] = !self.high.get();
+                <$prim>::from_le_bytes(bytes)
+            }
+        }
+
+        impl PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.get().cmp(&other.get())
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.get().fmt(f)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.get().fmt(f)
+            }
+        }
+
+        impl StoreIndex for $name {
+            type Error = Error;
+
+            const MAX_USIZE: usize = min_max!($max, usize::MAX);
+
+            fn to_usize(&self) -> usize {
+                usize::try_from(self.get()).unwrap()
+            }
+
+            unsafe fn to_usize_unchecked(&self) -> usize {
+                // Safety: Caller ensures self came from try_from_usize
+                // or from_usize_unchecked
+                unsafe { debug_unwrap!(usize::try_from(self.get())) }
+            }
+
+            fn try_from_usize(value: usize) -> Result<Self, Self::Error> {
+                <$prim>::try_from(value)
+                    .ok()
+                    .and_then(Self::new)
+                    .ok_or(Error::CapacityOverflow)
+            }
+
+            unsafe fn from_usize_unchecked(value: usize) -> Self {
+                // Safety: Caller ensures value <= MAX_USIZE, which is
+                // in the range of Self
+                unsafe { debug_unwrap!(Self::new(value as $prim)) }
+            }
+        }
+    };
+}
+
+packed_index!(
+    /// An unsigned 24-bit index, stored in 3 bytes with an alignment of 1.
+    ///
+    /// It addresses up to `0xFE_FFFF` nodes, for lists too long for `u16`
+    /// where a `u32` would waste a byte per link. `Option<U24>` also takes
+    /// 3 bytes.
+    U24,
+    u32,
+    3,
+    0xFE_FFFF
+);
+
+packed_index!(
+    /// An unsigned 48-bit index, stored in 6 bytes with an alignment of 1.
+    ///
+    /// It addresses up to `0xFEFF_FFFF_FFFF` nodes, or as many as `usize`
+    /// can on narrower targets. `Option<U48>` also takes 6 bytes.
+    U48,
+    u64,
+    6,
+    0xFEFF_FFFF_FFFF
+);
@@ -0,0 +1,43 @@
+use rayon::iter::{
+    IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator,
+    IntoParallelRefMutIterator, ParallelExtend, ParallelIterator,
+};
+
+use alloc::vec::Vec;
+
+use crate::{
+    inner_types::{StoreIndex, VecNode},
+    Allocator, LinkedVec,
+};
+
+impl<T: Sync, I: StoreIndex + Copy + Sync, A: Allocator> LinkedVec<T, I, A> {
+    /// Provides a parallel iterator over the physical array.
+    ///
+    /// Elements are yielded in physical order, not in logical order.
+    pub fn par_iter_p(&self) -> impl IndexedParallelIterator<Item = &T> + '_ {
+        self.data[..].par_iter().map(|x| &x.payload)
+    }
+}
+
+impl<T: Send, I: StoreIndex + Copy + Send, A: Allocator> LinkedVec<T, I, A> {
+    /// Provides a parallel iterator with mutable references over the
+    /// physical array.
+    ///
+    /// Elements are yielded in physical order, not in logical order.
+    pub fn par_iter_p_mut(&mut self) -> impl IndexedParallelIterator<Item = &mut T> + '_ {
+        self.data[..].par_iter_mut().map(|x| &mut x.payload)
+    }
+}
+
+impl<T: Send, I: StoreIndex + Copy + Send, A: Allocator> ParallelExtend<T> for LinkedVec<T, I, A> {
+    /// Appends the items in order. They are collected in parallel, moved
+    /// to the end of the physical array, then linked in one pass.
+    fn par_extend<P: IntoParallelIterator<Item = T>>(&mut self, par_iter: P) {
+        // Rayon can only collect into a `Vec` with the global allocator
+        let nodes: Vec<_> = par_iter.into_par_iter().map(VecNode::new).collect();
+        let start = self.len();
+        self.data.extend(nodes);
+        self.link_suffix(start);
+        paranoid_check!(self);
+    }
+}
@@ -0,0 +1,175 @@
+use alloc::vec::Vec;
+use core::{fmt::Debug, ops::Deref};
+
+use crate::{index_out_of_bounds, inner_types::StoreIndex, Allocator, Global, LinkedVec};
+
+/// A [`LinkedVec`] that records the logical index of every physical slot.
+///
+/// [`logical_index_of_p`] then costs *O*(1) time, for code that holds on
+/// to physical indices but has to report positions to users. The records
+/// are kept up to date by every mutation, so pushing and popping at the
+/// back stay *O*(1), while every other insertion or removal costs *O*(n)
+/// time to shift the positions after it.
+///
+/// It dereferences to the list for every read API.
+///
+/// [`logical_index_of_p`]: PositionedLinkedVec::logical_index_of_p
+pub struct PositionedLinkedVec<T, I: StoreIndex + Copy = usize, A: Allocator = Global> {
+    list: LinkedVec<T, I, A>,
+    /// The logical index of the element at each physical index.
+    positions: Vec<usize>,
+}
+
+impl<T, I: StoreIndex + Copy> PositionedLinkedVec<T, I> {
+    pub fn new() -> Self {
+        Self::from(LinkedVec::new())
+    }
+}
+
+impl<T, I: StoreIndex + Copy, A: Allocator> PositionedLinkedVec<T, I, A> {
+    /// Returns the logical index of the element at physical `index`, or
+    /// `None` if the index is out of bounds.
+    #[must_use]
+    pub fn logical_index_of_p(&self, index: usize) -> Option<usize> {
+        self.positions.get(index).copied()
+    }
+
+    /// Returns a mutable reference to the element at physical `index`, or
+    /// `None` if the index is out of bounds.
+    #[must_use]
+    pub fn get_p_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.list.get_p_mut(index)
+    }
+
+    /// Inserts an element at logical `index` in the linked list and last
+    /// in the physical array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert_l(&mut self, index: usize, value: T) {
+        let len = self.list.len();
+        self.list.insert_l(index, value);
+
+        if index < len {
+            for l in &mut self.positions {
+                if *l >= index {
+                    *l += 1;
+                }
+            }
+        }
+        self.positions.push(index);
+    }
+
+    /// Removes and returns the element at logical `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    pub fn remove_l(&mut self, index: usize) -> T {
+        let Some(removed) = self.list.nth_p(index) else {
+            index_out_of_bounds(index, self.list.len())
+        };
+        self.remove_at(removed, index)
+    }
+
+    /// Removes and returns the element at physical `index`, moving the
+    /// physically last element into its place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    pub fn remove_p(&mut self, index: usize) -> T {
+        let Some(&l) = self.positions.get(index) else {
+            index_out_of_bounds(index, self.list.len())
+        };
+        self.remove_at(index, l)
+    }
+
+    /// Inserts an element first in the linked list and last in the physical array.
+    pub fn push_front(&mut self, value: T) {
+        self.insert_l(0, value)
+    }
+
+    /// Inserts an element last in the linked list and last in the physical array.
+    pub fn push_back(&mut self, value: T) {
+        self.insert_l(self.list.len(), value)
+    }
+
+    /// Remove and return first element in the linked list, if any.
+    pub fn pop_front(&mut self) -> Option<T> {
+        (!self.list.is_empty()).then(|| self.remove_l(0))
+    }
+
+    /// Remove and return last element in the linked list, if any.
+    pub fn pop_back(&mut self) -> Option<T> {
+        let tail = self.list.tail?.to_usize();
+        Some(self.remove_at(tail, self.list.len() - 1))
+    }
+
+    /// Removes all elements from the list.
+    pub fn clear(&mut self) {
+        self.list.clear();
+        self.positions.clear();
+    }
+
+    /// Returns the number of heap bytes allocated for the physical array
+    /// and the positions.
+    pub fn allocated_bytes(&self) -> usize {
+        self.list.allocated_bytes() + self.positions.capacity() * core::mem::size_of::<usize>()
+    }
+
+    /// Gives the plain list back.
+    pub fn into_inner(self) -> LinkedVec<T, I, A> {
+        self.list
+    }
+
+    /// Removes the element at physical index `p` and logical index `l`.
+    fn remove_at(&mut self, p: usize, l: usize) -> T {
+        let value = self.list.in_swap_remove(p);
+        if l < self.list.len() {
+            for x in &mut self.positions {
+                if *x > l {
+                    *x -= 1;
+                }
+            }
+        }
+        // Same move as on the physical array
+        self.positions.swap_remove(p);
+        value
+    }
+}
+
+impl<T, I: StoreIndex + Copy, A: Allocator> From<LinkedVec<T, I, A>>
+    for PositionedLinkedVec<T, I, A>
+{
+    /// Records the positions of `list`. This operation should compute in
+    /// *O*(n) time.
+    fn from(list: LinkedVec<T, I, A>) -> Self {
+        let mut positions = alloc::vec![0; list.len()];
+        for (l, p) in list.indices_l().enumerate() {
+            positions[p] = l;
+        }
+        Self { list, positions }
+    }
+}
+
+impl<T, I: StoreIndex + Copy> Default for PositionedLinkedVec<T, I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, I: StoreIndex + Copy, A: Allocator> Deref for PositionedLinkedVec<T, I, A> {
+    type Target = LinkedVec<T, I, A>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.list
+    }
+}
+
+impl<T: Debug, I: StoreIndex + Copy, A: Allocator> Debug for PositionedLinkedVec<T, I, A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.list.fmt(f)
+    }
+}
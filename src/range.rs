@@ -0,0 +1,283 @@
+//! Borrowed views of a logical range of a list.
+//!
+//! These are named `LinkedRange` rather than after slices, because
+//! [`LinkedSlice`](crate::slice::LinkedSlice) is already the list over a
+//! leaked array.
+
+use core::{cmp::Ordering, ops::RangeBounds};
+
+use crate::{
+    inner_types::StoreIndex,
+    iterators::{Iter, IterMut},
+    Allocator, Global, LinkedVec,
+};
+
+/// A view of a run of adjacent elements of a [`LinkedVec`], in logical
+/// order.
+///
+/// It remembers the physical indices of both ends and its length, so it
+/// can be iterated, compared and passed around without walking the list
+/// again or copying anything. Returned by [`LinkedVec::range_l`].
+#[derive(Debug)]
+pub struct LinkedRange<'a, T: 'a, I: Copy + StoreIndex, A: Allocator = Global> {
+    list: &'a LinkedVec<T, I, A>,
+    head: usize, // Could be I,
+    tail: usize, // Could be I,
+    len: usize,
+}
+
+impl<T, I: Copy + StoreIndex, A: Allocator> Clone for LinkedRange<'_, T, I, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, I: Copy + StoreIndex, A: Allocator> Copy for LinkedRange<'_, T, I, A> {}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> LinkedRange<'a, T, I, A> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Provides a reference to the first element of the range, or `None`
+    /// if it is empty.
+    #[must_use]
+    pub fn front(&self) -> Option<&'a T> {
+        (self.len != 0).then(|| &self.list.data[self.head].payload)
+    }
+
+    /// Provides a reference to the last element of the range, or `None` if
+    /// it is empty.
+    #[must_use]
+    pub fn back(&self) -> Option<&'a T> {
+        (self.len != 0).then(|| &self.list.data[self.tail].payload)
+    }
+
+    /// Provides a forward iterator over the range.
+    #[must_use]
+    pub fn iter(&self) -> Iter<'a, T, I, A> {
+        Iter::new_range(self.list, self.head, self.tail, self.len)
+    }
+
+    /// Returns the list the range is a view of.
+    #[must_use]
+    pub fn get_list(&self) -> &'a LinkedVec<T, I, A> {
+        self.list
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> IntoIterator for LinkedRange<'a, T, I, A> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, I, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> IntoIterator for &LinkedRange<'a, T, I, A> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, I, A>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: PartialEq, I: Copy + StoreIndex, A: Allocator> PartialEq for LinkedRange<'_, T, I, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, I: Copy + StoreIndex, A: Allocator> Eq for LinkedRange<'_, T, I, A> {}
+
+impl<T: PartialOrd, I: Copy + StoreIndex, A: Allocator> PartialOrd for LinkedRange<'_, T, I, A> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord, I: Copy + StoreIndex, A: Allocator> Ord for LinkedRange<'_, T, I, A> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: PartialEq, I: Copy + StoreIndex, A: Allocator> PartialEq<[T]> for LinkedRange<'_, T, I, A> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.iter().eq(other)
+    }
+}
+
+/// A mutable view of a run of adjacent elements of a [`LinkedVec`], in
+/// logical order.
+///
+/// The elements can be changed in place, but not added or removed.
+/// Returned by [`LinkedVec::range_l_mut`].
+#[derive(Debug)]
+pub struct LinkedRangeMut<'a, T: 'a, I: Copy + StoreIndex, A: Allocator = Global> {
+    list: &'a mut LinkedVec<T, I, A>,
+    head: usize, // Could be I,
+    tail: usize, // Could be I,
+    len: usize,
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> LinkedRangeMut<'a, T, I, A> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Provides a reference to the first element of the range, or `None`
+    /// if it is empty.
+    #[must_use]
+    pub fn front(&self) -> Option<&T> {
+        (self.len != 0).then(|| &self.list.data[self.head].payload)
+    }
+
+    /// Provides a mutable reference to the first element of the range, or
+    /// `None` if it is empty.
+    #[must_use]
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        (self.len != 0).then(|| &mut self.list.data[self.head].payload)
+    }
+
+    /// Provides a reference to the last element of the range, or `None` if
+    /// it is empty.
+    #[must_use]
+    pub fn back(&self) -> Option<&T> {
+        (self.len != 0).then(|| &self.list.data[self.tail].payload)
+    }
+
+    /// Provides a mutable reference to the last element of the range, or
+    /// `None` if it is empty.
+    #[must_use]
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        (self.len != 0).then(|| &mut self.list.data[self.tail].payload)
+    }
+
+    /// Provides a forward iterator over the range.
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_, T, I, A> {
+        Iter::new_range(self.list, self.head, self.tail, self.len)
+    }
+
+    /// Provides a forward iterator with mutable references over the range.
+    #[must_use]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, I> {
+        IterMut::new_range(self.list, self.head, self.tail, self.len)
+    }
+
+    /// Returns a read-only view of the same range.
+    #[must_use]
+    pub fn as_range(&self) -> LinkedRange<'_, T, I, A> {
+        LinkedRange {
+            list: self.list,
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+        }
+    }
+
+    /// Returns the list the range is a view of.
+    #[must_use]
+    pub fn get_list(&self) -> &LinkedVec<T, I, A> {
+        self.list
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex, A: Allocator> IntoIterator for LinkedRangeMut<'a, T, I, A> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T, I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IterMut::new_range(self.list, self.head, self.tail, self.len)
+    }
+}
+
+impl<T: PartialEq, I: Copy + StoreIndex, A: Allocator> PartialEq for LinkedRangeMut<'_, T, I, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, I: Copy + StoreIndex, A: Allocator> Eq for LinkedRangeMut<'_, T, I, A> {}
+
+impl<T: PartialOrd, I: Copy + StoreIndex, A: Allocator> PartialOrd for LinkedRangeMut<'_, T, I, A> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord, I: Copy + StoreIndex, A: Allocator> Ord for LinkedRangeMut<'_, T, I, A> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: PartialEq, I: Copy + StoreIndex, A: Allocator> PartialEq<[T]>
+    for LinkedRangeMut<'_, T, I, A>
+{
+    fn eq(&self, other: &[T]) -> bool {
+        self.iter().eq(other)
+    }
+}
+
+impl<T, I: Copy + StoreIndex, A: Allocator> LinkedVec<T, I, A> {
+    /// Resolves a logical range into `(head, tail, len)`.
+    fn range_ends<R: RangeBounds<usize>>(&self, range: R) -> (usize, usize, usize) {
+        let (start, end) = crate::range_l(range, self.len());
+        if start == end {
+            return (0, 0, 0);
+        }
+        let head = self.nth_p(start).unwrap();
+        let tail = self.nth_p_from(Some((start, head)), end - 1).unwrap();
+        (head, tail, end - start)
+    }
+
+    /// Returns a view of the elements in a range of logical indices.
+    ///
+    /// Finding the ends takes *O*(n) time, walking from the closest end of
+    /// the list, but the view itself is then free to use.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than the end, or if
+    /// the end is greater than the length of the list.
+    pub fn range_l<R: RangeBounds<usize>>(&self, range: R) -> LinkedRange<'_, T, I, A> {
+        let (head, tail, len) = self.range_ends(range);
+        LinkedRange {
+            list: self,
+            head,
+            tail,
+            len,
+        }
+    }
+
+    /// Returns a mutable view of the elements in a range of logical
+    /// indices.
+    ///
+    /// See [`range_l`](Self::range_l).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than the end, or if
+    /// the end is greater than the length of the list.
+    pub fn range_l_mut<R: RangeBounds<usize>>(&mut self, range: R) -> LinkedRangeMut<'_, T, I, A> {
+        let (head, tail, len) = self.range_ends(range);
+        LinkedRangeMut {
+            list: self,
+            head,
+            tail,
+            len,
+        }
+    }
+}
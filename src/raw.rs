@@ -0,0 +1,176 @@
+//! The raw pointer work on the physical array, kept in one place.
+//!
+//! Every pointer here comes from `as_ptr` or `as_mut_ptr` on the array and
+//! is only offset with `add`, so it keeps the provenance of the buffer and
+//! never goes through an integer. The rest of the crate calls these
+//! functions instead of dereferencing raw pointers, which keeps the unsafe
+//! surface small enough to audit and to run under Miri.
+//!
+//! This also covers converting the array to and from its raw parts, and
+//! [`InlineVec`], the partly initialized array behind
+//! [`ArrayLinkedVec`](crate::array::ArrayLinkedVec).
+
+use alloc::boxed::Box;
+use core::{
+    mem::{ManuallyDrop, MaybeUninit},
+    ptr, slice,
+};
+
+use crate::{
+    allocator::{Allocator, Vec},
+    inner_types::{StoreIndex, VecNode},
+};
+
+/// Decomposes `data` into its pointer, length and capacity, leaking it.
+pub(crate) fn into_raw_parts<T>(data: Vec<T>) -> (*mut T, usize, usize) {
+    let mut data = ManuallyDrop::new(data);
+    (data.as_mut_ptr(), data.len(), data.capacity())
+}
+
+/// Rebuilds a `Vec` of the global allocator from its raw parts.
+///
+/// # Safety
+///
+/// Same as [`alloc::vec::Vec::from_raw_parts`].
+pub(crate) unsafe fn from_raw_parts<T>(ptr: *mut T, len: usize, capacity: usize) -> Vec<T> {
+    // Safety: Ensured by the caller
+    unsafe { Vec::from_raw_parts(ptr, len, capacity) }
+}
+
+/// Converts `data` into a boxed slice, dropping the spare capacity.
+pub(crate) fn into_boxed_slice<T>(data: Vec<T>) -> Box<[T]> {
+    let (ptr, len, capacity) = into_raw_parts(data);
+    // Safety: The parts come from a `Vec` of the global allocator, which
+    // is also the one of `Box`
+    unsafe { alloc::vec::Vec::from_raw_parts(ptr, len, capacity) }.into_boxed_slice()
+}
+
+/// Replaces the nodes of `dst` with a bitwise copy of `src`.
+///
+/// The whole buffer is copied at once, with no work per node.
+pub(crate) fn copy_nodes<T: Copy, I: StoreIndex + Copy, A: Allocator>(
+    dst: &mut Vec<VecNode<T, I>, A>,
+    src: &[VecNode<T, I>],
+) {
+    dst.clear();
+    dst.reserve_exact(src.len());
+    // Safety: The buffer has room for `src.len()` nodes, which do not
+    // overlap with `src`. Both `T` and `I` are `Copy`, so the nodes can be
+    // duplicated bitwise.
+    unsafe {
+        ptr::copy_nonoverlapping(src.as_ptr(), dst.as_mut_ptr(), src.len());
+        dst.set_len(src.len());
+    }
+}
+
+/// Moves every payload out of `data`, following the links from `head`,
+/// and leaves it empty.
+///
+/// If `f` panics, the payloads not yet passed to it are leaked.
+///
+/// # Safety
+///
+/// The links from `head` must visit every node of `data` exactly once.
+pub(crate) unsafe fn drain_linked<T, I: StoreIndex + Copy, A: Allocator, F: FnMut(T)>(
+    data: &mut Vec<VecNode<T, I>, A>,
+    head: Option<I>,
+    mut f: F,
+) {
+    let nodes = data.as_ptr();
+    // The length is cleared first, so that no node is dropped twice
+    // Safety: Zero is always a valid length
+    unsafe { data.set_len(0) };
+    let mut current = head;
+    while let Some(index) = current {
+        // Safety: Ensured by the caller, every index is in bounds and read
+        // only once
+        let node = unsafe { ptr::read(nodes.add(index.to_usize())) };
+        current = node.next;
+        f(node.payload);
+    }
+}
+
+/// Swaps the payloads of the nodes at `a` and `b`, which may be the same
+/// node. The links stay where they are.
+///
+/// # Panics
+///
+/// Panics if `a` or `b` is out of bounds.
+pub(crate) fn swap_payloads<T, I>(nodes: &mut [VecNode<T, I>], a: usize, b: usize) {
+    assert!(a < nodes.len() && b < nodes.len());
+    let base = nodes.as_mut_ptr();
+    // Safety: Both nodes are in bounds of the same exclusive borrow, and
+    // `ptr::swap` allows the two places to be the same.
+    unsafe {
+        ptr::swap(
+            ptr::addr_of_mut!((*base.add(a)).payload),
+            ptr::addr_of_mut!((*base.add(b)).payload),
+        );
+    }
+}
+
+/// An inline array of `N` slots whose first `len` are initialized, like a
+/// `Vec` that cannot grow.
+pub(crate) struct InlineVec<T, const N: usize> {
+    slots: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> InlineVec<T, N> {
+    pub(crate) const fn new() -> Self {
+        Self {
+            slots: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    pub(crate) fn as_slice(&self) -> &[T] {
+        // Safety: The first `len` slots are initialized, and
+        // `MaybeUninit<T>` has the layout of `T`
+        unsafe { slice::from_raw_parts(self.slots.as_ptr().cast::<T>(), self.len) }
+    }
+
+    pub(crate) fn as_mut_slice(&mut self) -> &mut [T] {
+        // Safety: Same as `as_slice`, with an exclusive borrow
+        unsafe { slice::from_raw_parts_mut(self.slots.as_mut_ptr().cast::<T>(), self.len) }
+    }
+
+    /// Appends `value`, or gives it back if every slot is taken.
+    pub(crate) fn push(&mut self, value: T) -> Result<(), T> {
+        match self.slots.get_mut(self.len) {
+            Some(slot) => {
+                slot.write(value);
+                self.len += 1;
+                Ok(())
+            }
+            None => Err(value),
+        }
+    }
+
+    /// Removes and returns the element at `index`, moving the last element
+    /// into its place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    pub(crate) fn swap_remove(&mut self, index: usize) -> T {
+        assert!(index < self.len);
+        let last = self.len - 1;
+        self.slots.swap(index, last);
+        self.len = last;
+        // Safety: The slot was initialized, and is now past `len`, so it is
+        // never read again
+        unsafe { self.slots[last].assume_init_read() }
+    }
+}
+
+impl<T, const N: usize> Drop for InlineVec<T, N> {
+    fn drop(&mut self) {
+        // Safety: The initialized elements are dropped once, here
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) }
+    }
+}
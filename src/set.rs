@@ -0,0 +1,208 @@
+use core::{borrow::Borrow, fmt::Debug};
+
+use crate::{inner_types::StoreIndex, map, map::LinkedVecMap};
+
+/// A set that remembers the order its values were inserted in.
+///
+/// This is a [`LinkedVecMap`] with `()` values, so membership tests cost
+/// *O*(log n) time and moving a value to either end of the order costs
+/// *O*(1) time once it is found. [`insert_refresh`] moves a value that is
+/// already there to the back, for LRU sets.
+///
+/// [`insert_refresh`]: LinkedVecSet::insert_refresh
+pub struct LinkedVecSet<T, I: StoreIndex + Copy = usize> {
+    map: LinkedVecMap<T, (), I>,
+}
+
+impl<T, I: StoreIndex + Copy> LinkedVecSet<T, I> {
+    pub const fn new() -> Self {
+        Self {
+            map: LinkedVecMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Provides the first value in insertion order, or `None` if the set
+    /// is empty.
+    #[must_use]
+    pub fn front(&self) -> Option<&T> {
+        self.map.front().map(|(v, _)| v)
+    }
+
+    /// Provides the last value in insertion order, or `None` if the set is
+    /// empty.
+    #[must_use]
+    pub fn back(&self) -> Option<&T> {
+        self.map.back().map(|(v, _)| v)
+    }
+
+    /// Provides a forward iterator over the values in insertion order.
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_, T, I> {
+        Iter(self.map.iter())
+    }
+
+    /// Removes all values from the set.
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+}
+
+impl<T: Ord + Clone, I: StoreIndex + Copy> LinkedVecSet<T, I> {
+    pub fn contains<Q: Ord + ?Sized>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+    {
+        self.map.contains_key(value)
+    }
+
+    /// Adds a value to the back of the set.
+    ///
+    /// Returns `false`, and leaves the set unchanged, if the value was
+    /// already there.
+    pub fn insert(&mut self, value: T) -> bool {
+        if self.map.contains_key(&value) {
+            return false;
+        }
+        self.map.insert(value, ());
+        true
+    }
+
+    /// Adds a value to the back of the set, or moves it there if it was
+    /// already in the set.
+    ///
+    /// Returns `true` if the value was not already there.
+    pub fn insert_refresh(&mut self, value: T) -> bool {
+        if self.map.move_to_back(&value) {
+            return false;
+        }
+        self.map.insert(value, ());
+        true
+    }
+
+    /// Moves `value` to the front of the order.
+    ///
+    /// Returns `false` if the set does not have `value`.
+    pub fn move_to_front<Q: Ord + ?Sized>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+    {
+        self.map.move_to_front(value)
+    }
+
+    /// Moves `value` to the back of the order.
+    ///
+    /// Returns `false` if the set does not have `value`.
+    pub fn move_to_back<Q: Ord + ?Sized>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+    {
+        self.map.move_to_back(value)
+    }
+
+    /// Removes `value` from the set, returning whether it was there.
+    pub fn remove<Q: Ord + ?Sized>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+    {
+        self.map.remove(value).is_some()
+    }
+
+    /// Removes and returns the value in the set equal to `value`, if any.
+    pub fn take<Q: Ord + ?Sized>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+    {
+        self.map.remove_entry(value).map(|(v, _)| v)
+    }
+
+    /// Removes and returns the first value in insertion order, if any.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.map.pop_front().map(|(v, _)| v)
+    }
+
+    /// Removes and returns the last value in insertion order, if any.
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.map.pop_back().map(|(v, _)| v)
+    }
+}
+
+impl<T: Clone, I: StoreIndex + Copy> Clone for LinkedVecSet<T, I> {
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<T, I: StoreIndex + Copy> Default for LinkedVecSet<T, I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug, I: StoreIndex + Copy> Debug for LinkedVecSet<T, I> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Ord + Clone, I: StoreIndex + Copy> Extend<T> for LinkedVecSet<T, I> {
+    fn extend<It: IntoIterator<Item = T>>(&mut self, iter: It) {
+        for v in iter {
+            self.insert(v);
+        }
+    }
+}
+
+impl<T: Ord + Clone, I: StoreIndex + Copy> FromIterator<T> for LinkedVecSet<T, I> {
+    fn from_iter<It: IntoIterator<Item = T>>(iter: It) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<'a, T, I: StoreIndex + Copy> IntoIterator for &'a LinkedVecSet<T, I> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Forward iterator over the values of a [`LinkedVecSet`].
+#[derive(Debug)]
+pub struct Iter<'a, T: 'a, I: Copy + StoreIndex>(map::Iter<'a, T, (), I>);
+
+impl<T, I: Copy + StoreIndex> Clone for Iter<'_, T, I> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex> Iterator for Iter<'a, T, I> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(v, _)| v)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a, T: 'a, I: Copy + StoreIndex> DoubleEndedIterator for Iter<'a, T, I> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|(v, _)| v)
+    }
+}
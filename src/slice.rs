@@ -0,0 +1,97 @@
+use core::fmt::Debug;
+
+use crate::{inner_types::StoreIndex, iterators::Iter, VecNode};
+
+/// A doubly linked list over a borrowed slice of nodes.
+///
+/// It is created by [`LinkedVec::leak`](crate::LinkedVec::leak). The
+/// elements can be read and changed in place, but the list cannot grow
+/// or shrink.
+pub struct LinkedSlice<'a, T, I: StoreIndex + Copy = usize> {
+    nodes: &'a mut [VecNode<T, I>],
+    head: Option<I>,
+    tail: Option<I>,
+}
+
+impl<'a, T, I: StoreIndex + Copy> LinkedSlice<'a, T, I> {
+    /// The caller ensures that the nodes form a single list from `head` to
+    /// `tail`.
+    pub(crate) fn new(nodes: &'a mut [VecNode<T, I>], head: Option<I>, tail: Option<I>) -> Self {
+        Self { nodes, head, tail }
+    }
+
+    /// Returns the number of elements in the list.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if the list contains no elements.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns a reference to the element at `index` on the physical array,
+    /// or `None` if the index is out of bounds.
+    #[must_use]
+    pub fn get_p(&self, index: usize) -> Option<&T> {
+        self.nodes.get(index).map(|x| &x.payload)
+    }
+
+    /// Returns a mutable reference to the element at `index` on the physical
+    /// array, or `None` if the index is out of bounds.
+    #[must_use]
+    pub fn get_p_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.nodes.get_mut(index).map(|x| &mut x.payload)
+    }
+
+    /// Provides a reference to the front element, or `None` if the list is
+    /// empty.
+    #[must_use]
+    pub fn front(&self) -> Option<&T> {
+        self.head.map(|x| &self.nodes[x.to_usize()].payload)
+    }
+
+    /// Provides a mutable reference to the front element, or `None` if the list is
+    /// empty.
+    #[must_use]
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.head.map(|x| &mut self.nodes[x.to_usize()].payload)
+    }
+
+    /// Provides a reference to the back element, or `None` if the list is
+    /// empty.
+    #[must_use]
+    pub fn back(&self) -> Option<&T> {
+        self.tail.map(|x| &self.nodes[x.to_usize()].payload)
+    }
+
+    /// Provides a mutable reference to the back element, or `None` if the list is
+    /// empty.
+    #[must_use]
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.tail.map(|x| &mut self.nodes[x.to_usize()].payload)
+    }
+
+    /// Provides a forward iterator.
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_, T, I> {
+        Iter::from_nodes(self.nodes, self.head, self.tail)
+    }
+}
+
+impl<T: Debug, I: StoreIndex + Copy> Debug for LinkedSlice<'_, T, I> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, T, I: StoreIndex + Copy> IntoIterator for &'a LinkedSlice<'_, T, I> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,226 @@
+use core::fmt::Debug;
+
+use crate::{array::ArrayLinkedVec, inner_types::StoreIndex, iterators::Iter, LinkedVec};
+
+/// A doubly linked list that stores up to `N` nodes inline before
+/// spilling to the heap.
+///
+/// While it has at most `N` elements, this is an [`ArrayLinkedVec`].
+/// Pushing one more moves all elements into a [`LinkedVec`] in logical
+/// order, so physical indices change once when the list spills. It
+/// stays on the heap after that, even if it shrinks again.
+pub enum SmallLinkedVec<T, const N: usize, I: StoreIndex + Copy = usize> {
+    Inline(ArrayLinkedVec<T, N, I>),
+    Heap(LinkedVec<T, I>),
+}
+
+impl<T, const N: usize, I: StoreIndex + Copy> SmallLinkedVec<T, N, I> {
+    pub const fn new() -> Self {
+        Self::Inline(ArrayLinkedVec::new())
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Inline(list) => list.len(),
+            Self::Heap(list) => list.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of heap bytes allocated, which is zero until the
+    /// list spills.
+    pub fn allocated_bytes(&self) -> usize {
+        match self {
+            Self::Inline(_) => 0,
+            Self::Heap(list) => list.allocated_bytes(),
+        }
+    }
+
+    /// Returns `true` if the elements have been moved to the heap.
+    pub fn spilled(&self) -> bool {
+        matches!(self, Self::Heap(_))
+    }
+
+    /// Returns a reference to the element at `index` on the physical array,
+    /// or `None` if the index is out of bounds.
+    #[must_use]
+    pub fn get_p(&self, index: usize) -> Option<&T> {
+        match self {
+            Self::Inline(list) => list.get_p(index),
+            Self::Heap(list) => list.get_p(index),
+        }
+    }
+
+    /// Returns a mutable reference to the element at `index` on the physical
+    /// array, or `None` if the index is out of bounds.
+    #[must_use]
+    pub fn get_p_mut(&mut self, index: usize) -> Option<&mut T> {
+        match self {
+            Self::Inline(list) => list.get_p_mut(index),
+            Self::Heap(list) => list.get_p_mut(index),
+        }
+    }
+
+    /// Provides a reference to the front element, or `None` if the list is
+    /// empty.
+    #[must_use]
+    pub fn front(&self) -> Option<&T> {
+        match self {
+            Self::Inline(list) => list.front(),
+            Self::Heap(list) => list.front(),
+        }
+    }
+
+    /// Provides a mutable reference to the front element, or `None` if the list is
+    /// empty.
+    #[must_use]
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        match self {
+            Self::Inline(list) => list.front_mut(),
+            Self::Heap(list) => list.front_mut(),
+        }
+    }
+
+    /// Provides a reference to the back element, or `None` if the list is
+    /// empty.
+    #[must_use]
+    pub fn back(&self) -> Option<&T> {
+        match self {
+            Self::Inline(list) => list.back(),
+            Self::Heap(list) => list.back(),
+        }
+    }
+
+    /// Provides a mutable reference to the back element, or `None` if the list is
+    /// empty.
+    #[must_use]
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        match self {
+            Self::Inline(list) => list.back_mut(),
+            Self::Heap(list) => list.back_mut(),
+        }
+    }
+
+    /// Inserts an element first in the linked list and last in the physical array.
+    pub fn push_front(&mut self, value: T) {
+        let value = match self {
+            Self::Inline(list) => match list.push_front(value) {
+                Ok(()) => return,
+                Err(value) => value,
+            },
+            Self::Heap(list) => return list.push_front(value),
+        };
+        self.spill().push_front(value)
+    }
+
+    /// Inserts an element last in the linked list and last in the physical array.
+    pub fn push_back(&mut self, value: T) {
+        let value = match self {
+            Self::Inline(list) => match list.push_back(value) {
+                Ok(()) => return,
+                Err(value) => value,
+            },
+            Self::Heap(list) => return list.push_back(value),
+        };
+        self.spill().push_back(value)
+    }
+
+    /// Remove and return first element in the linked list, if any.
+    pub fn pop_front(&mut self) -> Option<T> {
+        match self {
+            Self::Inline(list) => list.pop_front(),
+            Self::Heap(list) => list.pop_front(),
+        }
+    }
+
+    /// Remove and return last element in the linked list, if any.
+    pub fn pop_back(&mut self) -> Option<T> {
+        match self {
+            Self::Inline(list) => list.pop_back(),
+            Self::Heap(list) => list.pop_back(),
+        }
+    }
+
+    /// Remove and return the element pointed to by the index on the physical array.
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        match self {
+            Self::Inline(list) => list.swap_remove(index),
+            Self::Heap(list) => list.swap_remove(index),
+        }
+    }
+
+    /// Removes all elements from the list.
+    ///
+    /// The elements are dropped in logical order, front to back.
+    pub fn clear(&mut self) {
+        match self {
+            Self::Inline(list) => list.clear(),
+            Self::Heap(list) => list.clear(),
+        }
+    }
+
+    /// Provides a forward iterator.
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_, T, I> {
+        match self {
+            Self::Inline(list) => list.iter(),
+            Self::Heap(list) => list.iter(),
+        }
+    }
+
+    /// Moves the elements to the heap in logical order.
+    fn spill(&mut self) -> &mut LinkedVec<T, I> {
+        if let Self::Inline(inline) = self {
+            let mut heap = LinkedVec::new();
+            _ = heap.try_reserve(inline.len() * 2);
+            while let Some(value) = inline.pop_front() {
+                heap.push_back(value);
+            }
+            *self = Self::Heap(heap);
+        }
+        match self {
+            Self::Heap(list) => list,
+            Self::Inline(_) => unreachable!(),
+        }
+    }
+}
+
+impl<T, const N: usize, I: StoreIndex + Copy> Default for SmallLinkedVec<T, N, I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Debug, const N: usize, I: StoreIndex + Copy> Debug for SmallLinkedVec<T, N, I> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const N: usize, I: StoreIndex + Copy> Extend<T> for SmallLinkedVec<T, N, I> {
+    fn extend<It: IntoIterator<Item = T>>(&mut self, iter: It) {
+        for v in iter {
+            self.push_back(v);
+        }
+    }
+}
+
+impl<T, const N: usize, I: StoreIndex + Copy> FromIterator<T> for SmallLinkedVec<T, N, I> {
+    fn from_iter<It: IntoIterator<Item = T>>(iter: It) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<'a, T: 'a, const N: usize, I: Copy + StoreIndex> IntoIterator for &'a SmallLinkedVec<T, N, I> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,135 @@
+//! Byte snapshots of the physical array.
+//!
+//! A snapshot is a header of three `u64`s, the length, head and tail,
+//! followed by every node in physical order: the bytes of the payload,
+//! then its `next` and `prev` links as `u64`s. All integers are little
+//! endian, and missing links are stored as `u64::MAX`. Links are stored
+//! at a fixed width so that the format does not depend on `I`.
+
+use core::mem::size_of;
+
+use bytemuck::Pod;
+
+use crate::{
+    allocator::Vec, inner_types::StoreIndex, Allocator, Error, LinkCorruption, LinkedVec,
+    OverflowPolicy, VecNode,
+};
+
+const HEADER: usize = 3 * size_of::<u64>();
+const LINKS: usize = 2 * size_of::<u64>();
+const NONE: u64 = u64::MAX;
+
+fn encode<I: StoreIndex>(link: &Option<I>) -> [u8; 8] {
+    link.as_ref()
+        .map_or(NONE, |x| x.to_usize() as u64)
+        .to_le_bytes()
+}
+
+fn read_u64(bytes: &[u8]) -> u64 {
+    u64::from_le_bytes(bytes[..size_of::<u64>()].try_into().unwrap())
+}
+
+impl<T: Pod, I: StoreIndex + Copy, A: Allocator> LinkedVec<T, I, A> {
+    /// Returns the size in bytes of a snapshot of the list.
+    #[must_use]
+    pub fn snapshot_len(&self) -> usize {
+        HEADER + self.len() * (size_of::<T>() + LINKS)
+    }
+
+    /// Writes a snapshot of the physical array to the start of `buf`, and
+    /// returns the number of bytes written.
+    ///
+    /// The snapshot holds the payloads and links as they are, so
+    /// [`from_snapshot`](LinkedVec::from_snapshot) restores the same
+    /// physical indices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buf` is shorter than [`snapshot_len`](LinkedVec::snapshot_len).
+    pub fn write_snapshot(&self, buf: &mut [u8]) -> usize {
+        let total = self.snapshot_len();
+        let (header, mut rest) = buf[..total].split_at_mut(HEADER);
+        header[..8].copy_from_slice(&(self.len() as u64).to_le_bytes());
+        header[8..16].copy_from_slice(&encode(&self.head));
+        header[16..].copy_from_slice(&encode(&self.tail));
+
+        for node in self.data.iter() {
+            let (payload, tail) = rest.split_at_mut(size_of::<T>());
+            payload.copy_from_slice(bytemuck::bytes_of(&node.payload));
+            let (links, tail) = tail.split_at_mut(LINKS);
+            links[..8].copy_from_slice(&encode(&node.next));
+            links[8..].copy_from_slice(&encode(&node.prev));
+            rest = tail;
+        }
+        total
+    }
+
+    /// Returns a snapshot of the physical array.
+    ///
+    /// See [`write_snapshot`](LinkedVec::write_snapshot).
+    #[must_use]
+    pub fn as_bytes_p(&self) -> alloc::vec::Vec<u8> {
+        let mut buf = alloc::vec![0; self.snapshot_len()];
+        self.write_snapshot(&mut buf);
+        buf
+    }
+}
+
+impl<T: Pod, I: StoreIndex + Copy> LinkedVec<T, I> {
+    /// Restores a list from a snapshot written by
+    /// [`write_snapshot`](LinkedVec::write_snapshot).
+    ///
+    /// The payloads may be unaligned in `bytes`. This operation should
+    /// compute in *O*(n) time.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidSnapshot`] if `bytes` is not exactly as long
+    /// as its header announces, [`Error::CapacityOverflow`] if the length
+    /// is too large for `I`, and [`Error::LinkCorruption`] if the links do
+    /// not form a single list.
+    pub fn from_snapshot(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() < HEADER {
+            return Err(Error::InvalidSnapshot);
+        }
+        let len = usize::try_from(read_u64(bytes)).map_err(|_| Error::InvalidSnapshot)?;
+        let node_len = size_of::<T>() + LINKS;
+        if len.checked_mul(node_len) != Some(bytes.len() - HEADER) {
+            return Err(Error::InvalidSnapshot);
+        }
+        if len > 0 && len - 1 > I::MAX_USIZE {
+            return Err(Error::CapacityOverflow);
+        }
+
+        let decode = |bytes: &[u8]| match read_u64(bytes) {
+            NONE => Ok(None),
+            x => match usize::try_from(x) {
+                Ok(x) if x < len => Ok(Some(I::from_usize(x))),
+                _ => Err(LinkCorruption::OutOfBounds {
+                    index_p: x.try_into().unwrap_or(usize::MAX),
+                }),
+            },
+        };
+
+        let mut data = Vec::new();
+        data.reserve_exact(len);
+        for node in bytes[HEADER..].chunks_exact(node_len) {
+            let (payload, links) = node.split_at(size_of::<T>());
+            data.push(VecNode {
+                payload: bytemuck::pod_read_unaligned(payload),
+                next: decode(links)?,
+                prev: decode(&links[8..])?,
+            });
+        }
+        let list = Self {
+            data,
+            head: decode(&bytes[8..])?,
+            tail: decode(&bytes[16..])?,
+            version: 0,
+            overflow_policy: OverflowPolicy::Error,
+            max_len: None,
+        };
+        list.validate_links()?;
+        Ok(list)
+    }
+}
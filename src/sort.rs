@@ -0,0 +1,224 @@
+//! Sorting, by relinking or on the physical array.
+
+use core::cmp::Ordering;
+
+use crate::{inner_types::StoreIndex, Allocator, LinkedVec};
+
+impl<T, I: StoreIndex + Copy, A: Allocator> LinkedVec<T, I, A> {
+    /// Sorts the list in logical order.
+    ///
+    /// See [`sort_by`](Self::sort_by).
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+
+    /// Sorts the list in logical order with a key extraction function.
+    ///
+    /// See [`sort_by`](Self::sort_by). The key is computed twice per
+    /// comparison.
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut f: F) {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Sorts the list in logical order with a comparison function.
+    ///
+    /// The sort is stable, and only relinks the nodes, so every element
+    /// keeps its physical index. It is a natural merge sort: the ascending
+    /// runs already in the list are found in one pass and then merged
+    /// pairwise. This takes *O*(n log r) time for `r` runs, so a sorted or
+    /// nearly sorted list costs about *O*(n).
+    ///
+    /// If `compare` panics, the list keeps all of its elements, in physical
+    /// order.
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        let Some(head) = self.head else {
+            return;
+        };
+        let guard = RelinkOnDrop(self);
+        let list = &mut *guard.0;
+
+        // Cut the chain into ascending runs, leaving only the `next` links
+        let mut runs = alloc::vec::Vec::new();
+        let mut start = head.to_usize();
+        let mut current = start;
+        while let Some(next) = list.data[current].next {
+            let next = next.to_usize();
+            if compare(&list.data[next].payload, &list.data[current].payload) == Ordering::Less {
+                list.data[current].next = None;
+                runs.push(start);
+                start = next;
+            }
+            current = next;
+        }
+        if runs.is_empty() {
+            // Already sorted, and nothing was cut
+            core::mem::forget(guard);
+            return;
+        }
+        runs.push(start);
+
+        while runs.len() > 1 {
+            let mut merged = 0;
+            for k in (0..runs.len()).step_by(2) {
+                runs[merged] = match runs.get(k + 1) {
+                    Some(&right) => list.merge_runs(runs[k], right, &mut compare),
+                    None => runs[k],
+                };
+                merged += 1;
+            }
+            runs.truncate(merged);
+        }
+
+        // Restore the `prev` links along the merged chain
+        let mut prev = None;
+        let mut current = Some(runs[0]);
+        while let Some(p) = current {
+            list.data[p].prev = prev.map(I::from_usize);
+            prev = Some(p);
+            current = list.data[p].next.map(|x| x.to_usize());
+        }
+        list.head = Some(I::from_usize(runs[0]));
+        list.tail = prev.map(I::from_usize);
+        core::mem::forget(guard);
+        self.bump_version();
+        paranoid_check!(self);
+    }
+
+    /// Sorts the physical array and links it in physical order.
+    ///
+    /// See [`sort_p_by`](Self::sort_p_by).
+    pub fn sort_p(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_p_by(T::cmp);
+    }
+
+    /// Sorts the physical array with a key extraction function and links
+    /// it in physical order.
+    ///
+    /// See [`sort_p_by`](Self::sort_p_by).
+    pub fn sort_p_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut f: F) {
+        self.sort_p_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    /// Sorts the physical array with a comparison function and links it in
+    /// physical order.
+    ///
+    /// Afterwards the list is sorted in logical order too, and
+    /// [contiguous](Self::is_contiguous). Unlike [`sort_by`](Self::sort_by),
+    /// this moves the nodes with a slice sort, which is often faster and
+    /// leaves the list in the best state for iteration. The sort is stable
+    /// with respect to the physical order, not the logical one.
+    ///
+    /// If `compare` panics, the list keeps all of its elements, in physical
+    /// order.
+    pub fn sort_p_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut compare: F) {
+        let guard = RelinkOnDrop(self);
+        guard.0.data.sort_by(|a, b| compare(&a.payload, &b.payload));
+        // Relinking in physical order is also what finishes the sort
+        drop(guard);
+        self.bump_version();
+        paranoid_check!(self);
+    }
+
+    /// Reorders the list so that the element at logical index `n` is the
+    /// one that would be there if the list were sorted, and returns it.
+    ///
+    /// See [`select_nth_l_by`](Self::select_nth_l_by).
+    pub fn select_nth_l(&mut self, n: usize) -> Option<&mut T>
+    where
+        T: Ord,
+    {
+        self.select_nth_l_by(n, T::cmp)
+    }
+
+    /// Reorders the list with a comparison function so that the element at
+    /// logical index `n` is the one that would be there if the list were
+    /// sorted, and returns it.
+    ///
+    /// Every element before it compares less than or equal to it, and
+    /// every element after it greater than or equal to it. This is a
+    /// quickselect on the physical array, which is then linked in physical
+    /// order, so it takes *O*(n) time on average, and physical indices
+    /// change like with [`sort_p_by`](Self::sort_p_by).
+    ///
+    /// Returns `None`, and leaves the list unchanged, if `n >= len`. If
+    /// `compare` panics, the list keeps all of its elements, in physical
+    /// order.
+    pub fn select_nth_l_by<F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        n: usize,
+        mut compare: F,
+    ) -> Option<&mut T> {
+        if n >= self.len() {
+            return None;
+        }
+        let guard = RelinkOnDrop(self);
+        guard
+            .0
+            .data
+            .select_nth_unstable_by(n, |a, b| compare(&a.payload, &b.payload));
+        // Relinking in physical order puts the nth element in place
+        drop(guard);
+        self.bump_version();
+        paranoid_check!(self);
+        Some(&mut self.data[n].payload)
+    }
+
+    /// Merges two chains linked only by `next`, and returns the head of
+    /// the result. Ties are taken from `a` first.
+    fn merge_runs<F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        a: usize,
+        b: usize,
+        compare: &mut F,
+    ) -> usize {
+        let next = |list: &Self, p: usize| list.data[p].next.map(|x| x.to_usize());
+        let (mut a, mut b) = (Some(a), Some(b));
+        let mut head = None;
+        let mut tail: Option<usize> = None;
+        loop {
+            let taken = match (a, b) {
+                (Some(x), Some(y)) => {
+                    if compare(&self.data[y].payload, &self.data[x].payload) == Ordering::Less {
+                        b = next(self, y);
+                        y
+                    } else {
+                        a = next(self, x);
+                        x
+                    }
+                }
+                // The rest of one chain is already linked
+                (Some(rest), None) | (None, Some(rest)) => {
+                    self.data[tail.unwrap()].next = Some(I::from_usize(rest));
+                    break;
+                }
+                (None, None) => break,
+            };
+            match tail {
+                Some(t) => self.data[t].next = Some(I::from_usize(taken)),
+                None => head = Some(taken),
+            }
+            tail = Some(taken);
+        }
+        head.unwrap()
+    }
+}
+
+/// Links every node in physical order when dropped.
+///
+/// Guards sorts that leave the links half rewritten while user code runs.
+/// Forget it once the links are consistent again.
+struct RelinkOnDrop<'a, T, I: StoreIndex + Copy, A: Allocator>(&'a mut LinkedVec<T, I, A>);
+
+impl<T, I: StoreIndex + Copy, A: Allocator> Drop for RelinkOnDrop<'_, T, I, A> {
+    fn drop(&mut self) {
+        self.0.head = None;
+        self.0.tail = None;
+        self.0.link_suffix(0);
+    }
+}
@@ -0,0 +1,95 @@
+//! Strategies for generating lists in property tests with `proptest`.
+//!
+//! The generated lists have their elements scattered over the physical
+//! array, so the links are exercised as much as the elements. Shrinking
+//! shortens the list and moves it toward physical order.
+
+use alloc::vec::Vec;
+
+use proptest::{
+    arbitrary::{any_with, Arbitrary},
+    collection::{vec, SizeRange},
+    strategy::{BoxedStrategy, Just, Strategy},
+};
+
+use crate::{inner_types::StoreIndex, LinkedVec};
+
+/// Generates lists with elements from `element` and a length in `size`,
+/// each element at a random physical index.
+///
+/// # Panics
+///
+/// The strategy panics if `size` allows lengths that `I` cannot index.
+pub fn lists<S, I>(
+    element: S,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = LinkedVec<S::Value, I>>
+where
+    S: Strategy,
+    S::Value: Clone,
+    I: StoreIndex + Copy,
+{
+    vec(element, size)
+        .prop_flat_map(|values| {
+            let order: Vec<usize> = (0..values.len()).collect();
+            (Just(values), Just(order).prop_shuffle())
+        })
+        .prop_map(|(values, order)| scrambled(values, &order))
+}
+
+/// Generates lists that are at most `slack` elements away from holding as
+/// many elements as `I` can index.
+///
+/// This is meant for small index types like `u8` and `u16`, to test code
+/// near [`OverflowPolicy`](crate::OverflowPolicy) decisions. See
+/// [`lists`].
+pub fn near_capacity<S, I>(
+    element: S,
+    slack: usize,
+) -> impl Strategy<Value = LinkedVec<S::Value, I>>
+where
+    S: Strategy,
+    S::Value: Clone,
+    I: StoreIndex + Copy,
+{
+    let max = I::MAX_USIZE.saturating_add(1);
+    lists(element, max.saturating_sub(slack)..=max)
+}
+
+/// Builds a list of `values` in logical order, with the element at
+/// logical index `l` at physical index `order[l]`.
+fn scrambled<T, I: StoreIndex + Copy>(values: Vec<T>, order: &[usize]) -> LinkedVec<T, I> {
+    let mut slots: Vec<Option<T>> = core::iter::repeat_with(|| None)
+        .take(values.len())
+        .collect();
+    for (value, &p) in values.into_iter().zip(order) {
+        slots[p] = Some(value);
+    }
+    let mut list = LinkedVec::new();
+    for value in slots {
+        list.push_p(value.unwrap());
+    }
+    let mut prev = None;
+    for &p in order {
+        let current = Some(I::from_usize(p));
+        list.pair(prev, current);
+        prev = current;
+    }
+    list.pair(prev, None);
+    paranoid_check!(list);
+    list
+}
+
+impl<T, I> Arbitrary for LinkedVec<T, I>
+where
+    T: Arbitrary + Clone + 'static,
+    I: StoreIndex + Copy + 'static,
+{
+    /// The range of lengths and the parameters of the elements.
+    type Parameters = (SizeRange, T::Parameters);
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((size, params): Self::Parameters) -> Self::Strategy {
+        lists(any_with::<T>(params), size).boxed()
+    }
+}
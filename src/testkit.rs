@@ -0,0 +1,176 @@
+//! Checks and a fuzz loop for testing code built on [`LinkedVec`].
+//!
+//! [`fuzz`] pushes and pops random elements at both ends of a list and of
+//! a [`VecDeque`] that models it in logical order, checking the links as
+//! it goes. It takes any [`RngCore`], which makes a failing run
+//! reproducible from its seed. [`check_op`] does the same check for a
+//! single [`Op`], for loops over the other operations. The models assume
+//! the default [`OverflowPolicy`](crate::OverflowPolicy).
+//!
+//! Miri runs tests orders of magnitude slower, so [`fuzz`] scales its
+//! rounds down with [`miri_scaled`] when built with `cfg(miri)`. Loops in
+//! downstream tests can use it the same way.
+//!
+//! The crate's own tests use this module too, so it is also built for
+//! them without the `testkit` feature.
+
+use alloc::collections::VecDeque;
+#[cfg(feature = "ops")]
+use core::fmt::Debug;
+
+use rand_core::RngCore;
+
+#[cfg(feature = "ops")]
+use crate::ops::Op;
+use crate::{inner_types::StoreIndex, Allocator, LinkedVec};
+
+/// Panics if the links of `list` are corrupted, describing the first
+/// broken invariant.
+#[track_caller]
+pub fn check_links<T, I: StoreIndex + Copy, A: Allocator>(list: &LinkedVec<T, I, A>) {
+    if let Err(err) = list.validate_links() {
+        panic!("link corruption: {err}");
+    }
+}
+
+#[cfg(feature = "ops")]
+/// Performs `op` on `list` and on `model`, which holds the same elements
+/// in logical order, and returns the element `op` removed, if any.
+///
+/// # Panics
+///
+/// Panics if the two disagree on the element removed or on the elements
+/// left, if the links of `list` end up corrupted, or if `op` is not valid
+/// for `list`, such as with an index out of bounds.
+#[track_caller]
+pub fn check_op<T, I, A>(
+    list: &mut LinkedVec<T, I, A>,
+    model: &mut VecDeque<T>,
+    op: Op<T>,
+) -> Option<T>
+where
+    T: Clone + PartialEq + Debug,
+    I: StoreIndex + Copy,
+    A: Allocator,
+{
+    let expected = apply_to_model(list, model, op.clone());
+    let removed = list.apply(op);
+    assert_eq!(removed, expected, "removed element differs from the model");
+    check_links(list);
+    assert!(
+        list.iter().eq(model.iter()),
+        "list {list:?} differs from the model {model:?}"
+    );
+    removed
+}
+
+#[cfg(feature = "ops")]
+/// Performs `op` on `model` as it would be performed on `list`, which
+/// still holds the same elements. Physical indices are looked up in `list`.
+fn apply_to_model<T, I: StoreIndex + Copy, A: Allocator>(
+    list: &LinkedVec<T, I, A>,
+    model: &mut VecDeque<T>,
+    op: Op<T>,
+) -> Option<T> {
+    let index_l = |p: usize| {
+        list.indices_l()
+            .position(|x| x == p)
+            .expect("physical index out of bounds")
+    };
+    match op {
+        Op::PushFront(value) => model.push_front(value),
+        Op::PushBack(value) => model.push_back(value),
+        Op::PopFront => return model.pop_front(),
+        Op::PopBack => return model.pop_back(),
+        Op::Pop => {
+            return list
+                .len()
+                .checked_sub(1)
+                .and_then(|p| model.remove(index_l(p)))
+        }
+        Op::SwapRemove(index) => return model.remove(index_l(index)),
+        Op::SwapP(a, b) => model.swap(index_l(a), index_l(b)),
+        Op::InsertL(index, value) => model.insert(index, value),
+        Op::RemoveL(index) => return model.remove(index),
+        Op::Truncate(len) => model.truncate(len),
+        Op::Clear => model.clear(),
+    }
+    None
+}
+
+#[cfg(feature = "ops")]
+/// Returns a random op that is valid for a list of `len` elements.
+///
+/// Pushes and insertions carry the random number they were chosen with.
+/// Growing ops are picked a bit more often than shrinking ones, and
+/// clearing rarely, so the list tends to grow over a run.
+pub fn random_op(rng: &mut impl RngCore, len: usize) -> Op<u32> {
+    let r = rng.next_u32();
+    let mut index = |bound: usize| rng.next_u32() as usize % bound;
+    match r % 32 {
+        0..=5 => Op::PushFront(r),
+        6..=11 => Op::PushBack(r),
+        12..=16 => Op::InsertL(index(len + 1), r),
+        _ if len == 0 => Op::PushBack(r),
+        17..=18 => Op::PopFront,
+        19..=20 => Op::PopBack,
+        21 => Op::Pop,
+        22..=23 => Op::SwapRemove(index(len)),
+        24..=26 => Op::SwapP(index(len), index(len)),
+        27..=29 => Op::RemoveL(index(len)),
+        30 => Op::Truncate(index(len + 1)),
+        _ => Op::Clear,
+    }
+}
+
+/// Returns `n` divided by 64, rounded up, under Miri, and `n` otherwise.
+///
+/// Use it for the number of rounds of randomized tests, so that they
+/// still finish under Miri while covering the same kinds of ops.
+#[must_use]
+pub const fn miri_scaled(n: usize) -> usize {
+    if cfg!(miri) {
+        n.div_ceil(64)
+    } else {
+        n
+    }
+}
+
+/// Performs `rounds` random pushes and pops at both ends of `list`,
+/// checking the links before every one and the elements against a model
+/// at the end. Under Miri, the rounds are scaled down with
+/// [`miri_scaled`].
+///
+/// # Panics
+///
+/// Panics if the list and the model disagree, or if a push overflows `I`.
+#[track_caller]
+pub fn fuzz<I: StoreIndex + Copy, A: Allocator>(
+    list: &mut LinkedVec<u32, I, A>,
+    rng: &mut impl RngCore,
+    rounds: usize,
+) {
+    let mut model: VecDeque<u32> = list.iter().copied().collect();
+    for _ in 0..miri_scaled(rounds) {
+        check_links(list);
+        let r = rng.next_u32();
+        match r % 6 {
+            0 => assert_eq!(list.pop_back(), model.pop_back()),
+            1 => assert_eq!(list.pop_front(), model.pop_front()),
+            2 | 4 => {
+                list.push_front(r);
+                model.push_front(r);
+            }
+            _ => {
+                list.push_back(r);
+                model.push_back(r);
+            }
+        }
+    }
+
+    check_links(list);
+    assert!(
+        list.iter().eq(model.iter()),
+        "list {list:?} differs from the model {model:?}"
+    );
+}
@@ -1,10 +1,19 @@
 #![cfg(test)]
+extern crate std;
+
 mod std_stolen_tests;
 
-use alloc::borrow::ToOwned as _;
-use core::mem;
+use alloc::{
+    borrow::ToOwned as _,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{cell::RefCell, mem};
+use std::panic::{catch_unwind, AssertUnwindSafe};
 
 use super::*;
+use crate::testkit::check_links;
 
 #[test]
 fn test_vecnode() {
@@ -119,3 +128,2665 @@ fn overflow_ni_b() {
 }
 
 const _: () = debug_assert!(mem::size_of::<VecNode<isize, nonmax::NonMaxU32>>() == 16);
+
+/// The documented bound on the size of a node.
+fn max_node_size<T, I>() -> usize {
+    let align = mem::align_of::<VecNode<T, I>>();
+    (mem::size_of::<T>() + 2 * mem::size_of::<Option<I>>()).next_multiple_of(align)
+}
+
+fn check_node_size<T, I: StoreIndex + Copy>(expected: usize) {
+    assert_eq!(LinkedVec::<T, I>::node_size(), expected);
+    assert!(LinkedVec::<T, I>::node_size() <= max_node_size::<T, I>());
+}
+
+#[test]
+fn packed_index_types() {
+    assert_eq!(mem::size_of::<Option<U24>>(), 3);
+    assert_eq!(mem::align_of::<Option<U24>>(), 1);
+    assert_eq!(mem::size_of::<Option<U48>>(), 6);
+    assert_eq!(mem::align_of::<Option<U48>>(), 1);
+    check_node_size::<[u8; 4], U48>(16);
+
+    for value in [0, 1, 0xFF, 0x100, 0x12_3456, 0xFE_FFFF] {
+        assert_eq!(U24::new(value).unwrap().get(), value);
+        assert_eq!(U24::from_usize(value as usize).to_usize(), value as usize);
+    }
+    assert_eq!(U24::new(0xFF_0000), None);
+    assert_eq!(U24::MAX.get(), 0xFE_FFFF);
+    assert_eq!(U24::MAX_USIZE, 0xFE_FFFF);
+    assert_eq!(U24::try_from_usize(0xFF_0000), Err(Error::CapacityOverflow));
+    assert!(U24::new(0x100) > U24::new(0xFF));
+    assert_eq!(format!("{:?}", U24::new(300).unwrap()), "300");
+
+    for value in [0, 0x1234_5678_9ABC, 0xFEFF_FFFF_FFFF] {
+        assert_eq!(U48::new(value).unwrap().get(), value);
+    }
+    assert_eq!(U48::new(0xFF00_0000_0000), None);
+    assert_eq!(U48::new(1 << 48), None);
+
+    let mut list: LinkedVec<u16, U24> = (0..300).collect();
+    list.push_front(7);
+    assert_eq!(list.remove_l(150), 149);
+    check_links(&list);
+    assert!(list
+        .iter()
+        .copied()
+        .eq([7].into_iter().chain((0..300).filter(|&x| x != 149))));
+}
+
+#[test]
+fn node_size() {
+    check_node_size::<u16, nonmax::NonMaxU8>(4);
+    check_node_size::<u16, u8>(6);
+    check_node_size::<u32, nonmax::NonMaxU16>(8);
+    check_node_size::<u32, u16>(12);
+    check_node_size::<u16, U24>(8);
+    check_node_size::<u64, nonmax::NonMaxU32>(16);
+    check_node_size::<u64, u32>(24);
+    check_node_size::<u64, nonmax::NonMaxU64>(24);
+    check_node_size::<u64, u64>(40);
+
+    check_node_size::<(), nonmax::NonMaxU32>(8);
+    check_node_size::<u8, nonmax::NonMaxU32>(12);
+    check_node_size::<[u8; 3], nonmax::NonMaxU16>(8);
+    check_node_size::<isize, nonmax::NonMaxUsize>(3 * mem::size_of::<usize>());
+    check_node_size::<u8, u8>(5);
+
+    // The links of the nonmax types take no more than one packed integer
+    assert_eq!(
+        mem::size_of::<[Option<nonmax::NonMaxU8>; 2]>(),
+        mem::size_of::<u16>()
+    );
+    assert_eq!(
+        mem::size_of::<[Option<nonmax::NonMaxU16>; 2]>(),
+        mem::size_of::<u32>()
+    );
+    assert_eq!(
+        mem::size_of::<[Option<nonmax::NonMaxU32>; 2]>(),
+        mem::size_of::<u64>()
+    );
+}
+
+#[test]
+fn cursor_pair_mut() {
+    let mut list: LinkedVec<u32> = (0..6).collect();
+    list.push_front(9);
+    assert!(list.cursor_pair_mut(1, 1).is_none());
+    assert!(list.cursor_pair_mut(1, 7).is_none());
+
+    let mut pair = list.cursor_pair_mut(1, 2).unwrap();
+    assert_eq!(pair.current(), (Some(&mut 0), Some(&mut 1)));
+    assert!(!pair.move_first_next());
+    assert!(!pair.move_second_prev());
+    assert!(pair.move_first_prev());
+    assert_eq!(pair.first().index_l(), Some(0));
+    if let (Some(a), Some(b)) = pair.current() {
+        mem::swap(a, b);
+    }
+    assert!(pair.move_second_next());
+    assert!(pair.swap_current());
+    assert_eq!(pair.second().current(), Some(&1));
+    assert!(pair.move_first_prev());
+    assert!(!pair.swap_current());
+    assert_eq!(pair.current().0, None);
+
+    assert!(list.iter().eq(&[2, 0, 9, 1, 3, 4, 5]));
+}
+
+#[test]
+fn get_many_mut_p() {
+    let mut list: LinkedVec<u32> = (0..4).collect();
+    let [a, b] = list.get_many_mut_p([3, 1]).unwrap();
+    mem::swap(a, b);
+    assert!(list.iter().eq(&[0, 3, 2, 1]));
+
+    assert!(list.get_many_mut_p([0, 0]).is_none());
+    assert!(list.get_many_mut_p([0, 4]).is_none());
+    assert_eq!(list.get_many_mut_p::<0>([]), Some([]));
+}
+
+#[test]
+fn array_linked_vec() {
+    use array::ArrayLinkedVec;
+
+    let mut list = ArrayLinkedVec::<_, 4, u8>::new();
+    assert_eq!(list.capacity(), 4);
+    assert_eq!(list.push_back(1), Ok(()));
+    assert_eq!(list.push_back(2), Ok(()));
+    assert_eq!(list.push_front(0), Ok(()));
+    assert_eq!(list.push_front(-1), Ok(()));
+    assert!(list.is_full());
+    assert_eq!(list.push_back(3), Err(3));
+    assert!(list.iter().copied().eq([-1, 0, 1, 2]));
+    assert!(list.iter().rev().copied().eq([2, 1, 0, -1]));
+    assert_eq!(list.iter().len(), 4);
+    assert_eq!(list.iter().nth(2), Some(&1));
+    assert_eq!(list.iter().last(), Some(&2));
+    assert_eq!(format!("{list:?}"), "[-1, 0, 1, 2]");
+
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_next();
+    *cursor.current().unwrap() = 10;
+    assert_eq!(cursor.as_cursor().peek_next(), Some(&1));
+    assert_eq!(list.cursor_back().peek_prev(), Some(&1));
+
+    assert_eq!(list.pop_front(), Some(-1));
+    assert_eq!(list.swap_remove(0), 1);
+    assert!(list.iter().copied().eq([10, 2]));
+    assert_eq!(list.push_back(3), Ok(()));
+    assert_eq!(list.pop_back(), Some(3));
+    assert_eq!(list.front(), Some(&10));
+    assert_eq!(list.back(), Some(&2));
+
+    // Elements are dropped in logical order
+    let log = RefCell::new(Vec::new());
+    let mut list = ArrayLinkedVec::<_, 3>::new();
+    for i in [1, 0, 2] {
+        assert!(list.push_back(DropLogger(i, &log)).is_ok());
+    }
+    list.swap_remove(0);
+    drop(list);
+    assert_eq!(*log.borrow(), [1, 0, 2]);
+
+    let small = ArrayLinkedVec::<(), 300, u8>::new();
+    assert_eq!(small.capacity(), 256);
+}
+
+#[test]
+fn small_linked_vec() {
+    use small::SmallLinkedVec;
+
+    let mut list = SmallLinkedVec::<u32, 2>::new();
+    list.push_back(1);
+    list.push_front(0);
+    assert!(!list.spilled());
+    list.push_back(2);
+    list.push_front(3);
+    assert!(list.spilled());
+    assert!(list.iter().copied().eq([3, 0, 1, 2]));
+    assert_eq!(format!("{list:?}"), "[3, 0, 1, 2]");
+    if let SmallLinkedVec::Heap(heap) = &list {
+        check_links(heap);
+    }
+
+    assert_eq!(list.pop_back(), Some(2));
+    assert_eq!(list.pop_front(), Some(3));
+    assert_eq!(list.len(), 2);
+    assert!(list.spilled());
+
+    let list: SmallLinkedVec<u32, 4> = (0..4).collect();
+    assert!(!list.spilled());
+    assert!(list.iter().rev().copied().eq((0..4).rev()));
+}
+
+#[test]
+fn display() {
+    let mut list: LinkedVec<f32> = [1.0, 2.5].into_iter().collect();
+    list.push_front(0.25);
+    assert_eq!(format!("{}", list.display(", ")), "0.25, 1, 2.5");
+    assert_eq!(format!("{:.1}", list.display(" -> ")), "0.2 -> 1.0 -> 2.5");
+    assert_eq!(format!("{}", LinkedVec::<u8>::new().display(", ")), "");
+}
+
+#[test]
+fn iter_indices() {
+    let mut list: LinkedVec<char> = "bcd".chars().collect();
+    list.push_front('a');
+    let expected = [(0, 3, &'a'), (1, 0, &'b'), (2, 1, &'c'), (3, 2, &'d')];
+    assert!(list.iter_indices().eq(expected));
+    assert!(list.iter_indices().rev().eq(expected.into_iter().rev()));
+    assert!(list.iter_with_p().eq(expected.map(|(_, p, x)| (p, x))));
+
+    let mut it = list.iter_indices();
+    assert_eq!(it.next_back(), Some((3, 2, &'d')));
+    assert_eq!(it.next(), Some((0, 3, &'a')));
+    assert_eq!(it.next_back(), Some((2, 1, &'c')));
+    assert_eq!(it.next(), Some((1, 0, &'b')));
+    assert_eq!(it.next(), None);
+
+    let (p, _) = list.iter_with_p().find(|&(_, &x)| x == 'c').unwrap();
+    assert_eq!(list.swap_remove(p), 'c');
+}
+
+#[test]
+fn iter_indices_mut() {
+    let mut list: LinkedVec<u32> = (1..5).collect();
+    list.push_front(0);
+    let expected: Vec<_> = list.iter_indices().map(|(l, p, &x)| (l, p, x)).collect();
+    assert!(list
+        .iter_indices_mut()
+        .map(|(l, p, x)| (l, p, *x))
+        .eq(expected.iter().copied()));
+    assert!(list
+        .iter_indices_mut()
+        .rev()
+        .map(|(l, p, x)| (l, p, *x))
+        .eq(expected.iter().copied().rev()));
+
+    let mut it = list.iter_indices_mut();
+    assert_eq!(it.next_back().map(|(l, p, _)| (l, p)), Some((4, 3)));
+    assert_eq!(it.next().map(|(l, p, _)| (l, p)), Some((0, 4)));
+    assert_eq!(it.next_back().map(|(l, p, _)| (l, p)), Some((3, 2)));
+    assert_eq!(it.size_hint(), (2, Some(2)));
+
+    // Double the elements, noting the odd ones to remove afterward
+    let mut odd = Vec::new();
+    for (_, p, x) in list.iter_indices_mut() {
+        if *x % 2 == 1 {
+            odd.push(p);
+        }
+        *x *= 2;
+    }
+    odd.sort_unstable();
+    for p in odd.into_iter().rev() {
+        list.swap_remove(p);
+    }
+    check_links(&list);
+    assert!(list.iter().eq(&[0, 4, 8]));
+}
+
+#[test]
+fn cursor_into_iter() {
+    let mut list: LinkedVec<u32> = (1..5).collect();
+    list.push_front(0);
+
+    let mut cursor = list.cursor_front();
+    cursor.move_next();
+    cursor.move_next();
+    assert!(cursor.clone().into_iter().copied().eq(2..5));
+    assert!(cursor.clone().into_iter().rev().copied().eq((2..5).rev()));
+    assert!(cursor.clone().into_iter_back().rev().copied().eq([2, 1, 0]));
+    cursor.move_prev();
+    cursor.move_prev();
+    cursor.move_prev();
+    assert_eq!(cursor.clone().into_iter().next(), None);
+    assert_eq!(cursor.into_iter_back().next(), None);
+
+    let mut cursor = list.cursor_back_mut();
+    cursor.move_prev();
+    cursor.into_iter_mut().for_each(|x| *x *= 10);
+    assert!(list.iter().copied().eq([0, 1, 2, 30, 40]));
+}
+
+#[test]
+fn splice_range_l() {
+    for range in [0..0, 0..2, 1..4, 2..6, 6..6, 0..6] {
+        let mut list: LinkedVec<u32> = (1..6).collect();
+        list.push_front(0);
+        let removed: Vec<u32> = list.splice_range_l(range.clone(), [10, 11, 12]).collect();
+        check_links(&list);
+
+        let mut expected: Vec<u32> = (0..6).collect();
+        let expected_removed: Vec<u32> = expected.splice(range, [10, 11, 12]).collect();
+        assert_eq!(removed, expected_removed);
+        assert!(list.iter().eq(expected.iter()));
+    }
+
+    let mut list: LinkedVec<u32> = (0..4).collect();
+    assert!(list.splice_range_l(1..=2, []).eq([1, 2]));
+    assert!(list.iter().copied().eq([0, 3]));
+    check_links(&list);
+}
+
+#[test]
+fn extend_front() {
+    let mut list: LinkedVec<u32> = (3..5).collect();
+    list.extend_front(0..3);
+    check_links(&list);
+    assert!(list.iter().copied().eq(0..5));
+
+    let mut list = LinkedVec::<u32>::new();
+    list.extend_front(0..3);
+    list.extend_front([]);
+    check_links(&list);
+    assert!(list.iter().copied().eq(0..3));
+    assert_eq!(list.back(), Some(&2));
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        list.extend_front((10..20).map(|x| if x == 15 { panic!() } else { x }))
+    }));
+    assert!(result.is_err());
+    check_links(&list);
+    assert!(list.iter().copied().eq(0..3));
+}
+
+#[test]
+fn extend_from_slice() {
+    let mut list: LinkedVec<u32> = (0..2).collect();
+    list.push_front(9);
+    list.extend_from_slice(&[2, 3]);
+    list.extend_from_slice(&[]);
+    check_links(&list);
+    assert!(list.iter().eq(&[9, 0, 1, 2, 3]));
+
+    #[derive(Debug, PartialEq)]
+    struct Fuse(u32);
+    impl Clone for Fuse {
+        fn clone(&self) -> Self {
+            assert_ne!(self.0, 0);
+            Self(self.0)
+        }
+    }
+    let mut list: LinkedVec<Fuse> = LinkedVec::new();
+    list.extend_from_slice(&[Fuse(1)]);
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        list.extend_from_slice(&[Fuse(2), Fuse(0)])
+    }));
+    assert!(result.is_err());
+    check_links(&list);
+    assert!(list.iter().eq(&[Fuse(1)]));
+}
+
+#[test]
+fn extend_from_within() {
+    let mut list: LinkedVec<u32> = (1..4).collect();
+    list.push_front(0);
+    list.extend_from_within(1..3);
+    list.extend_from_within(..=0);
+    list.extend_from_within(4..4);
+    check_links(&list);
+    assert!(list.iter().eq(&[0, 1, 2, 3, 1, 2, 0]));
+
+    let result = catch_unwind(AssertUnwindSafe(|| list.extend_from_within(6..8)));
+    assert!(result.is_err());
+    assert_eq!(list.len(), 7);
+}
+
+#[test]
+fn repeat_and_concat() {
+    let mut list: LinkedVec<u32> = (1..3).collect();
+    list.push_front(0);
+    let repeated = list.repeat(3);
+    check_links(&repeated);
+    assert!(repeated.iter().eq(&[0, 1, 2, 0, 1, 2, 0, 1, 2]));
+    assert!(list.repeat(0).is_empty());
+
+    let result = catch_unwind(AssertUnwindSafe(|| list.repeat(usize::MAX)));
+    assert!(result.is_err());
+
+    // Overflowing the index type is caught before anything is cloned
+    #[derive(Debug)]
+    struct NoClone;
+    impl Clone for NoClone {
+        fn clone(&self) -> Self {
+            panic!("cloned")
+        }
+    }
+    let small: LinkedVec<NoClone, u8> = [NoClone, NoClone].into_iter().collect();
+    let result = catch_unwind(AssertUnwindSafe(|| small.repeat(129)));
+    let message = result.unwrap_err().downcast::<String>().unwrap();
+    assert!(message.contains("capacity overflow"));
+
+    let concat = LinkedVec::concat([list, repeated.clone(), LinkedVec::new()]);
+    check_links(&concat);
+    assert!(concat.iter().eq(&[0, 1, 2, 0, 1, 2, 0, 1, 2, 0, 1, 2]));
+    assert!(LinkedVec::<u32>::concat([]).is_empty());
+}
+
+#[test]
+fn map() {
+    let mut list: LinkedVec<u32> = (1..5).collect();
+    list.push_front(0);
+    let layout = format!("{:?}", list.debug_layout());
+
+    let mut mapped = list.map(|x| x.to_string());
+    check_links(&mapped);
+    assert!(mapped.iter().eq(["0", "1", "2", "3", "4"]));
+    assert_eq!(
+        format!(
+            "{:?}",
+            mapped.map(|x| x.parse::<u32>().unwrap()).debug_layout()
+        ),
+        layout
+    );
+
+    mapped = ["a", "bb", "ccc"].map(String::from).into_iter().collect();
+    mapped.map_in_place(|x| x.push('!'));
+    assert!(mapped.iter().eq(["a!", "bb!", "ccc!"]));
+
+    let filtered = mapped.filter_map(|x| (x.len() > 2).then_some(x.len()));
+    check_links(&filtered);
+    assert!(filtered.iter().copied().eq([3, 4]));
+
+    let list: LinkedVec<u32> = (0..4).collect();
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        list.map(|x| if x == 2 { panic!() } else { x })
+    }));
+    assert!(result.is_err());
+}
+
+#[test]
+fn zip_and_unzip() {
+    let mut names: LinkedVec<&str> = ["b", "c"].into_iter().collect();
+    names.push_front("a");
+    let ages: LinkedVec<u32> = (1..4).collect();
+
+    let zipped = LinkedVec::zip_into(names, ages);
+    check_links(&zipped);
+    assert!(zipped.iter().copied().eq([("a", 1), ("b", 2), ("c", 3)]));
+
+    let mut zipped = zipped;
+    zipped.push_front(("z", 0));
+    let layout: Vec<usize> = IterP::new(&zipped).collect();
+    let (names, ages) = zipped.unzip();
+    check_links(&names);
+    check_links(&ages);
+    assert!(names.iter().copied().eq(["z", "a", "b", "c"]));
+    assert!(ages.iter().copied().eq(0..4));
+    assert!(IterP::new(&names).eq(layout.iter().copied()));
+    assert!(IterP::new(&ages).eq(layout));
+}
+
+#[test]
+#[should_panic(expected = "zipped lists should have the same length")]
+fn zip_different_lengths() {
+    let a: LinkedVec<u32> = (0..3).collect();
+    let b: LinkedVec<u32> = (0..4).collect();
+    let _ = LinkedVec::zip_into(a, b);
+}
+
+#[test]
+fn starts_and_ends_with() {
+    let mut list: LinkedVec<u32> = (1..5).collect();
+    list.push_front(0);
+    assert!(list.starts_with(&[0, 1]));
+    assert!(list.starts_with(&[]));
+    assert!(!list.starts_with(&[1]));
+    assert!(!list.starts_with(&[0, 1, 2, 3, 4, 5]));
+    assert!(list.ends_with(&[3, 4]));
+    assert!(list.ends_with(&list));
+    assert!(!list.ends_with(&[3]));
+
+    let other: LinkedVec<u32> = (0..3).collect();
+    assert!(list.starts_with(&other));
+    assert!(!list.ends_with(&other));
+}
+
+#[test]
+fn try_from_vec() {
+    let list = LinkedVec::<u32, u8>::try_from((0..256).collect::<Vec<u32>>()).unwrap();
+    check_links(&list);
+    assert!(list.iter().copied().eq(0..256));
+
+    let err = LinkedVec::<u32, u8>::try_from((0..257).collect::<Vec<u32>>()).unwrap_err();
+    assert_eq!(err.to_string(), "capacity overflow");
+    assert_eq!(err.error(), Error::CapacityOverflow);
+    assert_eq!(err.into_inner().len(), 257);
+
+    let list = LinkedVec::<u32, u8>::try_from_iter(0..100).unwrap();
+    assert!(list.iter().copied().eq(0..100));
+    let mut it = 0..300;
+    let (list, rejected) = LinkedVec::<u32, u8>::try_from_iter(&mut it)
+        .unwrap_err()
+        .into_inner();
+    check_links(&list);
+    assert_eq!(list.len(), 256);
+    assert_eq!(rejected, 256);
+    assert_eq!(it.next(), Some(257));
+}
+
+#[test]
+fn errors() {
+    let mut list: LinkedVec<u32, u8> = (0..3).collect();
+    assert_eq!(list.try_reserve(253), Ok(()));
+    assert_eq!(list.try_reserve(254), Err(Error::CapacityOverflow));
+    let mut big = LinkedVec::<u64>::new();
+    assert_eq!(
+        big.try_reserve(usize::MAX / 8),
+        Err(Error::CapacityOverflow)
+    );
+
+    assert_eq!(list.try_swap_remove(1), Ok(1));
+    let err = list.try_swap_remove(2).unwrap_err();
+    assert_eq!(err, Error::IndexOutOfBounds { index: 2, len: 2 });
+    assert_eq!(err.to_string(), "index (is 2) should be < or <= len (is 2)");
+
+    assert!(LinkedVec::<u8, u8>::try_from_iter(0..=255).is_ok());
+    let err: Error = LinkedVec::<u16, u8>::try_from_iter(0..300)
+        .unwrap_err()
+        .into();
+    assert_eq!(err, Error::CapacityOverflow);
+}
+
+#[test]
+fn relaxed_bounds() {
+    struct Opaque(u32);
+
+    let list = LinkedVec::<Opaque, nonmax::NonMaxU32>::default();
+    assert!(list.is_empty());
+
+    let mut list = LinkedVec::<Opaque>::default();
+    list.push_back(Opaque(1));
+    list.push_back(Opaque(2));
+    let it = list.iter();
+    let copy = it;
+    assert!(it.map(|x| x.0).eq(copy.map(|x| x.0)));
+
+    let a: LinkedVec<f32> = [1.0, f32::NAN].into_iter().collect();
+    assert_ne!(a, a);
+    assert_eq!(a.partial_cmp(&a), None);
+}
+
+#[test]
+fn copy_from() {
+    let mut source: LinkedVec<u32, u16> = (1..50).collect();
+    source.push_front(0);
+    source.swap_remove(7);
+
+    let mut list: LinkedVec<u32, u16> = (100..200).collect();
+    list.copy_from(&source);
+    check_links(&list);
+    assert_eq!(list, source);
+    assert_eq!(
+        format!("{:?}", list.debug_layout()),
+        format!("{:?}", source.debug_layout())
+    );
+
+    list.copy_from(&LinkedVec::new());
+    assert!(list.is_empty());
+    check_links(&list);
+}
+
+#[test]
+fn extend_with_lists() {
+    let mut a: LinkedVec<u32> = (1..3).collect();
+    a.push_front(0);
+    let mut b: LinkedVec<u32> = (4..6).collect();
+    b.push_front(3);
+    let mut c = LinkedVec::new();
+    c.push_back(6);
+
+    a.extend([b, LinkedVec::new(), c]);
+    check_links(&a);
+    assert!(a.iter().copied().eq(0..7));
+    // The nodes of each list keep their relative physical order
+    assert!(IterP::new(&a).eq([2, 0, 1, 5, 3, 4, 6]));
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn append_overflow() {
+    let mut a: LinkedVec<u8, u8> = (0..200).collect();
+    let mut b: LinkedVec<u8, u8> = (0..57).collect();
+    a.append(&mut b);
+}
+
+#[test]
+fn raw_parts() {
+    let mut list: LinkedVec<String, u32> = ["b", "c"].iter().map(|&s| s.to_owned()).collect();
+    list.push_front("a".to_owned());
+    list.try_reserve(10).unwrap();
+
+    let (ptr, len, cap, head, tail) = list.into_raw_parts();
+    assert_eq!((len, head, tail), (3, Some(2), Some(1)));
+    assert!(cap >= 13);
+    // Safety: The parts were just taken from a list
+    let front = unsafe { &*ptr.add(2) };
+    assert_eq!(
+        (front.payload.as_str(), front.next, front.prev),
+        ("a", Some(0), None)
+    );
+
+    // Safety: The parts are returned unchanged
+    let list = unsafe { LinkedVec::from_raw_parts(ptr, len, cap, head, tail) };
+    check_links(&list);
+    assert!(list.iter().eq(["a", "b", "c"]));
+
+    let (ptr, len, cap, head, tail) = LinkedVec::<u8>::new().into_raw_parts();
+    assert_eq!((len, cap, head, tail), (0, 0, None, None));
+    // Safety: The parts are returned unchanged
+    let list = unsafe { LinkedVec::from_raw_parts(ptr, len, cap, head, tail) };
+    assert!(list.is_empty());
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn snapshot() {
+    let mut list: LinkedVec<[u16; 3], u8> = LinkedVec::new();
+    list.push_back([1, 2, 3]);
+    list.push_front([4, 5, 6]);
+    list.push_back([7, 8, 9]);
+    list.swap_remove(0);
+
+    let bytes = list.as_bytes_p();
+    assert_eq!(bytes.len(), list.snapshot_len());
+    assert_eq!(bytes.len(), 24 + 2 * (6 + 16));
+    let mut unaligned = alloc::vec![0; bytes.len() + 1];
+    assert_eq!(list.write_snapshot(&mut unaligned[1..]), bytes.len());
+
+    let restored = LinkedVec::<[u16; 3], u8>::from_snapshot(&unaligned[1..]).unwrap();
+    check_links(&restored);
+    assert_eq!(restored, list);
+    assert!(IterP::new(&restored).eq(IterP::new(&list)));
+
+    let empty = LinkedVec::<u8>::new().as_bytes_p();
+    assert!(LinkedVec::<u8>::from_snapshot(&empty).unwrap().is_empty());
+
+    let err = LinkedVec::<[u16; 3], u8>::from_snapshot(&bytes[1..]).unwrap_err();
+    assert_eq!(err, Error::InvalidSnapshot);
+    let mut broken = bytes.clone();
+    broken[24 + 6..24 + 14].copy_from_slice(&5u64.to_le_bytes());
+    let err = LinkedVec::<[u16; 3], u8>::from_snapshot(&broken).unwrap_err();
+    assert_eq!(err, LinkCorruption::OutOfBounds { index_p: 5 }.into());
+    let mut broken = bytes;
+    broken[8..16].fill(0xff);
+    let err = LinkedVec::<[u16; 3], u8>::from_snapshot(&broken).unwrap_err();
+    assert_eq!(err, LinkCorruption::EndsMismatch.into());
+}
+
+#[test]
+fn frozen() {
+    let mut list: LinkedVec<u32, u16> = (1..100).collect();
+    list.push_front(0);
+    let frozen = std::sync::Arc::new(list.freeze());
+    assert_eq!(frozen.get_l(0), Some(&0));
+    assert_eq!(frozen.get_l(99), Some(&99));
+    assert_eq!(frozen.get_l(100), None);
+    assert_eq!(frozen.index_l_to_p(0), Some(99));
+
+    std::thread::scope(|s| {
+        for _ in 0..4 {
+            let frozen = &frozen;
+            s.spawn(move || {
+                assert!(frozen.iter().copied().eq(0..100));
+                assert_eq!(frozen.cursor_back().current(), Some(&99));
+            });
+        }
+    });
+
+    let shared = frozen.clone();
+    let frozen = frozen::FrozenLinkedVec::try_thaw(frozen).unwrap_err();
+    drop(shared);
+    let mut list = frozen::FrozenLinkedVec::try_thaw(frozen).unwrap();
+    list.push_back(100);
+    check_links(&list);
+    assert_eq!(list.len(), 101);
+}
+
+#[test]
+fn insert_and_remove_l() {
+    let mut list: LinkedVec<u32, u8> = LinkedVec::new();
+    list.insert_l(0, 1);
+    list.insert_l(0, 0);
+    list.insert_l(2, 3);
+    list.insert_l(2, 2);
+    check_links(&list);
+    assert!(list.iter().copied().eq(0..4));
+
+    assert_eq!(list.remove_l(2), 2);
+    assert_eq!(list.remove_l(0), 0);
+    check_links(&list);
+    assert!(list.iter().eq(&[1, 3]));
+}
+
+#[test]
+#[should_panic(expected = "index (is 3) should be < or <= len (is 2)")]
+fn insert_l_out_of_bounds() {
+    let mut list: LinkedVec<u32> = (0..2).collect();
+    list.insert_l(3, 0);
+}
+
+#[cfg(feature = "ops")]
+#[test]
+fn replay_ops() {
+    use ops::Op;
+    use std::collections::VecDeque;
+
+    let ops = [
+        Op::PushBack(1),
+        Op::PushFront(0),
+        Op::InsertL(1, 5),
+        Op::PushBack(2),
+        Op::RemoveL(1),
+        Op::InsertL(3, 3),
+        Op::PopFront,
+        Op::PushFront(0),
+        Op::PopBack,
+    ];
+    let mut list: LinkedVec<u32> = LinkedVec::new();
+    let mut model = VecDeque::new();
+    for op in ops.iter().cloned() {
+        let expected = match op.clone() {
+            Op::PushFront(x) => {
+                model.push_front(x);
+                None
+            }
+            Op::PushBack(x) => {
+                model.push_back(x);
+                None
+            }
+            Op::PopFront => model.pop_front(),
+            Op::PopBack => model.pop_back(),
+            Op::InsertL(i, x) => {
+                model.insert(i, x);
+                None
+            }
+            Op::RemoveL(i) => model.remove(i),
+            _ => unreachable!(),
+        };
+        assert_eq!(list.apply(op), expected);
+        check_links(&list);
+        assert!(list.iter().eq(model.iter()));
+    }
+
+    let mut replayed: LinkedVec<u32> = LinkedVec::new();
+    replayed.apply_all(ops);
+    assert!(IterP::new(&replayed).eq(IterP::new(&list)));
+    replayed.apply_all([Op::SwapRemove(0), Op::Truncate(1), Op::Clear]);
+    assert!(replayed.is_empty());
+}
+
+#[test]
+fn indices_l() {
+    let mut list: LinkedVec<u32> = (0..10).collect();
+    list.extend_front([10, 11]);
+    let mut iter = list.indices_l();
+    assert_eq!(iter.len(), 12);
+    assert_eq!(iter.next(), Some(10));
+    assert_eq!(iter.next_back(), Some(9));
+    assert_eq!(iter.len(), 10);
+    let rest = iter;
+    assert_eq!(iter.by_ref().count(), 10);
+    assert_eq!(iter.next(), None);
+    assert_eq!(rest.len(), 10);
+
+    // Remove the odd elements, from the highest physical index down
+    let mut odd: Vec<usize> = list
+        .indices_l()
+        .filter(|&p| list.index_p(p) % 2 == 1)
+        .collect();
+    odd.sort_unstable_by(|a, b| b.cmp(a));
+    for p in odd {
+        assert_eq!(list.swap_remove(p) % 2, 1);
+    }
+    check_links(&list);
+    assert!(list.iter().eq(&[10, 0, 2, 4, 6, 8]));
+}
+
+#[test]
+fn get_l_and_finger() {
+    let mut list: LinkedVec<u32> = (5..10).collect();
+    list.extend_front(0..5);
+    assert_eq!(list.get_l(0), Some(&0));
+    assert_eq!(list.get_l(7), Some(&7));
+    assert_eq!(list.get_l(10), None);
+    *list.get_l_mut(3).unwrap() = 30;
+    assert_eq!(list.get_l(3), Some(&30));
+
+    let mut finger = list.finger();
+    for i in (0..10).chain((0..10).rev()) {
+        assert_eq!(finger.get_l(i), list.iter().nth(i));
+    }
+    assert_eq!(finger.index_l_to_p(0), Some(5));
+    assert_eq!(finger.index_l_to_p(9), Some(4));
+    assert_eq!(finger.get_l(10), None);
+    assert_eq!(finger.get_l(4), Some(&4));
+}
+
+#[test]
+fn nth_front_and_back() {
+    let mut list: LinkedVec<u32> = (2..5).collect();
+    list.extend_front(0..2);
+    assert_eq!(list.nth_front(1), Some(&1));
+    assert_eq!(list.nth_back(0), Some(&4));
+    assert_eq!(list.nth_back(4), Some(&0));
+    assert_eq!(list.nth_back(5), None);
+    assert_eq!(list.nth_back(usize::MAX), None);
+    assert_eq!(list.nth_front(5), None);
+    *list.nth_back_mut(1).unwrap() += 10;
+    *list.nth_front_mut(0).unwrap() += 10;
+    assert!(list.iter().eq(&[10, 1, 2, 13, 4]));
+}
+
+#[test]
+fn indexed_linked_vec() {
+    use indexed::IndexedLinkedVec;
+    use rand_xoshiro::rand_core::{RngCore, SeedableRng};
+    use std::collections::VecDeque;
+
+    let mut rng = rand_xoshiro::Xoroshiro128StarStar::seed_from_u64(601);
+    let mut list: IndexedLinkedVec<u32, u16> = IndexedLinkedVec::new();
+    let mut model = VecDeque::new();
+    let rounds = testkit::miri_scaled(3000);
+    for round in 0..rounds {
+        let r = rng.next_u32();
+        // Grow for a while, then shrink
+        let grow = if round < rounds * 2 / 3 { 3 } else { 1 };
+        match r % 5 {
+            0 => {
+                list.push_front(r);
+                model.push_front(r);
+            }
+            x if x <= grow => {
+                let i = r as usize % (model.len() + 1);
+                list.insert_l(i, r);
+                model.insert(i, r);
+            }
+            _ if !model.is_empty() => {
+                let i = r as usize % model.len();
+                assert_eq!(list.remove_l(i), model.remove(i).unwrap());
+            }
+            _ => {}
+        }
+        let i = r as usize % (model.len() + 1);
+        assert_eq!(list.get_l(i), model.get(i));
+    }
+    check_links(&list);
+    assert!(list.iter().eq(model.iter()));
+    for (i, x) in model.iter().enumerate() {
+        assert_eq!(list.get_l(i), Some(x));
+    }
+
+    *list.get_l_mut(0).unwrap() = 7;
+    assert_eq!(list.front(), Some(&7));
+    while list.pop_back().is_some() {}
+    assert!(list.is_empty());
+    list.push_back(1);
+    assert_eq!(list.into_inner().pop_front(), Some(1));
+}
+
+#[test]
+fn positioned_linked_vec() {
+    use positioned::PositionedLinkedVec;
+    use rand_xoshiro::rand_core::{RngCore, SeedableRng};
+    use std::collections::VecDeque;
+
+    let mut rng = rand_xoshiro::Xoroshiro128StarStar::seed_from_u64(655);
+    let mut list: PositionedLinkedVec<u32, u16> = PositionedLinkedVec::new();
+    let mut model = VecDeque::new();
+    let rounds = testkit::miri_scaled(2000);
+    for round in 0..rounds {
+        let r = rng.next_u32();
+        let grow = if round < rounds * 3 / 5 { 4 } else { 2 };
+        match r % 7 {
+            0 => {
+                list.push_front(r);
+                model.push_front(r);
+            }
+            1 => {
+                list.push_back(r);
+                model.push_back(r);
+            }
+            x if x <= grow => {
+                let i = r as usize % (model.len() + 1);
+                list.insert_l(i, r);
+                model.insert(i, r);
+            }
+            5 if !model.is_empty() => {
+                let p = r as usize % model.len();
+                let l = list.logical_index_of_p(p).unwrap();
+                assert_eq!(list.remove_p(p), model.remove(l).unwrap());
+            }
+            6 => assert_eq!(list.pop_back(), model.pop_back()),
+            _ if !model.is_empty() => {
+                let i = r as usize % model.len();
+                assert_eq!(list.remove_l(i), model.remove(i).unwrap());
+            }
+            _ => assert_eq!(list.pop_front(), None),
+        }
+        if round % 50 == 0 {
+            for (l, p) in list.indices_l().enumerate() {
+                assert_eq!(list.logical_index_of_p(p), Some(l));
+            }
+        }
+    }
+    check_links(&list);
+    assert!(list.iter().eq(model.iter()));
+    for (l, p) in list.indices_l().enumerate() {
+        assert_eq!(list.logical_index_of_p(p), Some(l));
+    }
+    assert_eq!(list.logical_index_of_p(list.len()), None);
+
+    let mut list = PositionedLinkedVec::from(list.into_inner());
+    *list.get_p_mut(0).unwrap() = 7;
+    assert_eq!(list.index_p(0), &7);
+    list.clear();
+    assert_eq!(list.logical_index_of_p(0), None);
+}
+
+#[test]
+fn remove_batch() {
+    let mut list: LinkedVec<u32, u8> = (0..10).collect();
+    list.swap_p(0, 9);
+    list.extend_front([10, 11]);
+    let removed = list.remove_batch_p(&[0, 10, 5]);
+    assert_eq!(removed, [9, 10, 5]);
+    check_links(&list);
+    assert!(list.iter().eq(&[11, 1, 2, 3, 4, 6, 7, 8, 0]));
+    // The survivors keep their relative physical order
+    assert!(list
+        .iter_with_p()
+        .map(|(p, _)| p)
+        .eq([8, 0, 1, 2, 3, 4, 5, 6, 7]));
+
+    let removed = list.remove_batch_l(&[8, 0, 3]);
+    assert_eq!(removed, [0, 11, 3]);
+    check_links(&list);
+    assert!(list.iter().eq(&[1, 2, 4, 6, 7, 8]));
+
+    assert!(list.remove_batch_l(&[]).is_empty());
+    let all: Vec<_> = (0..list.len()).collect();
+    assert_eq!(list.remove_batch_p(&all).len(), 6);
+    assert!(list.is_empty());
+    check_links(&list);
+}
+
+#[test]
+#[should_panic(expected = "logical index 1 appears more than once")]
+fn remove_batch_l_duplicate() {
+    let mut list: LinkedVec<u32> = (0..3).collect();
+    list.remove_batch_l(&[1, 2, 1]);
+}
+
+#[test]
+fn pop_n() {
+    let mut list: LinkedVec<u32, u8> = (5..10).collect();
+    list.extend_front(0..5);
+
+    let front = list.pop_front_n(3);
+    check_links(&front);
+    check_links(&list);
+    assert!(front.iter().eq(&[0, 1, 2]));
+    assert!(front.indices_l().eq(0..3));
+    let back = list.pop_back_n(2);
+    check_links(&back);
+    check_links(&list);
+    assert!(back.iter().eq(&[8, 9]));
+    assert!(list.iter().eq(&[3, 4, 5, 6, 7]));
+
+    assert!(list.pop_back_n(0).is_empty());
+    assert_eq!(list.len(), 5);
+    let rest = list.pop_front_n(10);
+    assert!(rest.iter().eq(&[3, 4, 5, 6, 7]));
+    assert!(rest.indices_l().eq(0..5));
+    assert!(list.is_empty());
+
+    list.extend([3, 4]);
+    list.extend_front(0..3);
+    let all = list.pop_front_n(5);
+    assert!(all.iter().eq(&[0, 1, 2, 3, 4]));
+    assert!(all.indices_l().eq(0..5));
+}
+
+#[test]
+fn physical_ends() {
+    let mut list: LinkedVec<u32> = LinkedVec::new();
+    assert_eq!(list.front_p(), None);
+    assert_eq!(list.back_p_mut(), None);
+    list.extend(1..3);
+    list.push_front(0);
+    assert_eq!(list.front(), Some(&0));
+    assert_eq!(list.front_p(), Some(&1));
+    assert_eq!(list.back(), Some(&2));
+    assert_eq!(list.back_p(), Some(&0));
+
+    *list.front_p_mut().unwrap() = 10;
+    *list.back_p_mut().unwrap() = 20;
+    assert!(list.iter().eq(&[20, 10, 2]));
+    assert_eq!(list.pop(), Some(20));
+}
+
+#[test]
+fn swap_with_vec() {
+    let mut list: LinkedVec<String, u8> = LinkedVec::new();
+    for s in ["c", "d"] {
+        list.push_back(s.to_owned());
+    }
+    for s in ["b", "a"] {
+        list.push_front(s.to_owned());
+    }
+
+    let mut vec = Vec::with_capacity(10);
+    vec.extend(["x", "y", "z"].map(str::to_owned));
+    let ptr = vec.as_ptr();
+    let old = list.swap_with_vec(vec);
+    assert_eq!(old, ["a", "b", "c", "d"]);
+    assert_eq!(old.as_ptr(), ptr);
+    check_links(&list);
+    assert!(list.iter().eq(&["x", "y", "z"]));
+    assert!(list.indices_l().eq(0..3));
+
+    assert_eq!(list.swap_with_vec(Vec::new()), ["x", "y", "z"]);
+    assert!(list.is_empty());
+}
+
+#[test]
+fn boxed_and_leaked() {
+    let mut list: LinkedVec<u32, u16> = LinkedVec::new();
+    list.try_reserve(100).unwrap();
+    list.extend(1..4);
+    list.push_front(0);
+    let (nodes, head, tail) = list.clone().into_boxed_parts();
+    assert_eq!(nodes.len(), 4);
+    assert_eq!((head, tail), (Some(3), Some(2)));
+    assert_eq!(nodes[3].next, Some(0));
+
+    let leaked: slice::LinkedSlice<'static, u32, u16> = list.leak();
+    assert_eq!(leaked.len(), 4);
+    assert!(leaked.iter().copied().eq(0..4));
+    assert!(leaked.iter().rev().copied().eq((0..4).rev()));
+    assert_eq!(leaked.iter().skip(1).len(), 3);
+    assert_eq!(leaked.iter().nth_back(1), Some(&2));
+    assert_eq!(format!("{leaked:?}"), "[0, 1, 2, 3]");
+    assert_eq!(format!("{:?}", leaked.iter()), "Iter([0, 1, 2, 3])");
+    let mut leaked = leaked;
+    *leaked.front_mut().unwrap() = 10;
+    *leaked.get_p_mut(0).unwrap() = 11;
+    assert_eq!((leaked.front(), leaked.back()), (Some(&10), Some(&3)));
+    assert_eq!(leaked.get_p(0), Some(&11));
+}
+
+#[test]
+fn memory_usage() {
+    let node = mem::size_of::<VecNode<u64, u32>>();
+    let mut list: LinkedVec<u64, u32> = LinkedVec::new();
+    assert_eq!((list.allocated_bytes(), list.used_bytes()), (0, 0));
+    list.try_reserve(10).unwrap();
+    list.extend(0..4);
+    assert_eq!(list.allocated_bytes(), list.capacity() * node);
+    assert!(list.capacity() >= 10);
+    assert_eq!(list.used_bytes(), 4 * node);
+
+    let frozen = list.clone().freeze();
+    assert_eq!(
+        frozen.allocated_bytes(),
+        frozen.capacity() * node + 4 * mem::size_of::<u32>()
+    );
+    let indexed = indexed::IndexedLinkedVec::from(list);
+    assert!(indexed.allocated_bytes() > indexed.used_bytes());
+
+    let mut small: small::SmallLinkedVec<u64, 2, u32> = (0..2).collect();
+    assert_eq!(small.allocated_bytes(), 0);
+    small.push_back(2);
+    assert!(small.allocated_bytes() >= 3 * node);
+}
+
+#[test]
+fn cursor_ops() {
+    use iterators::CursorOps;
+
+    /// Sums the elements from the cursor to the back, peeking ahead.
+    fn sum_forward<C: CursorOps<Item = u32>>(mut cursor: C) -> (u32, Option<usize>) {
+        let mut sum = 0;
+        let start = cursor.index_p();
+        while let Some(&x) = cursor.peek_current() {
+            sum += x;
+            if cursor.peek_next().is_none() || cursor.index_l() == Some(3) {
+                break;
+            }
+            cursor.move_next();
+        }
+        (sum, start)
+    }
+
+    let mut list: LinkedVec<u32> = (1..5).collect();
+    list.push_front(0);
+    assert_eq!(sum_forward(list.cursor_front()), (6, Some(4)));
+    assert_eq!(
+        sum_forward(list.cursor_front().as_nonempty_cursor().unwrap()),
+        (6, Some(4))
+    );
+    assert_eq!(sum_forward(list.cursor_front_mut()), (6, Some(4)));
+
+    let mut cursor = list.cursor_back().as_nonempty_cursor().unwrap();
+    assert_eq!(CursorOps::peek_next(&cursor), Some(&0));
+    CursorOps::move_prev(&mut cursor);
+    assert_eq!(CursorOps::peek_prev(&cursor), Some(&2));
+    assert_eq!(CursorOps::index_l(&cursor), Some(3));
+    let cursor = list.cursor_front();
+    assert_eq!(cursor.peek_current(), Some(&0));
+    assert_eq!(CursorOps::peek_prev(&cursor), None);
+    let mut cursor = list.cursor_front_mut();
+    CursorOps::move_prev(&mut cursor);
+    assert_eq!(CursorOps::index_p(&cursor), None);
+    assert_eq!(cursor.peek_prev(), Some(&4));
+    assert_eq!(cursor.peek_current(), None);
+    *VecCursorMut::peek_next(&mut cursor).unwrap() = 10;
+    assert_eq!(CursorOps::peek_next(&cursor), Some(&10));
+}
+
+#[test]
+fn nonempty_cursor_wraps() {
+    let mut list: LinkedVec<u32> = (1..3).collect();
+    list.push_front(0);
+    let mut cursor = list.cursor_back().as_nonempty_cursor().unwrap();
+    assert_eq!((cursor.index_l(), *cursor.current()), (2, 2));
+    assert!(!cursor.move_next());
+    assert_eq!(
+        (cursor.index_l(), cursor.index_p(), *cursor.current()),
+        (0, 2, 0)
+    );
+    assert!(cursor.move_next());
+    assert_eq!((cursor.index_l(), *cursor.current()), (1, 1));
+    assert!(cursor.move_prev());
+    assert!(!cursor.move_prev());
+    assert_eq!(
+        (cursor.index_l(), cursor.index_p(), *cursor.current()),
+        (2, 1, 2)
+    );
+}
+
+#[test]
+fn cursor_mut_borrows() {
+    fn bump(mut cursor: VecCursorMut<'_, u32, usize>) {
+        cursor.move_next();
+        *cursor.current().unwrap() += 100;
+    }
+
+    let mut list: LinkedVec<u32> = (1..4).collect();
+    list.push_front(0);
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_next();
+    let (prev, current, next) = cursor.current_with_neighbors();
+    let current = current.unwrap();
+    *current += prev.unwrap() + next.unwrap();
+    assert_eq!(*current, 3);
+
+    bump(cursor.reborrow());
+    assert_eq!(cursor.index_l(), Some(1));
+    cursor.move_prev();
+    cursor.move_prev();
+    assert_eq!(cursor.current_with_neighbors(), (Some(&3), None, Some(&0)));
+    cursor.move_prev();
+    let last = cursor.into_current().unwrap();
+    *last = 30;
+    assert!(list.iter().eq(&[0, 3, 102, 30]));
+}
+
+#[test]
+fn cursor_peek_nth() {
+    let mut list: LinkedVec<u32> = (2..5).collect();
+    list.extend_front(0..2);
+    let mut cursor = list.cursor_front();
+    cursor.move_next();
+    assert_eq!(cursor.peek_nth(0), cursor.peek_next());
+    assert_eq!(cursor.peek_nth(2), Some(&4));
+    assert_eq!(cursor.peek_nth(3), None);
+    assert_eq!(cursor.peek_prev_nth(0), Some(&0));
+    assert_eq!(cursor.peek_prev_nth(1), None);
+    assert_eq!(cursor.index_l(), Some(1));
+
+    cursor.move_prev();
+    cursor.move_prev();
+    assert_eq!(cursor.peek_nth(4), Some(&4));
+    assert_eq!(cursor.peek_nth(5), None);
+    assert_eq!(cursor.peek_prev_nth(1), Some(&3));
+
+    let mut cursor = list.cursor_back_mut();
+    *cursor.peek_prev_nth(1).unwrap() = 20;
+    assert_eq!(cursor.peek_nth(0), None);
+    assert!(list.iter().eq(&[0, 1, 20, 3, 4]));
+}
+
+#[test]
+fn cursor_tokens() {
+    let mut list: LinkedVec<u32> = (0..5).collect();
+    let mut cursor = list.cursor_front();
+    cursor.move_next();
+    cursor.move_next();
+    let token = cursor.save();
+    assert_eq!(token.index_l(), Some(2));
+
+    *list.get_p_mut(0).unwrap() = 10;
+    let cursor = list.resume(token).unwrap();
+    assert_eq!(cursor.current(), Some(&2));
+    assert_eq!(cursor.index_l(), Some(2));
+
+    list.push_front(20);
+    assert!(list.resume(token).is_none());
+    assert!(list.resume_mut(token).is_none());
+    let cursor = list.reanchor(token);
+    assert_eq!(cursor.current(), Some(&1));
+    assert_eq!(cursor.index_l(), Some(2));
+
+    let mut cursor = list.cursor_back_mut();
+    cursor.move_next();
+    let token = cursor.save();
+    assert_eq!(token.index_l(), None);
+    assert!(list.resume_mut(token).unwrap().current().is_none());
+
+    let cursor = list.cursor_back_mut();
+    let token = cursor.save();
+    list.truncate(3);
+    let cursor = list.reanchor(token);
+    assert_eq!(cursor.index_l(), None);
+    assert_eq!(cursor.peek_next(), Some(&20));
+
+    let token = list.cursor_front().save();
+    let mut other = list.split_off(1);
+    assert!(list.resume(token).is_none());
+    list.append(&mut other);
+    assert!(other.resume(token).is_none());
+}
+
+#[test]
+fn version() {
+    let mut list: LinkedVec<u32> = LinkedVec::new();
+    let mut last = list.version();
+    let mut changed = |list: &LinkedVec<u32>| {
+        let changed = list.version() != last;
+        last = list.version();
+        changed
+    };
+
+    list.push_back(1);
+    assert!(changed(&list));
+    list.extend(2..5);
+    assert!(changed(&list));
+    *list.front_mut().unwrap() = 0;
+    list.swap_p(0, 1);
+    list.map_in_place(|x| *x += 1);
+    _ = list.try_reserve(10);
+    assert!(!changed(&list));
+    list.truncate(10);
+    assert!(!changed(&list));
+    list.swap_remove(0);
+    assert!(changed(&list));
+    list.insert_l(1, 7);
+    assert!(changed(&list));
+    drop(list.pop_front_n(4));
+    assert!(changed(&list));
+    list.copy_from(&(0..3).collect::<LinkedVec<u32>>());
+    assert!(changed(&list));
+    drop(list.split_off(1));
+    assert!(changed(&list));
+    drop(list.swap_with_vec(alloc::vec![5, 6]));
+    assert!(changed(&list));
+    *list.cursor_front_mut().current().unwrap() = 3;
+    assert!(!changed(&list));
+}
+
+#[test]
+fn extract_if_after() {
+    let mut list: LinkedVec<u32> = (0..8).collect();
+    // Put the current element last on the physical array
+    list.swap_remove(3);
+    list.insert_l(3, 3);
+    let mut cursor = list.find_cursor_mut(|&x| x == 3).unwrap();
+    let removed: Vec<u32> = cursor.extract_if_after(|x| *x % 2 == 0).collect();
+    assert_eq!(removed, [4, 6]);
+    assert_eq!(cursor.current(), Some(&mut 3));
+    assert_eq!(cursor.index_l(), Some(3));
+    assert_eq!(cursor.peek_next(), Some(&mut 5));
+    assert!(list.iter().eq(&[0, 1, 2, 3, 5, 7]));
+    check_links(&list);
+
+    let mut cursor = list.cursor_back_mut();
+    assert_eq!(cursor.extract_if_after(|_| true).count(), 0);
+    cursor.move_next();
+    let removed: Vec<u32> = cursor.extract_if_after(|x| *x < 2).collect();
+    assert_eq!(removed, [0, 1]);
+    assert!(list.iter().eq(&[2, 3, 5, 7]));
+}
+
+#[test]
+fn retain_with_index() {
+    let mut list: LinkedVec<u32> = (3..10).collect();
+    list.extend_front(0..3);
+    list.retain_with_index(|i, x| {
+        *x += 10;
+        i % 3 == 0
+    });
+    assert!(list.iter().eq(&[10, 13, 16, 19]));
+    check_links(&list);
+
+    let mut budget = 25;
+    list.retain_with_index(|_, &mut x| {
+        let keep = budget >= x;
+        budget = budget.saturating_sub(x);
+        keep
+    });
+    assert!(list.iter().eq(&[10, 13]));
+}
+
+#[test]
+fn windows_and_chunks() {
+    let mut list: LinkedVec<u32> = (2..6).collect();
+    list.extend_front(0..2);
+    let windows: Vec<Vec<u32>> = list.windows(3).map(|w| w.copied().collect()).collect();
+    assert_eq!(windows, [[0, 1, 2], [1, 2, 3], [2, 3, 4], [3, 4, 5]]);
+    assert_eq!(list.windows(6).len(), 1);
+    assert_eq!(list.windows(7).len(), 0);
+    assert_eq!(
+        list.windows(2).map(|mut w| w.next_back().unwrap()).max(),
+        Some(&5)
+    );
+
+    let chunks: Vec<Vec<u32>> = list.chunks(4).map(|c| c.copied().collect()).collect();
+    assert_eq!(chunks, [alloc::vec![0, 1, 2, 3], alloc::vec![4, 5]]);
+    assert_eq!(list.chunks(2).len(), 3);
+    assert_eq!(list.chunks(7).len(), 1);
+    assert_eq!(LinkedVec::<u32>::new().chunks(1).len(), 0);
+    assert_eq!(LinkedVec::<u32>::new().windows(1).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "window size must be non-zero")]
+fn windows_zero() {
+    let list: LinkedVec<u32> = (0..3).collect();
+    _ = list.windows(0);
+}
+
+#[test]
+fn array_chunks() {
+    let mut list: LinkedVec<u32> = (3..8).collect();
+    list.extend_front(0..3);
+    list.push_back(8);
+    let chunks = list.array_chunks::<2>();
+    assert_eq!(chunks.len(), 4);
+    assert!(chunks.remainder().eq(&[8]));
+    let sums: Vec<u32> = chunks.map(|[a, b]| a + b).collect();
+    assert_eq!(sums, [1, 5, 9, 13]);
+    assert_eq!(list.array_chunks::<3>().last(), Some([&6, &7, &8]));
+    assert_eq!(list.array_chunks::<10>().remainder().count(), 9);
+
+    let mut chunks = list.into_array_chunks::<4>();
+    assert_eq!(chunks.next(), Some([0, 1, 2, 3]));
+    assert_eq!(chunks.next(), Some([4, 5, 6, 7]));
+    assert_eq!(chunks.next(), None);
+    assert!(chunks.into_remainder().iter().eq(&[8]));
+}
+
+#[test]
+fn sum_lists() {
+    let lists: Vec<LinkedVec<u32>> =
+        alloc::vec![(0..2).collect(), LinkedVec::new(), (2..5).collect()];
+    let cloned: LinkedVec<u32> = lists.iter().sum();
+    assert!(cloned.iter().eq(&[0, 1, 2, 3, 4]));
+    let mut lists = lists.into_iter();
+    let mut front = lists.next().unwrap();
+    front.push_front(9);
+    let moved: LinkedVec<u32> = core::iter::once(front).chain(lists).sum();
+    assert!(moved.iter().eq(&[9, 0, 1, 2, 3, 4]));
+    check_links(&moved);
+}
+
+#[test]
+fn collect_references() {
+    let words = [String::from("a"), String::from("b")];
+    let mut list: LinkedVec<String> = words.iter().collect();
+    list.extend(&words[..1]);
+    assert!(list.iter().eq(["a", "b", "a"].iter()));
+}
+
+#[test]
+fn insert_p() {
+    let mut list: LinkedVec<u32> = (2..4).collect();
+    list.extend_front(0..2);
+    list.insert_p(1, 4);
+    list.insert_p(0, 5);
+    list.insert_p(list.len(), 6);
+    assert!(list.iter().eq(&[0, 1, 2, 3, 4, 5, 6]));
+    assert_eq!(
+        list.data.iter().map(|x| x.payload).collect::<Vec<_>>(),
+        [5, 2, 4, 3, 0, 1, 6]
+    );
+    check_links(&list);
+}
+
+#[test]
+#[should_panic(expected = "index (is 3) should be < or <= len (is 2)")]
+fn insert_p_out_of_bounds() {
+    let mut list: LinkedVec<u32> = (0..2).collect();
+    list.insert_p(3, 0);
+}
+
+#[test]
+fn remove_p() {
+    let mut list: LinkedVec<u32> = (2..5).collect();
+    list.extend_front(0..2);
+    assert_eq!(list.remove_p(1), 3);
+    assert_eq!(list.remove_p(0), 2);
+    assert!(list.iter().eq(&[0, 1, 4]));
+    assert_eq!(
+        list.data.iter().map(|x| x.payload).collect::<Vec<_>>(),
+        [4, 0, 1]
+    );
+    check_links(&list);
+    assert_eq!(list.remove_p(2), 1);
+    assert_eq!(list.remove_p(0), 4);
+    assert_eq!(list.remove_p(0), 0);
+    assert!(list.is_empty());
+    check_links(&list);
+}
+
+#[test]
+fn truncate_p() {
+    let mut list: LinkedVec<u32> = (0..3).collect();
+    let len = list.len();
+    list.push_front(3);
+    list.insert_l(2, 4);
+    list.push_back(5);
+    list.truncate_p(len);
+    assert!(list.iter().eq(&[0, 1, 2]));
+    check_links(&list);
+    list.truncate_p(5);
+    assert_eq!(list.len(), 3);
+    list.truncate_p(0);
+    assert!(list.is_empty());
+    check_links(&list);
+}
+
+#[test]
+fn push_guard() {
+    let mut list: LinkedVec<u32> = (0..3).collect();
+    let mut guard = list.push_guard();
+    guard.push_back(3);
+    guard.push_front(4);
+    drop(guard);
+    assert!(list.iter().eq(&[0, 1, 2]));
+    check_links(&list);
+
+    let mut guard = list.push_guard();
+    guard.push_back(3);
+    guard.rollback();
+    guard.push_back(5);
+    guard.commit();
+    assert!(list.iter().eq(&[0, 1, 2, 5]));
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let mut guard = list.push_guard();
+        guard.extend_front(6..8);
+        panic!("parse error");
+    }));
+    assert!(result.is_err());
+    assert!(list.iter().eq(&[0, 1, 2, 5]));
+    check_links(&list);
+}
+
+#[test]
+fn cursor_insert_and_remove() {
+    let mut list: LinkedVec<u32> = (1..4).collect();
+    let mut cursor = list.cursor_front_mut();
+    cursor.insert_before(0);
+    cursor.insert_after(5);
+    assert_eq!(cursor.index_l(), Some(1));
+    assert_eq!(cursor.remove_current(), Some(1));
+    assert_eq!(cursor.current(), Some(&mut 5));
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!(cursor.remove_current(), Some(3));
+    assert_eq!(cursor.index_l(), None);
+    assert_eq!(cursor.remove_current(), None);
+    cursor.insert_after(6);
+    cursor.insert_before(7);
+    assert!(list.iter().eq(&[6, 0, 5, 2, 7]));
+    check_links(&list);
+}
+
+#[test]
+fn split_into() {
+    let mut list: LinkedVec<u32> = LinkedVec::new();
+    for x in [3, 4, 5] {
+        list.push_back(x);
+    }
+    for x in [2, 1, 0] {
+        list.push_front(x);
+    }
+    let mut out: LinkedVec<u32> = (0..8).collect();
+
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_next();
+    cursor.move_next();
+    cursor.split_after_into(&mut out);
+    assert_eq!(cursor.current(), Some(&mut 2));
+    assert_eq!(cursor.index_l(), Some(2));
+    cursor.move_prev();
+    cursor.split_before_into(&mut out);
+    assert_eq!(cursor.current(), Some(&mut 1));
+    assert_eq!(cursor.index_l(), Some(0));
+    assert!(list.iter().eq(&[1, 2]));
+    assert!(out.iter().eq(&[0]));
+    assert!(out.capacity() >= 8);
+    check_links(&list);
+
+    let capacity = list.capacity();
+    let mut cursor = list.cursor_back_mut();
+    cursor.move_next();
+    cursor.split_after_into(&mut out);
+    assert!(list.is_empty());
+    assert_eq!(list.capacity(), capacity);
+    assert!(out.iter().eq(&[1, 2]));
+    assert!(out.capacity() >= 8);
+    check_links(&out);
+
+    // The whole list is moved in logical order
+    list.extend([5, 6]);
+    list.push_front(4);
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_prev();
+    cursor.split_before_into(&mut out);
+    assert!(list.is_empty());
+    assert!(out.iter_unordered().eq(&[4, 5, 6]));
+    assert!(out.capacity() >= 8);
+    check_links(&out);
+}
+
+#[test]
+fn entry_l() {
+    use entry::Entry;
+
+    let mut list: LinkedVec<u32> = (0..3).collect();
+    *list.entry_l(1).or_insert(9) += 10;
+    *list.entry_l(3).and_modify(|x| *x += 1).or_insert(3) += 10;
+    assert!(list.iter().eq(&[0, 11, 2, 13]));
+
+    match list.entry_l(2) {
+        Entry::Occupied(mut cursor) => {
+            cursor.insert_before(4);
+            assert_eq!(cursor.remove_current(), Some(2));
+        }
+        Entry::Vacant(_) => unreachable!(),
+    }
+    match list.entry_l(4) {
+        Entry::Occupied(_) => unreachable!(),
+        Entry::Vacant(point) => assert_eq!(point.insert(5).index_l(), Some(4)),
+    }
+    assert!(list.iter().eq(&[0, 11, 4, 13, 5]));
+    check_links(&list);
+}
+
+#[test]
+fn binary_search_p() {
+    let mut list: LinkedVec<u32> = [1, 3, 3, 5, 8].into_iter().collect();
+    assert!(list.is_contiguous());
+    assert_eq!(list.binary_search_p(&5), Ok(3));
+    assert!(matches!(list.binary_search_p(&3), Ok(1 | 2)));
+    assert_eq!(list.binary_search_p(&4), Err(3));
+    assert_eq!(list.binary_search_by_p(|x| x.cmp(&9)), Err(5));
+    assert_eq!(list.partition_point_p(|&x| x < 4), 3);
+
+    list.push_front(0);
+    assert!(!list.is_contiguous());
+    assert!(LinkedVec::<u32>::new().is_contiguous());
+}
+
+#[test]
+fn const_constructors() {
+    static EMPTY: LinkedVec<u32, u8> = LinkedVec::EMPTY;
+    const WIDE: LinkedVec<u32, u16> = LinkedVec::<u32>::with_index_type();
+    const { assert!(EMPTY.is_empty()) };
+    const TABLE: [LinkedVec<u32, u8>; 2] = [LinkedVec::EMPTY; 2];
+
+    assert!(EMPTY.is_empty());
+    assert!(WIDE.is_empty());
+    let mut table = TABLE;
+    table[1].push_back(1);
+    assert!(table[0].is_empty());
+    assert!(!table[1].is_empty());
+    table[1].clear();
+    assert!(table[1].is_empty());
+}
+
+/// Fails to compile if `$ty` implements `$trait`, by making the path to
+/// `some_item` ambiguous.
+macro_rules! assert_not_impl {
+    ($ty:ty: $trait:path) => {{
+        trait AmbiguousIfImpl<A> {
+            fn some_item() {}
+        }
+        impl<T: ?Sized> AmbiguousIfImpl<()> for T {}
+        impl<T: ?Sized + $trait> AmbiguousIfImpl<u8> for T {}
+        let _ = <$ty as AmbiguousIfImpl<_>>::some_item;
+    }};
+}
+
+#[test]
+fn auto_traits() {
+    use alloc::rc::Rc;
+    use core::cell::Cell;
+    use iterators::{Iter, IterMut, VecCursor, VecCursorMut};
+
+    fn send<T: Send>() {}
+    fn sync<T: Sync>() {}
+
+    // Everything is Send and Sync for Send and Sync elements
+    send::<LinkedVec<u32>>();
+    sync::<LinkedVec<u32>>();
+    send::<VecCursor<'_, u32, usize>>();
+    sync::<VecCursor<'_, u32, usize>>();
+    send::<VecCursorMut<'_, u32, usize>>();
+    sync::<VecCursorMut<'_, u32, usize>>();
+    send::<Iter<'_, u32, usize>>();
+    sync::<Iter<'_, u32, usize>>();
+    send::<IterMut<'_, u32, usize>>();
+    sync::<IterMut<'_, u32, usize>>();
+    send::<iterators::IntoIter<u32, usize>>();
+    sync::<iterators::IntoIter<u32, usize>>();
+    send::<frozen::FrozenLinkedVec<u32>>();
+    sync::<frozen::FrozenLinkedVec<u32>>();
+    send::<slice::LinkedSlice<'_, u32, usize>>();
+    sync::<slice::LinkedSlice<'_, u32, usize>>();
+    send::<array::ArrayLinkedVec<u32, 4>>();
+    sync::<array::ArrayLinkedVec<u32, 4>>();
+
+    // Owners and unique borrows are Send for Send elements
+    send::<LinkedVec<Cell<u32>>>();
+    send::<VecCursorMut<'_, Cell<u32>, usize>>();
+    send::<IterMut<'_, Cell<u32>, usize>>();
+    send::<iterators::IntoIter<Cell<u32>, usize>>();
+    send::<slice::LinkedSlice<'_, Cell<u32>, usize>>();
+    assert_not_impl!(LinkedVec<Cell<u32>>: Sync);
+    assert_not_impl!(VecCursorMut<'_, Cell<u32>, usize>: Sync);
+    assert_not_impl!(IterMut<'_, Cell<u32>, usize>: Sync);
+    assert_not_impl!(frozen::FrozenLinkedVec<Cell<u32>>: Sync);
+
+    // Shared borrows need Sync elements to be sent
+    assert_not_impl!(VecCursor<'_, Cell<u32>, usize>: Send);
+    assert_not_impl!(Iter<'_, Cell<u32>, usize>: Send);
+
+    assert_not_impl!(LinkedVec<Rc<u32>>: Send);
+    assert_not_impl!(LinkedVec<Rc<u32>>: Sync);
+    assert_not_impl!(IterMut<'_, Rc<u32>, usize>: Send);
+    assert_not_impl!(array::ArrayLinkedVec<Rc<u32>, 4>: Send);
+}
+
+#[test]
+fn fallible_methods() {
+    let mut list: LinkedVec<u32, u8> = LinkedVec::new();
+    for x in 0..=255 {
+        list.try_push_back(x).unwrap();
+    }
+    assert_eq!(list.try_push_front(7).unwrap_err().into_inner(), 7);
+    assert_eq!(
+        list.try_insert_l(3, 7).unwrap_err().into_parts(),
+        (Error::CapacityOverflow, 7)
+    );
+    list.clear();
+
+    list.try_push_front(1).unwrap();
+    list.try_insert_l(1, 3).unwrap();
+    list.try_insert_p(0, 2).unwrap();
+    let err = Error::IndexOutOfBounds { index: 4, len: 3 };
+    assert_eq!(list.try_insert_l(4, 7).unwrap_err().into_parts(), (err, 7));
+    assert_eq!(list.try_insert_p(4, 7).unwrap_err().into_parts(), (err, 7));
+    assert_eq!(list.try_remove_l(4), Err(err));
+    assert_eq!(list.try_remove_p(4), Err(err));
+    assert_eq!(list.try_swap_p(0, 4), Err(err));
+    assert_eq!(list.try_split_off(4).unwrap_err(), err);
+    assert!(list.iter().eq(&[1, 3, 2]));
+
+    list.try_swap_p(0, 1).unwrap();
+    assert_eq!(list.try_remove_p(0), Ok(1));
+    assert_eq!(list.try_remove_l(1), Ok(3));
+    assert!(list.try_split_off(0).unwrap().iter().eq(&[2]));
+    assert!(list.is_empty());
+}
+
+#[test]
+fn iterator_shortcuts() {
+    let mut list: LinkedVec<u32> = (4..10).collect();
+    list.extend_front(0..4);
+    let expected: Vec<u32> = (0..10).collect();
+
+    for n in 0..12 {
+        let mut iter = list.iter();
+        let mut model = expected.iter();
+        assert_eq!(iter.nth(n), model.nth(n));
+        assert_eq!(iter.nth_back(1), model.nth_back(1));
+        assert_eq!(iter.next(), model.next());
+        assert_eq!(iter.count(), model.clone().count());
+        assert_eq!(iter.last(), model.last());
+
+        let mut iter = list.indices_l();
+        let mut model = list.indices_l().collect::<Vec<_>>().into_iter();
+        assert_eq!(iter.nth_back(n), model.nth_back(n));
+        assert_eq!(iter.nth(2), model.nth(2));
+        assert_eq!(iter.next_back(), model.next_back());
+        assert_eq!(iter.count(), model.clone().count());
+        assert_eq!(iter.last(), model.last());
+    }
+
+    assert_eq!(list.clone().into_iter().last(), Some(9));
+    assert_eq!(list.into_iter().count(), 10);
+}
+
+#[test]
+fn nonempty_cursor_cycle() {
+    let mut list: LinkedVec<u32> = (2..5).collect();
+    list.extend_front(0..2);
+    let mut cursor = list.cursor_front();
+    cursor.move_next();
+    cursor.move_next();
+    cursor.move_next();
+    let cursor = cursor.as_nonempty_cursor().unwrap();
+
+    let mut seen = Vec::new();
+    for x in &cursor {
+        seen.push(*x);
+    }
+    assert_eq!(seen, [3, 4, 0, 1, 2]);
+    assert!(cursor.iter().rev().eq(&[2, 1, 0, 4, 3]));
+    let mut iter = cursor.iter();
+    assert_eq!(iter.next_back(), Some(&2));
+    assert_eq!(iter.len(), 4);
+    assert!(iter.eq(&[3, 4, 0, 1]));
+    assert_eq!(cursor.current(), &3);
+}
+
+#[test]
+fn raw_navigation() {
+    let mut list: LinkedVec<u32> = (2..5).collect();
+    list.extend_front(0..2);
+    assert_eq!(list.head_p(), Some(3));
+    assert_eq!(list.tail_p(), Some(2));
+
+    let mut forward = Vec::new();
+    let mut current = list.head_p();
+    while let Some(p) = current {
+        forward.push(list.data[p].payload);
+        // Safety: Links are always in bounds
+        current = unsafe { list.next_p_unchecked(p) };
+    }
+    assert_eq!(forward, [0, 1, 2, 3, 4]);
+    // Safety: 2 < len
+    assert_eq!(unsafe { list.prev_p_unchecked(2) }, Some(1));
+    // Safety: 3 < len
+    assert_eq!(unsafe { list.prev_p_unchecked(3) }, None);
+    assert_eq!(LinkedVec::<u32>::new().head_p(), None);
+}
+
+#[test]
+fn links_of() {
+    let mut list: LinkedVec<u32> = (2..5).collect();
+    list.extend_front(0..2);
+    assert_eq!(list.links_of(3), Some((None, Some(4))));
+    assert_eq!(list.links_of(0), Some((Some(4), Some(1))));
+    assert_eq!(list.links_of(2), Some((Some(1), None)));
+    assert_eq!(list.links_of(5), None);
+}
+
+#[cfg(feature = "dot")]
+#[test]
+fn debug_dot() {
+    let mut list: LinkedVec<&str> = ["b"].into_iter().collect();
+    list.push_front("\"a\"");
+    let mut dot = String::new();
+    list.debug_dot(&mut dot).unwrap();
+    assert_eq!(
+        dot,
+        r#"digraph LinkedVec {
+    node [shape=box];
+    head [shape=plaintext];
+    tail [shape=plaintext];
+    n0 [label="0: \"b\""];
+    n1 [label="1: \"\\\"a\\\"\""];
+    n0 -> n1 [style=dashed];
+    n1 -> n0;
+    head -> n1;
+    tail -> n0;
+}
+"#
+    );
+}
+
+#[test]
+fn sort() {
+    use rand_xoshiro::rand_core::{RngCore, SeedableRng};
+
+    let mut rng = rand_xoshiro::Xoroshiro128StarStar::seed_from_u64(646);
+    for len in [0, 1, 2, 5, 64, 300] {
+        let mut list: LinkedVec<(u32, usize)> = LinkedVec::new();
+        for i in 0..len {
+            let x = (rng.next_u32() % 16, i);
+            if rng.next_u32() % 2 == 0 {
+                list.push_back(x);
+            } else {
+                list.push_front(x);
+            }
+        }
+        let layout: Vec<_> = list.iter_unordered().copied().collect();
+        let mut model: Vec<_> = list.iter().copied().collect();
+        model.sort_by_key(|x| x.0);
+        list.sort_by_key(|x| x.0);
+        check_links(&list);
+        assert!(list.iter().eq(&model));
+        assert!(list.iter_unordered().eq(&layout));
+    }
+
+    // A sorted list is left alone
+    let mut list: LinkedVec<u32> = (0..10).collect();
+    let version = list.version();
+    list.sort();
+    assert_eq!(list.version(), version);
+    list.push_front(5);
+    list.sort();
+    assert!(list.iter().copied().eq([0, 1, 2, 3, 4, 5, 5, 6, 7, 8, 9]));
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        list.sort_by(|a, b| if *a == 7 { panic!() } else { b.cmp(a) })
+    }));
+    assert!(result.is_err());
+    check_links(&list);
+    assert_eq!(list.len(), 11);
+}
+
+#[test]
+fn sort_p() {
+    let mut list: LinkedVec<(u32, u32)> = [(2, 0), (1, 1), (2, 2)].into_iter().collect();
+    list.push_front((0, 3));
+    list.push_front((1, 4));
+    list.sort_p_by_key(|x| x.0);
+    check_links(&list);
+    assert!(list.is_contiguous());
+    // Ties keep their physical order
+    assert!(list.iter().eq(&[(0, 3), (1, 1), (1, 4), (2, 0), (2, 2)]));
+
+    let mut list: LinkedVec<u32> = (0..5).rev().collect();
+    list.sort_p();
+    assert!(list.iter().copied().eq(0..5));
+    assert!(list.iter_unordered().copied().eq(0..5));
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        list.sort_p_by(|a, b| if *a == 3 { panic!() } else { b.cmp(a) })
+    }));
+    assert!(result.is_err());
+    check_links(&list);
+    assert_eq!(list.len(), 5);
+}
+
+#[test]
+fn select_nth_l() {
+    let mut list: LinkedVec<u32> = [5, 1, 8, 3].into_iter().collect();
+    list.extend_front([9, 0, 7]);
+    assert_eq!(list.select_nth_l(3), Some(&mut 5));
+    check_links(&list);
+    let all: Vec<u32> = list.iter().copied().collect();
+    assert!(all[..3].iter().all(|&x| x <= 5));
+    assert!(all[4..].iter().all(|&x| x >= 5));
+
+    assert_eq!(list.select_nth_l_by(0, |a, b| b.cmp(a)), Some(&mut 9));
+    assert_eq!(list.front(), Some(&9));
+    assert_eq!(list.select_nth_l(7), None);
+    assert_eq!(list.len(), 7);
+}
+
+#[test]
+fn iter_unordered() {
+    let mut list: LinkedVec<u32> = (2..5).collect();
+    list.extend_front(0..2);
+    list.iter_unordered_mut().for_each(|x| *x *= 2);
+    assert_eq!(list.iter_unordered().len(), 5);
+    assert_eq!(list.iter_unordered().sum::<u32>(), 20);
+    let mut sorted: Vec<u32> = list.iter_unordered().copied().collect();
+    sorted.sort_unstable();
+    assert!(list.iter().eq(&sorted));
+
+    // The iterators are nameable, so they can be stored
+    struct Holder<'a>(iterators::IterUnordered<'a, u32, usize>);
+    let mut held = Holder(list.iter_unordered());
+    assert!(held.0.next_back().is_some());
+    assert_eq!(held.0.clone().len(), 4);
+    let mut iter: iterators::IterUnorderedMut<'_, u32, usize> = list.iter_unordered_mut();
+    *iter.nth(1).unwrap() += 1;
+    assert_eq!(list.iter_unordered().sum::<u32>(), 21);
+}
+
+#[test]
+fn contains() {
+    let mut list: LinkedVec<u32> = (2..5).collect();
+    list.extend_front(0..2);
+    assert!(list.contains(&0));
+    assert!(list.contains_ordered(&4));
+    assert!(!list.contains(&5));
+    assert!(!list.contains_ordered(&5));
+
+    assert_eq!(list.count_matching(|&x| x % 2 == 0), 3);
+    assert!(list.any_p(|&x| x > 3));
+    assert!(!list.any_p(|&x| x > 4));
+    assert!(list.all_p(|&x| x < 5));
+    assert!(!list.all_p(|&x| x > 0));
+    let mut visited = Vec::new();
+    assert!(list.any_p(|&x| {
+        visited.push(x);
+        x == 0
+    }));
+    assert_eq!(visited, [2, 3, 4, 0]);
+}
+
+#[test]
+fn linked_vec_map() {
+    use crate::map::LinkedVecMap;
+
+    let mut map: LinkedVecMap<String, u32> = [("a", 1), ("b", 2), ("c", 3)]
+        .map(|(k, v)| (k.to_string(), v))
+        .into_iter()
+        .collect();
+    assert_eq!(map.insert("b".to_string(), 20), Some(2));
+    assert_eq!(map.keys().collect::<Vec<_>>(), ["a", "b", "c"]);
+    assert_eq!(map.get("b"), Some(&20));
+
+    assert_eq!(map.get_refresh("a"), Some(&mut 1));
+    assert!(map.move_to_front("c"));
+    assert!(!map.move_to_back("z"));
+    assert_eq!(map.keys().collect::<Vec<_>>(), ["c", "b", "a"]);
+
+    // "a" is physically first, so removing it moves "c" into its slot.
+    assert_eq!(map.remove("a"), Some(1));
+    assert_eq!(map.keys().collect::<Vec<_>>(), ["c", "b"]);
+    assert_eq!(map.get("c"), Some(&3));
+    assert_eq!(map.pop_front(), Some(("c".to_string(), 3)));
+    assert_eq!(map.get("b"), Some(&20));
+    map.insert("d".to_string(), 4);
+    assert_eq!(format!("{map:?}"), r#"{"b": 20, "d": 4}"#);
+    assert_eq!(map.pop_back(), Some(("d".to_string(), 4)));
+    assert_eq!(map.len(), 1);
+    assert!(!map.contains_key("d"));
+}
+
+#[test]
+fn linked_vec_set() {
+    use crate::set::LinkedVecSet;
+
+    let mut set: LinkedVecSet<u32> = [3, 1, 2, 1].into_iter().collect();
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), [3, 1, 2]);
+    assert!(!set.insert(3));
+    assert_eq!(set.front(), Some(&3));
+    assert!(!set.insert_refresh(3));
+    assert!(set.insert_refresh(4));
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4]);
+
+    assert!(set.remove(&1));
+    assert!(!set.contains(&1));
+    assert!(set.move_to_front(&4));
+    assert_eq!(format!("{set:?}"), "{4, 2, 3}");
+    assert_eq!(set.pop_back(), Some(3));
+    assert_eq!(set.take(&4), Some(4));
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), [2]);
+}
+
+#[test]
+fn overflow_policy() {
+    let mut list: LinkedVec<usize, u8> = (0..=u8::MAX_USIZE).collect();
+    assert_eq!(list.overflow_policy(), OverflowPolicy::Error);
+    assert_eq!(list.try_push_back(0).unwrap_err().into_inner(), 0);
+    let result = catch_unwind(AssertUnwindSafe(|| list.push_front(0)));
+    assert!(result.is_err());
+
+    list.set_overflow_policy(OverflowPolicy::Evict(End::Front));
+    list.push_back(300);
+    list.try_push_back(301).unwrap();
+    check_links(&list);
+    assert_eq!(list.len(), u8::MAX_USIZE + 1);
+    assert_eq!(list.front(), Some(&2));
+    assert_eq!(list.back(), Some(&301));
+
+    list.set_overflow_policy(OverflowPolicy::Evict(End::Back));
+    list.push_front(302);
+    assert_eq!(list.front(), Some(&302));
+    assert_eq!(list.back(), Some(&300));
+    assert_eq!(
+        list.clone().overflow_policy(),
+        OverflowPolicy::Evict(End::Back)
+    );
+
+    list.set_overflow_policy(OverflowPolicy::Panic);
+    let result = catch_unwind(AssertUnwindSafe(|| list.try_push_back(0)));
+    assert!(result.is_err());
+    check_links(&list);
+}
+
+#[test]
+fn overflow_policy_kept_when_emptied() {
+    let policy = OverflowPolicy::Evict(End::Front);
+    let mut list: LinkedVec<u32, u8> = (0..4).collect();
+    list.set_overflow_policy(policy);
+
+    let popped = list.pop_front_n(2);
+    assert_eq!(list.overflow_policy(), policy);
+    assert_eq!(popped.overflow_policy(), OverflowPolicy::Error);
+    let popped = list.pop_back_n(2);
+    assert!(list.is_empty());
+    assert_eq!(list.overflow_policy(), policy);
+    assert_eq!(popped.overflow_policy(), OverflowPolicy::Error);
+
+    list.extend(0..4);
+    let popped = list.pop_front_n(4);
+    assert_eq!(list.overflow_policy(), policy);
+    assert_eq!(popped.overflow_policy(), OverflowPolicy::Error);
+
+    list.extend(0..4);
+    let second = list.split_off(0);
+    assert!(list.is_empty());
+    assert_eq!(list.overflow_policy(), policy);
+    assert_eq!(second.overflow_policy(), OverflowPolicy::Error);
+    list.extend(0..4);
+    let second = list.split_off(2);
+    assert_eq!(list.overflow_policy(), policy);
+    assert_eq!(second.overflow_policy(), OverflowPolicy::Error);
+}
+
+#[test]
+fn push_evicting() {
+    // Without a maximum, only overflowing `I` evicts
+    let mut list: LinkedVec<u32> = LinkedVec::new();
+    for i in 0..10 {
+        assert_eq!(list.push_back_evicting(i), None);
+    }
+    assert_eq!(list.len(), 10);
+
+    let mut list: LinkedVec<u32> = LinkedVec::new();
+    list.set_max_len(Some(6));
+    for i in 0..10 {
+        list.push_back_evicting(i);
+    }
+    assert_eq!(list.len(), 6);
+    assert!(list.iter().eq(&[4, 5, 6, 7, 8, 9]));
+    assert_eq!(list.push_front_evicting(11), Some(9));
+    assert_eq!(list.front(), Some(&11));
+    check_links(&list);
+
+    // The bound does not follow the capacity
+    let mut list: LinkedVec<u32> = LinkedVec::new();
+    list.set_max_len(Some(6));
+    list.try_reserve(3).unwrap();
+    list.extend(0..5);
+    assert_eq!(list.push_back_evicting(5), None);
+    assert_eq!(list.push_back_evicting(6), Some(0));
+    list.try_reserve(100).unwrap();
+    assert_eq!(list.push_back_evicting(7), Some(1));
+    assert_eq!(list.len(), 6);
+
+    let mut clone = list.clone();
+    assert_eq!(clone.max_len(), Some(6));
+    assert_eq!(clone.push_back_evicting(8), list.push_back_evicting(8));
+    assert!(clone.iter().eq(list.iter()));
+    assert_eq!(list.split_off(2).max_len(), None);
+    assert_eq!(list.max_len(), Some(6));
+
+    list.set_max_len(Some(0));
+    assert_eq!(list.push_front_evicting(9), Some(9));
+    assert_eq!(list.len(), 2);
+
+    let mut list: LinkedVec<usize, u8> = (0..=u8::MAX_USIZE).collect();
+    _ = list.data.try_reserve(10);
+    assert!(list.capacity() > list.len());
+    assert_eq!(list.push_back_evicting(300), Some(0));
+    assert_eq!(list.back(), Some(&300));
+    check_links(&list);
+}
+
+#[cfg(feature = "hashbrown")]
+#[test]
+fn dedup_all_by_key() {
+    let mut list: LinkedVec<i32> = [3, 1, -3, 2, 1, 4, -2, 3].into_iter().collect();
+    list.pop_front();
+    list.push_front(5);
+    list.dedup_all_by_key(|x| x.abs());
+    check_links(&list);
+    assert!(list.iter().eq(&[5, 1, -3, 2, 4]));
+
+    let mut empty: LinkedVec<i32> = LinkedVec::new();
+    empty.dedup_all_by_key(|x| *x);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn chunk_by() {
+    let mut list: LinkedVec<i32> = [2, 3, 3, 3, 1].into_iter().collect();
+    list.push_front(1);
+    list.push_front(1);
+    let runs: Vec<Vec<i32>> = list
+        .chunk_by(|a, b| a == b)
+        .map(|run| run.copied().collect())
+        .collect();
+    assert_eq!(
+        runs,
+        [
+            alloc::vec![1, 1],
+            alloc::vec![2],
+            alloc::vec![3, 3, 3],
+            alloc::vec![1]
+        ]
+    );
+    let runs: Vec<Vec<i32>> = list
+        .chunk_by(|a, b| a <= b)
+        .rev()
+        .map(|run| run.copied().collect())
+        .collect();
+    assert_eq!(runs, [alloc::vec![1], alloc::vec![1, 1, 2, 3, 3, 3]]);
+
+    let mut runs = list.chunk_by(|a, b| a == b);
+    assert!(runs.next().unwrap().eq(&[1, 1]));
+    assert!(runs.next_back().unwrap().eq(&[1]));
+    assert!(runs.next_back().unwrap().eq(&[3, 3, 3]));
+    assert!(runs.next().unwrap().eq(&[2]));
+    assert!(runs.next().is_none());
+    assert!(runs.next_back().is_none());
+
+    let empty: LinkedVec<i32> = LinkedVec::new();
+    assert_eq!(empty.chunk_by(|_, _| true).count(), 0);
+}
+
+#[test]
+fn range_views() {
+    let mut list: LinkedVec<i32> = [2, 3, 4, 5].into_iter().collect();
+    list.push_front(1);
+    list.push_front(0);
+
+    let range = list.range_l(1..4);
+    assert_eq!(range.len(), 3);
+    assert_eq!(range.front(), Some(&1));
+    assert_eq!(range.back(), Some(&3));
+    assert!(range.iter().eq(&[1, 2, 3]));
+    assert!(range.iter().rev().eq(&[3, 2, 1]));
+    assert!(range == *[1, 2, 3].as_slice());
+    assert!(list.range_l(..2) < list.range_l(1..));
+    assert!(list.range_l(3..3).is_empty());
+    assert_eq!(list.range_l(3..3).front(), None);
+    assert_eq!(list.range_l(..), list.range_l(0..=5));
+
+    let mut range = list.range_l_mut(2..=4);
+    *range.front_mut().unwrap() += 10;
+    *range.back_mut().unwrap() += 20;
+    for x in range.iter_mut() {
+        *x *= 2;
+    }
+    assert!(range.as_range().iter().eq(&[24, 6, 48]));
+    assert!(list.iter().eq(&[0, 1, 24, 6, 48, 5]));
+
+    assert!(catch_unwind(|| list.clone().range_l(2..7).len()).is_err());
+}
+
+#[test]
+fn swap_ranges() {
+    let mut list: LinkedVec<i32> = (3..10).collect();
+    list.push_front(2);
+    list.push_front(1);
+    list.push_front(0);
+    let original = list.clone();
+
+    list.swap_ranges_l(7..10, 1..3);
+    check_links(&list);
+    assert!(list.iter().eq(&[0, 7, 8, 9, 3, 4, 5, 6, 1, 2]));
+    assert!(list
+        .data
+        .iter()
+        .map(|x| x.payload)
+        .eq(original.data.iter().map(|x| x.payload)));
+
+    list.swap_ranges_l(..1, 1..4);
+    check_links(&list);
+    assert!(list.iter().eq(&[7, 8, 9, 0, 3, 4, 5, 6, 1, 2]));
+    list.swap_ranges_l(4..7, 7..);
+    check_links(&list);
+    assert!(list.iter().eq(&[7, 8, 9, 0, 6, 1, 2, 3, 4, 5]));
+    list.swap_ranges_l(0..0, 4..);
+    check_links(&list);
+    assert!(list.iter().eq(&[6, 1, 2, 3, 4, 5, 7, 8, 9, 0]));
+    assert!(catch_unwind(|| list.clone().swap_ranges_l(1..4, 3..5)).is_err());
+
+    let mut other: LinkedVec<i32> = (20..25).collect();
+    other.push_front(19);
+    list.swap_ranges_l_with(1..4, &mut other, 3..=5);
+    check_links(&list);
+    check_links(&other);
+    assert!(list.iter().eq(&[6, 22, 23, 24, 4, 5, 7, 8, 9, 0]));
+    assert!(other.iter().eq(&[19, 20, 21, 1, 2, 3]));
+    assert!(catch_unwind(|| list.clone().swap_ranges_l_with(.., &mut other.clone(), ..)).is_err());
+}
+
+#[test]
+fn move_range() {
+    let mut list: LinkedVec<i32> = (2..8).collect();
+    list.push_front(1);
+    list.push_front(0);
+
+    list.move_range_l(5..7, 1);
+    check_links(&list);
+    assert!(list.iter().eq(&[0, 5, 6, 1, 2, 3, 4, 7]));
+    list.move_range_l(..2, 8);
+    check_links(&list);
+    assert!(list.iter().eq(&[6, 1, 2, 3, 4, 7, 0, 5]));
+    list.move_range_l(2..4, 2);
+    list.move_range_l(2..4, 4);
+    list.move_range_l(3..3, 0);
+    assert!(list.iter().eq(&[6, 1, 2, 3, 4, 7, 0, 5]));
+    list.move_range_l(6.., 0);
+    check_links(&list);
+    assert!(list.iter().eq(&[0, 5, 6, 1, 2, 3, 4, 7]));
+
+    assert!(catch_unwind(|| list.clone().move_range_l(2..5, 3)).is_err());
+    assert!(catch_unwind(|| list.clone().move_range_l(2..5, 9)).is_err());
+}
+
+#[test]
+fn testkit_fuzz() {
+    use rand_xoshiro::rand_core::SeedableRng;
+
+    let mut rng = rand_xoshiro::Xoroshiro128StarStar::seed_from_u64(656);
+    let mut list: LinkedVec<u32, u16> = (0..10).collect();
+    testkit::fuzz(&mut list, &mut rng, 2000);
+    check_links(&list);
+
+    assert!(catch_unwind(|| {
+        let mut list = list.clone();
+        list.data.swap(0, 1);
+        testkit::check_links(&list)
+    })
+    .is_err());
+}
+
+#[cfg(feature = "ops")]
+#[test]
+fn testkit_check_op() {
+    let mut model = std::collections::VecDeque::from([0, 1, 2]);
+    let mut list: LinkedVec<u32> = model.iter().copied().collect();
+    list.push_front(9);
+    model.push_front(9);
+    assert_eq!(
+        testkit::check_op(&mut list, &mut model, ops::Op::Pop),
+        Some(9)
+    );
+    assert_eq!(
+        testkit::check_op(&mut list, &mut model, ops::Op::PushBack(3)),
+        None
+    );
+}
+
+#[cfg(feature = "proptest")]
+#[test]
+fn proptest_strategies() {
+    use proptest::{arbitrary::any, strategy::Strategy, test_runner::TestRunner};
+
+    let mut runner = TestRunner::deterministic();
+    let scattered = core::cell::Cell::new(false);
+    runner
+        .run(&strategy::lists::<_, u8>(any::<u16>(), 0..40), |list| {
+            check_links(&list);
+            assert!(list.len() < 40);
+            scattered.set(scattered.get() || !list.is_contiguous());
+            Ok(())
+        })
+        .unwrap();
+    assert!(scattered.get());
+
+    runner
+        .run(&strategy::near_capacity::<_, u8>(any::<u8>(), 3), |list| {
+            check_links(&list);
+            assert!((253..=256).contains(&list.len()));
+            Ok(())
+        })
+        .unwrap();
+
+    runner
+        .run(&any::<LinkedVec<i8, u16>>(), |list| {
+            check_links(&list);
+            Ok(())
+        })
+        .unwrap();
+    let value = strategy::lists::<_, usize>(any::<u8>(), 5)
+        .new_tree(&mut runner)
+        .unwrap();
+    assert_eq!(proptest::strategy::ValueTree::current(&value).len(), 5);
+}
+
+#[test]
+fn reacquire_tokens() {
+    let mut list: LinkedVec<i32> = (1..5).collect();
+    list.push_front(0);
+    let cell = RefCell::new(list);
+
+    let token = cell.borrow().token_l(0).unwrap();
+    assert_eq!(token.index_p(), Some(4));
+    assert_eq!(cell.borrow().token_p(4), Some(token));
+    assert_eq!(cell.borrow().token_l(5), None);
+    assert_eq!(cell.borrow().token_p(5), None);
+    assert_eq!(cell.borrow().resume(token).unwrap().save(), token);
+
+    *cell.borrow_mut().reacquire_mut(token).unwrap() += 10;
+    assert_eq!(cell.borrow().reacquire(token), Some(&10));
+    let ghost = {
+        let list = cell.borrow();
+        let mut cursor = list.cursor_front();
+        cursor.move_prev();
+        cursor.save()
+    };
+    assert_eq!(cell.borrow().reacquire(ghost), None);
+
+    cell.borrow_mut().push_back(5);
+    assert_eq!(cell.borrow().reacquire(token), None);
+    assert_eq!(cell.borrow_mut().reacquire_mut(token), None);
+    let fresh = cell.borrow().token_l(5).unwrap();
+    assert_eq!(cell.borrow().reacquire(fresh), Some(&5));
+}
+
+#[test]
+fn cursor_advance_while_and_skip_to() {
+    let list: LinkedVec<i32> = [1, 2, 3, 10, 4, 20, 5].into_iter().collect();
+
+    let mut cursor = list.cursor_front();
+    assert_eq!(cursor.advance_while(|&x| x < 10), 2);
+    assert_eq!(cursor.current(), Some(&3));
+    assert_eq!(cursor.index_l(), Some(2));
+    assert_eq!(cursor.advance_while(|&x| x < 10), 0);
+    assert_eq!(cursor.current(), Some(&3));
+
+    assert!(cursor.skip_to(|&x| x >= 10));
+    assert_eq!(cursor.current(), Some(&10));
+    assert!(cursor.skip_to(|&x| x >= 10));
+    assert_eq!(cursor.current(), Some(&20));
+    assert!(!cursor.skip_to(|&x| x >= 10));
+    assert_eq!(cursor.current(), None);
+
+    // From the ghost, both start at the front
+    assert_eq!(cursor.advance_while(|_| true), 7);
+    assert_eq!(cursor.current(), Some(&5));
+
+    let mut list = list;
+    let mut cursor = list.cursor_front_mut();
+    while cursor.skip_to(|&x| x >= 10) {
+        *cursor.current().unwrap() = 0;
+    }
+    assert_eq!(cursor.advance_while(|&x| x != 3), 2);
+    assert_eq!(cursor.index_l(), Some(1));
+    assert!(list.iter().eq(&[1, 2, 3, 0, 4, 0, 5]));
+}
+
+#[test]
+fn linked_arena() {
+    use crate::arena::LinkedArena;
+
+    let mut arena = LinkedArena::<u32, u8>::new();
+    let free = arena.new_list();
+    let active = arena.new_list();
+    for i in 0..5 {
+        arena.push_back(free, i);
+    }
+    assert_eq!(arena.push_front(active, 10), 5);
+    assert_eq!(arena.list_len(free), 5);
+    assert_eq!(arena.list_len(active), 1);
+
+    arena.move_to_back(2, active);
+    arena.move_to_front(0, active);
+    assert!(arena.iter(free).eq(&[1, 3, 4]));
+    assert!(arena.iter(active).eq(&[0, 10, 2]));
+    assert!(arena.iter(active).rev().eq(&[2, 10, 0]));
+
+    // Removes physical 0, moving `10` out of the last slot
+    assert_eq!(arena.pop_front(active), Some(0));
+    assert_eq!(arena.get_p(0), Some(&10));
+    assert_eq!(arena.get_p(5), None);
+    assert_eq!(arena.front(active), Some(&10));
+    assert_eq!(arena.pop_back(free), Some(4));
+    assert_eq!(arena.back(free), Some(&3));
+    assert_eq!(arena.len(), 4);
+    assert_eq!(format!("{arena:?}"), "[[1, 3], [10, 2]]");
+    assert_eq!(arena.list_of_p(0), Some(active));
+    assert_eq!(arena.list_of_p(1), Some(free));
+    assert_eq!(arena.list_of_p(4), None);
+
+    // Removes physical 2, moving the tail of `free` out of the last slot
+    assert_eq!(arena.pop_back(active), Some(2));
+    assert_eq!(arena.get_p(2), Some(&3));
+    assert_eq!(arena.list_of_p(2), Some(free));
+    assert_eq!(arena.back(free), Some(&3));
+    assert!(arena.iter(free).rev().eq(&[3, 1]));
+    assert!(arena.iter(active).eq(&[10]));
+
+    // Moving within a list reorders it
+    arena.move_to_front(2, free);
+    assert!(arena.iter(free).eq(&[3, 1]));
+    assert_eq!(arena.iter(free).len(), 2);
+    assert_eq!(arena.list_len(free), 2);
+}
+
+#[test]
+fn split_at_l() {
+    for at in 0..=6 {
+        let mut list: LinkedVec<u32, u8> = (0..4).collect();
+        list.push_front(10);
+        list.push_front(11);
+        list.swap_remove(3);
+        list.push_back(12);
+        let expected: Vec<u32> = list.iter().copied().collect();
+
+        let (first, second) = list.split_at_l(at);
+        check_links(&first);
+        check_links(&second);
+        assert!(first.iter().eq(&expected[..at]));
+        assert!(second.iter().eq(&expected[at..]));
+    }
+
+    let mut list: LinkedVec<u32> = (0..5).collect();
+    let tail = list.split_off(2);
+    assert!(list.iter().eq(&[0, 1]));
+    assert!(tail.iter().eq(&[2, 3, 4]));
+}
+
+#[test]
+#[should_panic(expected = "index (is 3) should be < or <= len (is 2)")]
+fn split_at_l_out_of_bounds() {
+    let list: LinkedVec<u32> = (0..2).collect();
+    let _ = list.split_at_l(3);
+}
+
+#[test]
+fn position_and_find_cursor() {
+    let mut list: LinkedVec<u32> = [3, 1, 4, 1, 5].into_iter().collect();
+    list.push_front(9);
+    assert_eq!(list.position(|&x| x == 1), Some(2));
+    assert_eq!(list.rposition(|&x| x == 1), Some(4));
+    assert_eq!(list.position(|&x| x == 7), None);
+    assert_eq!(list.rposition(|&x| x == 7), None);
+
+    let cursor = list.find_cursor(|&x| x == 4).unwrap();
+    assert_eq!(cursor.index_l(), Some(3));
+    assert_eq!(cursor.peek_prev(), Some(&1));
+    assert!(list.find_cursor(|&x| x > 10).is_none());
+
+    let mut cursor = list.find_cursor_mut(|&x| x == 9).unwrap();
+    assert_eq!(cursor.index_l(), Some(0));
+    assert_eq!(cursor.index_p(), Some(5));
+    *cursor.current().unwrap() = 2;
+    assert!(list.iter().eq(&[2, 3, 1, 4, 1, 5]));
+}
+
+#[test]
+fn checked_accessors() {
+    let mut list: LinkedVec<u32> = (0..3).collect();
+    assert_eq!(list.get_p(2), Some(&2));
+    assert_eq!(list.get_p(3), None);
+    *list.get_p_mut(1).unwrap() = 7;
+    assert_eq!(list.get_p_mut(3), None);
+    assert_eq!(*list.index_p(1), 7);
+    *list.index_p_mut(0) = 8;
+    assert!(list.iter().eq(&[8, 7, 2]));
+}
+
+#[test]
+#[should_panic(expected = "index (is 3) should be < or <= len (is 3)")]
+fn index_p_out_of_bounds() {
+    let list: LinkedVec<u32> = (0..3).collect();
+    let _ = list.index_p(3);
+}
+
+#[test]
+#[should_panic(expected = "index (is 5) should be < or <= len (is 3)")]
+fn swap_p_out_of_bounds() {
+    let mut list: LinkedVec<u32> = (0..3).collect();
+    list.swap_p(0, 5);
+}
+
+#[test]
+fn validate_links() {
+    let mut list: LinkedVec<u32, u8> = (0..4).collect();
+    assert_eq!(list.validate_links(), Ok(()));
+    assert_eq!(LinkedVec::<u32>::new().validate_links(), Ok(()));
+
+    list.data[1].prev = Some(2);
+    assert_eq!(
+        list.validate_links(),
+        Err(LinkCorruption::NotReciprocal { index_p: 1 })
+    );
+    list.data[1].prev = Some(0);
+
+    list.data[3].next = Some(9);
+    assert_eq!(
+        list.validate_links(),
+        Err(LinkCorruption::OutOfBounds { index_p: 9 })
+    );
+    list.data[3].next = None;
+
+    list.data[2].next = None;
+    assert_eq!(list.validate_links(), Err(LinkCorruption::TailMismatch));
+    list.tail = Some(2);
+    assert_eq!(
+        list.validate_links(),
+        Err(LinkCorruption::Unreachable { reached: 3, len: 4 })
+    );
+
+    list.head = None;
+    assert_eq!(list.validate_links(), Err(LinkCorruption::EndsMismatch));
+}
+
+#[test]
+#[cfg(all(feature = "paranoid-checks", debug_assertions))]
+#[should_panic(expected = "link corruption")]
+fn paranoid_checks() {
+    let mut list: LinkedVec<u32> = (0..4).collect();
+    list.data[1].prev = None;
+    list.push_back(4);
+}
+
+struct DropLogger<'a>(u32, &'a RefCell<Vec<u32>>);
+
+impl Drop for DropLogger<'_> {
+    fn drop(&mut self) {
+        self.1.borrow_mut().push(self.0);
+    }
+}
+
+fn drop_logger_list(log: &RefCell<Vec<u32>>) -> LinkedVec<DropLogger<'_>> {
+    let mut list = LinkedVec::new();
+    list.push_back(DropLogger(2, log));
+    list.push_front(DropLogger(1, log));
+    list.push_back(DropLogger(3, log));
+    list.push_front(DropLogger(0, log));
+    list.push_back(DropLogger(4, log));
+    list
+}
+
+#[test]
+fn drop_order() {
+    let log = RefCell::new(Vec::new());
+
+    let mut list = drop_logger_list(&log);
+    list.truncate(2);
+    assert_eq!(*log.borrow(), [2, 3, 4]);
+    check_links(&list);
+    list.truncate(3);
+    assert_eq!(list.len(), 2);
+    list.clear();
+    assert_eq!(*log.borrow(), [2, 3, 4, 0, 1]);
+
+    log.borrow_mut().clear();
+    drop(drop_logger_list(&log));
+    assert_eq!(*log.borrow(), [0, 1, 2, 3, 4]);
+
+    log.borrow_mut().clear();
+    let mut list = drop_logger_list(&log);
+    list.clear_unordered();
+    assert_eq!(log.borrow().len(), 5);
+    assert!(list.is_empty());
+}
+
+#[derive(Debug, PartialEq)]
+struct CloneBomb(u32);
+
+impl Clone for CloneBomb {
+    fn clone(&self) -> Self {
+        if self.0 == 3 {
+            panic!("clone bomb");
+        }
+        Self(self.0)
+    }
+}
+
+#[test]
+fn extend_panic_safety() {
+    let mut list: LinkedVec<u32> = (0..2).collect();
+    list.push_front(9);
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        list.extend((0..10).map(|x| if x == 5 { panic!("iter bomb") } else { x }))
+    }));
+    assert!(res.is_err());
+    check_links(&list);
+    assert!(list.iter().eq(&[9, 0, 1, 0, 1, 2, 3, 4]));
+}
+
+#[test]
+fn clone_panic_safety() {
+    let mut source: LinkedVec<CloneBomb> = (0..5).map(CloneBomb).collect();
+    source.push_front(CloneBomb(7));
+    assert!(catch_unwind(|| source.clone()).is_err());
+
+    let mut target: LinkedVec<CloneBomb> = (4..9).map(CloneBomb).collect();
+    let res = catch_unwind(AssertUnwindSafe(|| target.clone_from(&source)));
+    assert!(res.is_err());
+    check_links(&target);
+    assert!(target.is_empty());
+
+    source.swap_remove(3);
+    target.clone_from(&source);
+    check_links(&target);
+    assert!(target.iter().eq(source.iter()));
+}
+
+#[test]
+#[cfg(feature = "rayon")]
+fn rayon_physical_iter() {
+    use rayon::prelude::*;
+
+    let mut list: LinkedVec<u32> = (0..4).collect();
+    list.push_front(4);
+    list.par_extend((5..100).into_par_iter());
+    check_links(&list);
+    assert!(list
+        .iter()
+        .copied()
+        .eq([4].into_iter().chain(0..4).chain(5..100)));
+
+    list.par_iter_p_mut().for_each(|x| *x *= 2);
+    assert_eq!(list.par_iter_p().sum::<u32>(), 9900);
+    assert_eq!(list.par_iter_p().len(), 100);
+    assert_eq!(list.front(), Some(&8));
+}
+
+#[test]
+#[cfg(feature = "allocator-api2")]
+fn custom_allocator() {
+    use allocator_api2::alloc::{AllocError, Layout};
+    use core::{cell::Cell, ptr::NonNull};
+
+    #[derive(Clone)]
+    struct Counting<'a>(&'a Cell<usize>);
+
+    unsafe impl Allocator for Counting<'_> {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            self.0.set(self.0.get() + 1);
+            Global.allocate(layout)
+        }
+
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            Global.deallocate(ptr, layout)
+        }
+    }
+
+    let count = Cell::new(0);
+    let mut list = LinkedVec::<u32, u32, _>::new_in(Counting(&count));
+    assert_eq!(count.get(), 0);
+    list.extend(0..10);
+    list.push_front(10);
+    assert!(count.get() > 0);
+    check_links(&list);
+
+    let allocations = count.get();
+    let (first, second) = list.split_at_l(4);
+    assert!(count.get() > allocations);
+    check_links(&first);
+    check_links(&second);
+    assert!(first.iter().copied().eq([10, 0, 1, 2]));
+    assert!(second.clone().iter().copied().eq(3..10));
+}
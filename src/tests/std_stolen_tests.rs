@@ -1,8 +1,17 @@
-use crate::tests::VecNode;
+// Adapted from the `LinkedList` tests in std; keep them close to upstream.
+#![allow(
+    clippy::clone_on_copy,
+    clippy::manual_next_back,
+    clippy::neg_cmp_op_on_partial_ord,
+    clippy::wildcard_in_or_patterns,
+    clippy::zero_divided_by_zero
+)]
+
 use alloc::{boxed::Box, format, vec::Vec};
-use rand_xoshiro::rand_core::{RngCore, SeedableRng};
+use rand_xoshiro::rand_core::SeedableRng;
 
 use super::{LinkedVec, StoreIndex};
+use crate::testkit::{self, check_links};
 
 #[test]
 fn test_basic() {
@@ -52,49 +61,6 @@ fn list_from<T: Clone, I: StoreIndex + Copy>(v: &[T]) -> LinkedVec<T, I> {
     v.iter().cloned().collect()
 }
 
-pub fn check_links<T, I: StoreIndex + Copy>(list: &LinkedVec<T, I>) {
-    let mut len = 0;
-    let mut last_index: Option<usize> = None;
-    let mut node_index: usize;
-    match list.head {
-        None => {
-            // tail node should also be None.
-            assert!(list.tail.is_none());
-            assert_eq!(0, list.len());
-            return;
-        }
-        Some(node) => node_index = node.to_usize(),
-    }
-
-    loop {
-        match (last_index, list.data[node_index].prev) {
-            (None, None) => {}
-            (None, _) => panic!("prev link for head"),
-            (Some(p), Some(pptr)) => {
-                assert_eq!(p as *const VecNode<T>, pptr.to_usize() as *const VecNode<T>);
-            }
-            _ => panic!("prev link is none, not good"),
-        }
-        match list.data[node_index].next {
-            Some(next) => {
-                last_index = Some(node_index);
-                node_index = next.to_usize();
-                len += 1;
-            }
-            None => {
-                len += 1;
-                break;
-            }
-        }
-    }
-
-    // verify that the tail node points to the last node.
-    let tail = list.tail.expect("some tail node").to_usize();
-    assert_eq!(tail, node_index);
-    // check that len matches interior links.
-    assert_eq!(len, list.len());
-}
-
 #[test]
 fn test_append() {
     // Empty to empty
@@ -369,52 +335,13 @@ fn test_ord_nan() {
     assert!(s >= one);
 }
 
-fn fuzz_test(sz: i32, rng: &mut impl RngCore) {
-    let mut m: LinkedVec<_> = LinkedVec::new();
-    let mut v = Vec::from([]);
-    for i in 0..sz {
-        check_links(&m);
-        let r: u8 = rng.next_u32() as u8;
-        match r % 6 {
-            0 => {
-                m.pop_back();
-                v.pop();
-            }
-            1 => {
-                if !v.is_empty() {
-                    m.pop_front();
-                    v.remove(0);
-                }
-            }
-            2 | 4 => {
-                m.push_front(-i);
-                v.insert(0, -i);
-            }
-            3 | 5 | _ => {
-                m.push_back(i);
-                v.push(i);
-            }
-        }
-    }
-
-    check_links(&m);
-
-    let mut i = 0;
-    for (a, &b) in m.into_iter().zip(&v) {
-        i += 1;
-        assert_eq!(a, b);
-    }
-    assert_eq!(i, v.len());
-}
-
 #[test]
 fn test_fuzz() {
     let mut rng = rand_xoshiro::Xoroshiro128StarStar::seed_from_u64(127);
     for _ in 0..25 {
-        fuzz_test(3, &mut rng);
-        fuzz_test(16, &mut rng);
-        #[cfg(not(miri))] // Miri is too slow
-        fuzz_test(189, &mut rng);
+        for rounds in [3, 16, 189] {
+            testkit::fuzz(&mut LinkedVec::<u32>::new(), &mut rng, rounds);
+        }
     }
 }
 
@@ -423,41 +350,46 @@ fn test_show() {
     let mut list: LinkedVec<_> = (0..10).collect();
     list.pop_front();
     list.push_front(0);
+    assert_eq!(format!("{list:?}"), "[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]");
     assert_eq!(
-        format!("{list:?}"),
+        format!("{:?}", list.debug_layout()),
         "{9: 0, 1: 1, 2: 2, 3: 3, 4: 4, 5: 5, 6: 6, 7: 7, 8: 8, 0: 9}"
     );
 
     let list: LinkedVec<_> = ["just", "one", "test", "more"].into_iter().collect();
     assert_eq!(
         format!("{list:?}"),
+        "[\"just\", \"one\", \"test\", \"more\"]"
+    );
+    assert_eq!(
+        format!("{:?}", list.debug_layout()),
         "{0: \"just\", 1: \"one\", 2: \"test\", 3: \"more\"}"
     );
 }
 
-// #[test]
-// fn extract_if_test() {
-//     let mut m: LinkedVec<u32> = LinkedVec::new();
-//     m.extend(&[1, 2, 3, 4, 5, 6]);
-//     let deleted = m.extract_if(|v| *v < 4).collect::<Vec<_>>();
+#[test]
+fn extract_if_test() {
+    let mut m: LinkedVec<u32> = LinkedVec::new();
+    m.extend(&[1, 2, 3, 4, 5, 6]);
+    let deleted = m.extract_if(|v| *v < 4).collect::<Vec<_>>();
 
-//     check_links(&m);
+    check_links(&m);
 
-//     assert_eq!(deleted, &[1, 2, 3]);
-//     assert_eq!(m.into_iter().collect::<Vec<_>>(), &[4, 5, 6]);
-// }
+    assert_eq!(deleted, &[1, 2, 3]);
+    assert_eq!(m.into_iter().collect::<Vec<_>>(), &[4, 5, 6]);
+}
 
-// #[test]
-// fn drain_to_empty_test() {
-//     let mut m: LinkedVec<u32> = LinkedVec::new();
-//     m.extend(&[1, 2, 3, 4, 5, 6]);
-//     let deleted = m.extract_if(|_| true).collect::<Vec<_>>();
+#[test]
+fn drain_to_empty_test() {
+    let mut m: LinkedVec<u32> = LinkedVec::new();
+    m.extend(&[1, 2, 3, 4, 5, 6]);
+    let deleted = m.extract_if(|_| true).collect::<Vec<_>>();
 
-//     check_links(&m);
+    check_links(&m);
 
-//     assert_eq!(deleted, &[1, 2, 3, 4, 5, 6]);
-//     assert_eq!(m.into_iter().collect::<Vec<_>>(), &[]);
-// }
+    assert_eq!(deleted, &[1, 2, 3, 4, 5, 6]);
+    assert_eq!(m.into_iter().collect::<Vec<_>>(), &[]);
+}
 
 #[test]
 fn test_cursor_move_peek() {
@@ -721,179 +653,194 @@ fn test_contains() {
     assert!(!l.contains(&3));
 }
 
-// #[test]
-// fn extract_if_empty() {
-//     let mut list: LinkedVec<i32> = LinkedVec::new();
-
-//     {
-//         let mut iter = list.extract_if(|_| true);
-//         assert_eq!(iter.size_hint(), (0, Some(0)));
-//         assert_eq!(iter.next(), None);
-//         assert_eq!(iter.size_hint(), (0, Some(0)));
-//         assert_eq!(iter.next(), None);
-//         assert_eq!(iter.size_hint(), (0, Some(0)));
-//     }
-
-//     assert_eq!(list.len(), 0);
-//     assert_eq!(list.into_iter().collect::<Vec<_>>(), Vec::from([]));
-// }
+#[test]
+fn extract_if_empty() {
+    let mut list: LinkedVec<i32> = LinkedVec::new();
 
-// #[test]
-// fn extract_if_zst() {
-//     let mut list: LinkedVec<_> = [(), (), (), (), ()].into_iter().collect();
-//     let initial_len = list.len();
-//     let mut count = 0;
-
-//     {
-//         let mut iter = list.extract_if(|_| true);
-//         assert_eq!(iter.size_hint(), (0, Some(initial_len)));
-//         while let Some(_) = iter.next() {
-//             count += 1;
-//             assert_eq!(iter.size_hint(), (0, Some(initial_len - count)));
-//         }
-//         assert_eq!(iter.size_hint(), (0, Some(0)));
-//         assert_eq!(iter.next(), None);
-//         assert_eq!(iter.size_hint(), (0, Some(0)));
-//     }
-
-//     assert_eq!(count, initial_len);
-//     assert_eq!(list.len(), 0);
-//     assert_eq!(list.into_iter().collect::<Vec<_>>(), Vec::from([]));
-// }
+    {
+        let mut iter = list.extract_if(|_| true);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
 
-// #[test]
-// fn extract_if_false() {
-//     let mut list: LinkedVec<_> = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10].into_iter().collect();
-
-//     let initial_len = list.len();
-//     let mut count = 0;
-
-//     {
-//         let mut iter = list.extract_if(|_| false);
-//         assert_eq!(iter.size_hint(), (0, Some(initial_len)));
-//         for _ in iter.by_ref() {
-//             count += 1;
-//         }
-//         assert_eq!(iter.size_hint(), (0, Some(0)));
-//         assert_eq!(iter.next(), None);
-//         assert_eq!(iter.size_hint(), (0, Some(0)));
-//     }
-
-//     assert_eq!(count, 0);
-//     assert_eq!(list.len(), initial_len);
-//     assert_eq!(list.into_iter().collect::<Vec<_>>(), Vec::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]));
-// }
+    assert_eq!(list.len(), 0);
+    assert_eq!(list.into_iter().collect::<Vec<_>>(), Vec::from([]));
+}
 
-// #[test]
-// fn extract_if_true() {
-//     let mut list: LinkedVec<_> = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10].into_iter().collect();
-
-//     let initial_len = list.len();
-//     let mut count = 0;
-
-//     {
-//         let mut iter = list.extract_if(|_| true);
-//         assert_eq!(iter.size_hint(), (0, Some(initial_len)));
-//         while let Some(_) = iter.next() {
-//             count += 1;
-//             assert_eq!(iter.size_hint(), (0, Some(initial_len - count)));
-//         }
-//         assert_eq!(iter.size_hint(), (0, Some(0)));
-//         assert_eq!(iter.next(), None);
-//         assert_eq!(iter.size_hint(), (0, Some(0)));
-//     }
-
-//     assert_eq!(count, initial_len);
-//     assert_eq!(list.len(), 0);
-//     assert_eq!(list.into_iter().collect::<Vec<_>>(), Vec::from([]));
-// }
+#[test]
+fn extract_if_zst() {
+    let mut list: LinkedVec<_> = [(), (), (), (), ()].into_iter().collect();
+    let initial_len = list.len();
+    let mut count = 0;
 
-// #[test]
-// fn extract_if_complex() {
-//     {
-//         //                [+xxx++++++xxxxx++++x+x++]
-//         let mut list = [
-//             1, 2, 4, 6, 7, 9, 11, 13, 15, 17, 18, 20, 22, 24, 26, 27, 29, 31, 33, 34, 35, 36, 37,
-//             39,
-//         ]
-//         .into_iter()
-//         .collect::<LinkedVec<_>>();
-
-//         let removed = list.extract_if(|x| *x % 2 == 0).collect::<Vec<_>>();
-//         assert_eq!(removed.len(), 10);
-//         assert_eq!(removed, Vec::from([2, 4, 6, 18, 20, 22, 24, 26, 34, 36]));
-
-//         assert_eq!(list.len(), 14);
-//         assert_eq!(
-//             list.into_iter().collect::<Vec<_>>(),
-//             Vec::from([1, 7, 9, 11, 13, 15, 17, 27, 29, 31, 33, 35, 37, 39])
-//         );
-//     }
-
-//     {
-//         // [xxx++++++xxxxx++++x+x++]
-//         let mut list =
-//             [2, 4, 6, 7, 9, 11, 13, 15, 17, 18, 20, 22, 24, 26, 27, 29, 31, 33, 34, 35, 36, 37, 39]
-//                 .into_iter()
-//                 .collect::<LinkedVec<_>>();
-
-//         let removed = list.extract_if(|x| *x % 2 == 0).collect::<Vec<_>>();
-//         assert_eq!(removed.len(), 10);
-//         assert_eq!(removed, Vec::from([2, 4, 6, 18, 20, 22, 24, 26, 34, 36]));
-
-//         assert_eq!(list.len(), 13);
-//         assert_eq!(
-//             list.into_iter().collect::<Vec<_>>(),
-//             Vec::from([7, 9, 11, 13, 15, 17, 27, 29, 31, 33, 35, 37, 39])
-//         );
-//     }
-
-//     {
-//         // [xxx++++++xxxxx++++x+x]
-//         let mut list =
-//             [2, 4, 6, 7, 9, 11, 13, 15, 17, 18, 20, 22, 24, 26, 27, 29, 31, 33, 34, 35, 36]
-//                 .into_iter()
-//                 .collect::<LinkedVec<_>>();
-
-//         let removed = list.extract_if(|x| *x % 2 == 0).collect::<Vec<_>>();
-//         assert_eq!(removed.len(), 10);
-//         assert_eq!(removed, Vec::from([2, 4, 6, 18, 20, 22, 24, 26, 34, 36]));
-
-//         assert_eq!(list.len(), 11);
-//         assert_eq!(
-//             list.into_iter().collect::<Vec<_>>(),
-//             Vec::from([7, 9, 11, 13, 15, 17, 27, 29, 31, 33, 35])
-//         );
-//     }
-
-//     {
-//         // [xxxxxxxxxx+++++++++++]
-//         let mut list = [2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 1, 3, 5, 7, 9, 11, 13, 15, 17, 19]
-//             .into_iter()
-//             .collect::<LinkedVec<_>>();
-
-//         let removed = list.extract_if(|x| *x % 2 == 0).collect::<Vec<_>>();
-//         assert_eq!(removed.len(), 10);
-//         assert_eq!(removed, Vec::from([2, 4, 6, 8, 10, 12, 14, 16, 18, 20]));
-
-//         assert_eq!(list.len(), 10);
-//         assert_eq!(list.into_iter().collect::<Vec<_>>(), Vec::from([1, 3, 5, 7, 9, 11, 13, 15, 17, 19]));
-//     }
-
-//     {
-//         // [+++++++++++xxxxxxxxxx]
-//         let mut list = [1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20]
-//             .into_iter()
-//             .collect::<LinkedVec<_>>();
-
-//         let removed = list.extract_if(|x| *x % 2 == 0).collect::<Vec<_>>();
-//         assert_eq!(removed.len(), 10);
-//         assert_eq!(removed, Vec::from([2, 4, 6, 8, 10, 12, 14, 16, 18, 20]));
-
-//         assert_eq!(list.len(), 10);
-//         assert_eq!(list.into_iter().collect::<Vec<_>>(), Vec::from([1, 3, 5, 7, 9, 11, 13, 15, 17, 19]));
-//     }
-// }
+    {
+        let mut iter = list.extract_if(|_| true);
+        assert_eq!(iter.size_hint(), (0, Some(initial_len)));
+        while let Some(_) = iter.next() {
+            count += 1;
+            assert_eq!(iter.size_hint(), (0, Some(initial_len - count)));
+        }
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+
+    assert_eq!(count, initial_len);
+    assert_eq!(list.len(), 0);
+    assert_eq!(list.into_iter().collect::<Vec<_>>(), Vec::from([]));
+}
+
+#[test]
+fn extract_if_false() {
+    let mut list: LinkedVec<_> = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10].into_iter().collect();
+
+    let initial_len = list.len();
+    let mut count = 0;
+
+    {
+        let mut iter = list.extract_if(|_| false);
+        assert_eq!(iter.size_hint(), (0, Some(initial_len)));
+        for _ in iter.by_ref() {
+            count += 1;
+        }
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+
+    assert_eq!(count, 0);
+    assert_eq!(list.len(), initial_len);
+    assert_eq!(
+        list.into_iter().collect::<Vec<_>>(),
+        Vec::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10])
+    );
+}
+
+#[test]
+fn extract_if_true() {
+    let mut list: LinkedVec<_> = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10].into_iter().collect();
+
+    let initial_len = list.len();
+    let mut count = 0;
+
+    {
+        let mut iter = list.extract_if(|_| true);
+        assert_eq!(iter.size_hint(), (0, Some(initial_len)));
+        while let Some(_) = iter.next() {
+            count += 1;
+            assert_eq!(iter.size_hint(), (0, Some(initial_len - count)));
+        }
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+
+    assert_eq!(count, initial_len);
+    assert_eq!(list.len(), 0);
+    assert_eq!(list.into_iter().collect::<Vec<_>>(), Vec::from([]));
+}
+
+#[test]
+fn extract_if_complex() {
+    {
+        //                [+xxx++++++xxxxx++++x+x++]
+        let mut list = [
+            1, 2, 4, 6, 7, 9, 11, 13, 15, 17, 18, 20, 22, 24, 26, 27, 29, 31, 33, 34, 35, 36, 37,
+            39,
+        ]
+        .into_iter()
+        .collect::<LinkedVec<_>>();
+
+        let removed = list.extract_if(|x| *x % 2 == 0).collect::<Vec<_>>();
+        assert_eq!(removed.len(), 10);
+        assert_eq!(removed, Vec::from([2, 4, 6, 18, 20, 22, 24, 26, 34, 36]));
+
+        assert_eq!(list.len(), 14);
+        assert_eq!(
+            list.into_iter().collect::<Vec<_>>(),
+            Vec::from([1, 7, 9, 11, 13, 15, 17, 27, 29, 31, 33, 35, 37, 39])
+        );
+    }
+
+    {
+        // [xxx++++++xxxxx++++x+x++]
+        let mut list = [
+            2, 4, 6, 7, 9, 11, 13, 15, 17, 18, 20, 22, 24, 26, 27, 29, 31, 33, 34, 35, 36, 37, 39,
+        ]
+        .into_iter()
+        .collect::<LinkedVec<_>>();
+
+        let removed = list.extract_if(|x| *x % 2 == 0).collect::<Vec<_>>();
+        assert_eq!(removed.len(), 10);
+        assert_eq!(removed, Vec::from([2, 4, 6, 18, 20, 22, 24, 26, 34, 36]));
+
+        assert_eq!(list.len(), 13);
+        assert_eq!(
+            list.into_iter().collect::<Vec<_>>(),
+            Vec::from([7, 9, 11, 13, 15, 17, 27, 29, 31, 33, 35, 37, 39])
+        );
+    }
+
+    {
+        // [xxx++++++xxxxx++++x+x]
+        let mut list = [
+            2, 4, 6, 7, 9, 11, 13, 15, 17, 18, 20, 22, 24, 26, 27, 29, 31, 33, 34, 35, 36,
+        ]
+        .into_iter()
+        .collect::<LinkedVec<_>>();
+
+        let removed = list.extract_if(|x| *x % 2 == 0).collect::<Vec<_>>();
+        assert_eq!(removed.len(), 10);
+        assert_eq!(removed, Vec::from([2, 4, 6, 18, 20, 22, 24, 26, 34, 36]));
+
+        assert_eq!(list.len(), 11);
+        assert_eq!(
+            list.into_iter().collect::<Vec<_>>(),
+            Vec::from([7, 9, 11, 13, 15, 17, 27, 29, 31, 33, 35])
+        );
+    }
+
+    {
+        // [xxxxxxxxxx+++++++++++]
+        let mut list = [
+            2, 4, 6, 8, 10, 12, 14, 16, 18, 20, 1, 3, 5, 7, 9, 11, 13, 15, 17, 19,
+        ]
+        .into_iter()
+        .collect::<LinkedVec<_>>();
+
+        let removed = list.extract_if(|x| *x % 2 == 0).collect::<Vec<_>>();
+        assert_eq!(removed.len(), 10);
+        assert_eq!(removed, Vec::from([2, 4, 6, 8, 10, 12, 14, 16, 18, 20]));
+
+        assert_eq!(list.len(), 10);
+        assert_eq!(
+            list.into_iter().collect::<Vec<_>>(),
+            Vec::from([1, 3, 5, 7, 9, 11, 13, 15, 17, 19])
+        );
+    }
+
+    {
+        // [+++++++++++xxxxxxxxxx]
+        let mut list = [
+            1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 2, 4, 6, 8, 10, 12, 14, 16, 18, 20,
+        ]
+        .into_iter()
+        .collect::<LinkedVec<_>>();
+
+        let removed = list.extract_if(|x| *x % 2 == 0).collect::<Vec<_>>();
+        assert_eq!(removed.len(), 10);
+        assert_eq!(removed, Vec::from([2, 4, 6, 8, 10, 12, 14, 16, 18, 20]));
+
+        assert_eq!(list.len(), 10);
+        assert_eq!(
+            list.into_iter().collect::<Vec<_>>(),
+            Vec::from([1, 3, 5, 7, 9, 11, 13, 15, 17, 19])
+        );
+    }
+}
 
 #[test]
 fn test_drop() {
@@ -0,0 +1,97 @@
+use core::fmt;
+
+use crate::{inner_types::StoreIndex, Allocator, LinkedVec};
+
+/// Describes the first broken invariant found by
+/// [`LinkedVec::validate_links`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkCorruption {
+    /// Exactly one of `head` and `tail` is set, or neither is set but the
+    /// physical array is not empty.
+    EndsMismatch,
+    /// The node at `index_p` is reached through a link, but is past the end
+    /// of the physical array.
+    OutOfBounds { index_p: usize },
+    /// The `prev` link of the node at `index_p` does not point back to the
+    /// node it was reached from.
+    NotReciprocal { index_p: usize },
+    /// The node at `index_p` is reached twice when walking from the head.
+    Cycle { index_p: usize },
+    /// The walk from the head ended somewhere other than the tail.
+    TailMismatch,
+    /// Walking from the head reached only `reached` of the `len` nodes in
+    /// the physical array.
+    Unreachable { reached: usize, len: usize },
+}
+
+impl fmt::Display for LinkCorruption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EndsMismatch => write!(f, "head and tail disagree on emptiness"),
+            Self::OutOfBounds { index_p } => write!(f, "link to out of bounds node {index_p}"),
+            Self::NotReciprocal { index_p } => {
+                write!(f, "node {index_p} does not link back to its predecessor")
+            }
+            Self::Cycle { index_p } => write!(f, "node {index_p} is part of a cycle"),
+            Self::TailMismatch => write!(f, "the last node reached is not the tail"),
+            Self::Unreachable { reached, len } => {
+                write!(f, "only {reached} of {len} nodes are reachable from head")
+            }
+        }
+    }
+}
+
+impl<T, I: StoreIndex + Copy, A: Allocator> LinkedVec<T, I, A> {
+    /// Checks that the links form a single chain from `head` to `tail` that
+    /// covers every node of the physical array exactly once.
+    ///
+    /// This operation should compute in *O*(n) time and allocates a bitmap
+    /// of visited nodes.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first broken invariant found.
+    pub fn validate_links(&self) -> Result<(), LinkCorruption> {
+        let len = self.len();
+        let (head, tail) = match (self.head, self.tail) {
+            (None, None) if len == 0 => return Ok(()),
+            (Some(head), Some(tail)) => (head.to_usize(), tail.to_usize()),
+            _ => return Err(LinkCorruption::EndsMismatch),
+        };
+
+        let mut visited = alloc::vec![false; len];
+        let mut reached = 0;
+        let mut prev = None;
+        let mut current = head;
+        loop {
+            if current >= len {
+                return Err(LinkCorruption::OutOfBounds { index_p: current });
+            }
+            if visited[current] {
+                return Err(LinkCorruption::Cycle { index_p: current });
+            }
+            visited[current] = true;
+            reached += 1;
+
+            let node = &self.data[current];
+            if node.prev.map(|x| x.to_usize()) != prev {
+                return Err(LinkCorruption::NotReciprocal { index_p: current });
+            }
+            match node.next {
+                Some(next) => {
+                    prev = Some(current);
+                    current = next.to_usize();
+                }
+                None => break,
+            }
+        }
+
+        if current != tail {
+            return Err(LinkCorruption::TailMismatch);
+        }
+        if reached != len {
+            return Err(LinkCorruption::Unreachable { reached, len });
+        }
+        Ok(())
+    }
+}